@@ -0,0 +1,84 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+//! Type-level helpers asserting that the application part builders of a chain are of distinct types.
+//!
+//! The helpers are implementation details of [`crate::ApplicationBuilder::build`]. They cannot be named outside of
+//! this crate, so that they cannot be implemented or used elsewhere.
+
+use crate::application::Node;
+
+/// Marks that an element is absent from a type-level list. See [`NotContains`].
+pub struct Absent {
+    _priv: (),
+}
+
+/// Marks that an element is present in a type-level list. See [`NotContains`].
+pub struct Present {
+    _priv: (),
+}
+
+/// Marks that an optional element is present in a type-level list. See [`NotSame`].
+pub struct PresentOptional {
+    _priv: (),
+}
+
+/// A trait for asserting at compile time that a type is not the same as `T`.
+///
+/// The marker `M` is inferred by the compiler. Every type implements the trait for [`Absent`], but `T`
+/// additionally implements it for [`Present`]. The marker is then ambiguous and the program is rejected with a
+/// type annotations error (E0283) rather than an unsatisfied trait bound, as the trait is implemented either way.
+///
+/// An optional application part builder `Option<T>` is considered the same as `T`, so that a part cannot be added
+/// both unconditionally and conditionally. Both types additionally implement the trait for [`PresentOptional`].
+///
+/// # Type Parameters
+/// - `T`: The type that must not be the same as the implementing type.
+/// - `M`: The inferred marker, either [`Absent`], [`Present`] or [`PresentOptional`].
+pub trait NotSame<T, M> {}
+
+impl<U, T> NotSame<T, Absent> for U {}
+
+impl<T> NotSame<T, Present> for T {}
+
+impl<T> NotSame<T, PresentOptional> for Option<T> {}
+
+impl<T> NotSame<Option<T>, PresentOptional> for T {}
+
+/// A trait for asserting at compile time that a type-level list does not contain an element of type `T`.
+///
+/// The marker `M` is inferred by the compiler. See [`NotSame`] for details.
+///
+/// # Type Parameters
+/// - `T`: The type of the element that must not be contained in the list.
+/// - `M`: The inferred marker.
+pub trait NotContains<T, M> {}
+
+impl<T> NotContains<T, ()> for () {}
+
+impl<Head, Tail, T, HeadMarker, TailMarker> NotContains<T, (HeadMarker, TailMarker)>
+    for Node<Head, Tail>
+where
+    Head: NotSame<T, HeadMarker>,
+    Tail: NotContains<T, TailMarker>,
+{
+}
+
+/// A trait for asserting at compile time that all elements of a type-level list are of distinct types.
+///
+/// The marker `M` is inferred by the compiler. See [`NotSame`] for details.
+///
+/// # Type Parameters
+/// - `M`: The inferred marker.
+pub trait Distinct<M> {}
+
+impl Distinct<()> for () {}
+
+impl<Head, Tail, HeadMarker, TailMarker> Distinct<(HeadMarker, TailMarker)> for Node<Head, Tail> where
+    Tail: NotContains<Head, HeadMarker> + Distinct<TailMarker>
+{
+}
@@ -7,7 +7,7 @@
 
 use crate::{
     ApplicationPart,
-    application::{ApplicationPartBuilder, configurable::Configurable},
+    application::ApplicationPartBuilder,
     inject::{ConstructionResult, ServiceProvider},
 };
 use std::convert::Infallible;
@@ -31,9 +31,3 @@ impl ApplicationPartBuilder for () {
         Ok(())
     }
 }
-
-impl Configurable<'_> for () {
-    fn has_item<I>() -> bool {
-        false
-    }
-}
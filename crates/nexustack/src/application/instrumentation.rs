@@ -6,7 +6,7 @@
  */
 
 use crate::ApplicationPart;
-use std::{borrow::Cow, time::Instant};
+use std::{borrow::Cow, collections::BTreeSet, time::Instant};
 use tokio_util::sync::CancellationToken;
 
 pub struct WithInstrumentation<T>(pub(crate) T);
@@ -21,6 +21,14 @@ where
         T::name()
     }
 
+    fn startup_order() -> i32 {
+        T::startup_order()
+    }
+
+    fn startup_orders() -> BTreeSet<i32> {
+        T::startup_orders()
+    }
+
     #[tracing::instrument(
         name = "application_part.before_startup",
         skip(self, cancellation_token),
@@ -5,18 +5,18 @@
  * Licensed under the MIT license. See LICENSE file in the project root for details.
  */
 
-use crate::{
-    application::configurable::Configurable,
-    inject::{ConstructionResult, ServiceCollection, ServiceProvider},
-};
-use std::{borrow::Cow, time::Instant};
+use crate::inject::{ConstructionResult, ServiceCollection, ServiceProvider, ServiceToken};
+use distinct::Distinct;
+use std::{borrow::Cow, collections::BTreeSet, time::Instant};
 use tokio_util::sync::CancellationToken;
 
 mod chain;
-mod configurable;
+#[doc(hidden)]
+mod distinct;
 mod empty;
 mod instrumentation;
 mod node;
+mod optional;
 
 pub use chain::{Chain, Here, InHead, InTail, Index};
 pub use node::Node;
@@ -44,13 +44,26 @@ pub trait ApplicationPartBuilder {
     /// # Errors
     /// Returns an error if the application part or any of its dependencies cannot be constructed from the service provider.
     fn build(self, service_provider: ServiceProvider) -> ConstructionResult<Self::ApplicationPart>;
+
+    /// Returns the services the application part resolves from the service provider.
+    ///
+    /// These services and their transitive dependencies are considered used when reporting unused service
+    /// registrations, see [`ServiceCollection::find_unused`].
+    ///
+    /// # Returns
+    /// The services used by the application part. Defaults to no services.
+    #[must_use]
+    fn dependencies(&self) -> Vec<ServiceToken> {
+        Vec::new()
+    }
 }
 
 /// Trait representing a part of an application lifecycle.
 ///
 /// Implementors define async hooks for startup, running, and shutdown phases.
 /// All hooks should return immediately if cancelled via the provided `CancellationToken`.
-/// Hooks are run in parallel for all parts.
+/// Hooks are run in parallel for all parts, except for `before_startup`, which is sequenced by
+/// [`ApplicationPart::startup_order`].
 pub trait ApplicationPart {
     /// The error type returned by this application part's hooks.
     type Error: std::error::Error + Send;
@@ -64,6 +77,44 @@ pub trait ApplicationPart {
         Cow::Borrowed(std::any::type_name::<Self>())
     }
 
+    /// Returns the startup order of this application part.
+    ///
+    /// The `before_startup` phase of this part is executed after the `before_startup` phases of all parts with a
+    /// lower startup order completed, e.g. to run database migrations before serving requests. Parts with the same
+    /// startup order execute the phase in parallel. The `run` and `before_shutdown` phases are not affected.
+    ///
+    /// # Returns
+    /// The startup order of the application part. Defaults to `0`.
+    #[must_use]
+    fn startup_order() -> i32 {
+        0
+    }
+
+    /// Returns the distinct startup orders of all application parts contained in this part.
+    #[doc(hidden)]
+    #[must_use]
+    fn startup_orders() -> BTreeSet<i32> {
+        BTreeSet::from([Self::startup_order()])
+    }
+
+    /// Executes the `before_startup` phase of all contained application parts with the specified startup order.
+    #[doc(hidden)]
+    fn before_startup_in_order(
+        &mut self,
+        startup_order: i32,
+        cancellation_token: CancellationToken,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send + '_ {
+        let before_startup = (Self::startup_order() == startup_order)
+            .then(|| self.before_startup(cancellation_token));
+
+        async move {
+            match before_startup {
+                Some(before_startup) => before_startup.await,
+                None => Ok(()),
+            }
+        }
+    }
+
     /// Called before application startup for this part.
     ///
     /// # Arguments
@@ -112,7 +163,7 @@ pub trait ApplicationPart {
 /// # Returns
 /// An [`ApplicationBuilder`] instance for chaining application part and service configuration.
 #[must_use]
-pub fn application_builder() -> impl ApplicationBuilder {
+pub fn application_builder() -> impl ApplicationBuilder<Chain = ()> {
     ApplicationBuilderConcrete::default()
 }
 
@@ -127,6 +178,9 @@ pub trait ApplicationBuilder {
 
     /// Adds an application part to the builder using a default-constructed builder.
     ///
+    /// Each application part builder type can be added at most once. Adding a type twice is rejected at
+    /// compile time by [`ApplicationBuilder::build`].
+    ///
     /// # Type Parameters
     /// - `B`: The application part builder type to add. Must implement [`ApplicationPartBuilder`] and [`Default`].
     ///
@@ -141,6 +195,40 @@ pub trait ApplicationBuilder {
         self.add_application_part_with_factory(B::default)
     }
 
+    /// Adds an application part to the builder only if the specified condition holds.
+    ///
+    /// The part is added as an [`Option<B>`] in any case, so that the type of the builder chain does not depend on
+    /// the condition. If the condition does not hold, the factory is not invoked and all lifecycle hooks of the
+    /// part are no-ops.
+    ///
+    /// An optional part counts as the same application part as `B`, so that `B` cannot be added both via this method
+    /// and unconditionally. The part is configured via [`ApplicationBuilder::configure_application_part`] with the
+    /// builder type `Option<B>`.
+    ///
+    /// # Type Parameters
+    /// - `B`: The application part builder type to add. Must implement [`ApplicationPartBuilder`].
+    /// - `F`: The factory closure type.
+    ///
+    /// # Arguments
+    /// * `condition` - Whether the application part is enabled.
+    /// * `factory` - A closure that produces a new instance of the part builder.
+    ///
+    /// # Returns
+    /// A new builder with the optional application part added.
+    #[must_use]
+    fn add_application_part_if<B, F>(
+        self,
+        condition: bool,
+        factory: F,
+    ) -> impl ApplicationBuilder<Chain = Node<Option<B>, Self::Chain>>
+    where
+        Self: Sized,
+        B: ApplicationPartBuilder + 'static,
+        F: FnOnce() -> B,
+    {
+        self.add_application_part_with_factory(move || condition.then(factory))
+    }
+
     /// Adds an application part to the builder using a custom factory.
     ///
     /// # Type Parameters
@@ -229,6 +317,16 @@ pub trait ApplicationBuilder {
     where
         F: FnOnce(&mut ServiceCollection);
 
+    /// Returns the registered services that are not used by any application part.
+    ///
+    /// The services resolved by the application parts, see [`ApplicationPartBuilder::dependencies`], are considered
+    /// used in addition to the services marked as used. See [`ServiceCollection::find_unused`] for details.
+    ///
+    /// # Returns
+    /// The unused services in registration order.
+    #[must_use]
+    fn find_unused_services(&self) -> Vec<ServiceToken>;
+
     /// Builds the final [`Application`] instance from the collected parts and configured services.
     ///
     /// # Returns
@@ -236,7 +334,19 @@ pub trait ApplicationBuilder {
     ///
     /// # Errors
     /// Returns an error if any application part or its dependencies cannot be constructed, or if service configuration fails.
-    fn build(self) -> ConstructionResult<impl Application + Send>;
+    ///
+    /// # Compile-time guarantees
+    /// This method is only available when each application part builder type was added at most once. The marker `M`
+    /// is inferred by the compiler.
+    ///
+    /// Stable Rust cannot require two types to be different, so adding a part twice cannot be reported as an
+    /// unsatisfied trait bound. Instead, the marker `M` cannot be inferred and the call is rejected with
+    /// `error[E0283]: type annotations needed`. The error notes that multiple impls of `NotSame<P, _>` were found,
+    /// where `P` is the duplicated application part builder type. Remove the duplicate part rather than following
+    /// the suggestion to annotate the type.
+    fn build<M>(self) -> ConstructionResult<impl Application + Send>
+    where
+        Self::Chain: Distinct<M>;
 }
 
 /// Concrete implementation of [`ApplicationBuilder`].
@@ -252,7 +362,7 @@ struct ApplicationBuilderConcrete<B> {
 
 impl<C> ApplicationBuilder for ApplicationBuilderConcrete<C>
 where
-    C: ApplicationPartBuilder + Configurable<'static>,
+    C: ApplicationPartBuilder,
 {
     type Chain = C;
 
@@ -264,15 +374,6 @@ where
         B: ApplicationPartBuilder + 'static,
         F: FnOnce() -> B,
     {
-        // gen_assert!((B, C: Configurable<'static>) => !<C as Configurable<'_>>::has_item::<B>());
-
-        // TODO: Enforce this at compile time!
-        assert!(
-            !<C as Configurable<'_>>::has_item::<B>(),
-            "Application part of type {} already added",
-            std::any::type_name::<B>()
-        );
-
         ApplicationBuilderConcrete {
             service_collection: self.service_collection,
             application_part_builder_chain: Node {
@@ -314,7 +415,19 @@ where
         self
     }
 
-    fn build(self) -> ConstructionResult<impl Application + Send> {
+    fn find_unused_services(&self) -> Vec<ServiceToken> {
+        self.service_collection
+            .find_unused_with(self.application_part_builder_chain.dependencies())
+    }
+
+    fn build<M>(self) -> ConstructionResult<impl Application + Send>
+    where
+        Self::Chain: Distinct<M>,
+    {
+        for service in self.find_unused_services() {
+            tracing::debug!(%service, "Service is registered but not used by any application part");
+        }
+
         let service_provider = self.service_collection.build();
 
         Ok(ApplicationConcrete {
@@ -516,7 +629,8 @@ where
         )
     }
 
-    /// Executes the `before_shutdown` lifecycle phase for all collected application parts.
+    /// Executes the `before_shutdown` lifecycle phase for all collected application parts, followed by the
+    /// shutdown hooks registered via [`ServiceProvider::on_shutdown`].
     ///
     /// # Parameters
     /// - `cancellation_token`: A [`CancellationToken`] used to control forceful shutdown.
@@ -535,9 +649,14 @@ where
         tracing::debug!("Executing before_shutdown phase");
         let start = Instant::now();
 
-        self.application_part_chain
+        let result = self
+            .application_part_chain
             .before_shutdown(cancellation_token)
-            .await
+            .await;
+
+        self.service_provider.run_shutdown_hooks().await;
+
+        result
             .inspect(|()| {
             tracing::debug!(
                 took_ms = start.elapsed().as_millis(),
@@ -10,14 +10,13 @@ use crate::{
     application::{
         ApplicationPartBuilder, Chain,
         chain::{InHead, InTail, Index},
-        configurable::Configurable,
         instrumentation::WithInstrumentation,
     },
-    inject::{ConstructionResult, ServiceProvider},
+    inject::{ConstructionResult, ServiceProvider, ServiceToken},
 };
 use either::Either;
 use futures_util::TryFutureExt;
-use std::{any::TypeId, borrow::Cow};
+use std::{borrow::Cow, collections::BTreeSet};
 use tokio_util::sync::CancellationToken;
 
 /// A composite application part that combines two other application parts, `Head` and `Tail`.
@@ -71,21 +70,40 @@ where
         }
     }
 
-    async fn before_startup(
+    fn startup_orders() -> BTreeSet<i32> {
+        let mut startup_orders = Head::startup_orders();
+        startup_orders.append(&mut Tail::startup_orders());
+        startup_orders
+    }
+
+    async fn before_startup_in_order(
         &mut self,
+        startup_order: i32,
         cancellation_token: CancellationToken,
     ) -> Result<(), Self::Error> {
         tokio::try_join!(
             self.head
-                .before_startup(cancellation_token.clone())
+                .before_startup_in_order(startup_order, cancellation_token.clone())
                 .map_err(Either::Left),
             self.tail
-                .before_startup(cancellation_token)
+                .before_startup_in_order(startup_order, cancellation_token)
                 .map_err(Either::Right)
         )
         .map(|_| ())
     }
 
+    async fn before_startup(
+        &mut self,
+        cancellation_token: CancellationToken,
+    ) -> Result<(), Self::Error> {
+        for startup_order in Self::startup_orders() {
+            self.before_startup_in_order(startup_order, cancellation_token.clone())
+                .await?;
+        }
+
+        Ok(())
+    }
+
     async fn run(&mut self, cancellation_token: CancellationToken) -> Result<(), Self::Error> {
         tokio::try_join!(
             self.head
@@ -125,16 +143,11 @@ where
             tail: self.tail.build(service_provider)?,
         })
     }
-}
 
-impl<Head, Tail> Configurable<'static> for Node<Head, Tail>
-where
-    Head: ApplicationPartBuilder + 'static,
-    Tail: ApplicationPartBuilder + Configurable<'static>,
-{
-    fn has_item<I: 'static>() -> bool {
-        // TODO: This is a nasty hack. Can we find another way?
-        TypeId::of::<Head>() == TypeId::of::<I>() || <Tail as Configurable<'_>>::has_item::<I>()
+    fn dependencies(&self) -> Vec<ServiceToken> {
+        let mut dependencies = self.head.dependencies();
+        dependencies.extend(self.tail.dependencies());
+        dependencies
     }
 }
 
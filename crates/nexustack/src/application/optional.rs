@@ -0,0 +1,80 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use crate::{
+    ApplicationPart,
+    application::ApplicationPartBuilder,
+    inject::{ConstructionResult, ServiceProvider, ServiceToken},
+};
+use std::{borrow::Cow, collections::BTreeSet};
+use tokio_util::sync::CancellationToken;
+
+/// An optional application part. All lifecycle hooks are no-ops if the part is disabled.
+impl<T> ApplicationPart for Option<T>
+where
+    T: ApplicationPart + Send + Sync,
+{
+    type Error = T::Error;
+
+    fn name() -> Cow<'static, str> {
+        T::name()
+    }
+
+    fn startup_order() -> i32 {
+        T::startup_order()
+    }
+
+    fn startup_orders() -> BTreeSet<i32> {
+        T::startup_orders()
+    }
+
+    async fn before_startup(
+        &mut self,
+        cancellation_token: CancellationToken,
+    ) -> Result<(), Self::Error> {
+        match self {
+            Some(part) => part.before_startup(cancellation_token).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn run(&mut self, cancellation_token: CancellationToken) -> Result<(), Self::Error> {
+        match self {
+            Some(part) => part.run(cancellation_token).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn before_shutdown(
+        &mut self,
+        cancellation_token: CancellationToken,
+    ) -> Result<(), Self::Error> {
+        match self {
+            Some(part) => part.before_shutdown(cancellation_token).await,
+            None => Ok(()),
+        }
+    }
+}
+
+/// An optional application part builder. A disabled builder builds a disabled application part.
+impl<B> ApplicationPartBuilder for Option<B>
+where
+    B: ApplicationPartBuilder,
+{
+    type ApplicationPart = Option<B::ApplicationPart>;
+
+    fn build(self, service_provider: ServiceProvider) -> ConstructionResult<Self::ApplicationPart> {
+        self.map(|builder| builder.build(service_provider))
+            .transpose()
+    }
+
+    fn dependencies(&self) -> Vec<ServiceToken> {
+        self.as_ref()
+            .map(ApplicationPartBuilder::dependencies)
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,26 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+/// An error representing a failure in parsing the command line or executing a subcommand
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum CliError {
+    /// Raised when the operation was canceled
+    #[error("Operation was canceled")]
+    Canceled,
+
+    /// Raised when the command line arguments are invalid
+    #[error("Invalid command line arguments")]
+    InvalidArguments(#[source] clap::Error),
+
+    /// Raised when the subcommand fails to run
+    #[error("Failed to run subcommand")]
+    RunError(#[source] Box<dyn std::error::Error + Send + Sync>),
+}
+
+/// A cli result representing the result of a subcommand execution
+pub type CliResult<T = ()> = Result<T, CliError>;
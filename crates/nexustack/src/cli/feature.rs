@@ -0,0 +1,399 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use crate::{
+    ApplicationBuilder, ApplicationPart, ApplicationPartBuilder, Index,
+    application::{Here, InHead, InTail, Node},
+    cli::{CliCommand, CliError, CliResult},
+    inject::{ServiceProvider, ServiceScope, ServiceToken},
+};
+use clap::{ArgMatches, Command, error::ErrorKind};
+use futures_util::future::BoxFuture;
+use std::{ffi::OsString, time::Instant};
+use tokio_util::sync::CancellationToken;
+
+/// A trait that extends the `ApplicationBuilder` to add support for command line subcommands.
+///
+/// When the application is started with a registered subcommand, e.g. `app migrate`, the
+/// subcommand is executed during the `before_startup` phase and the application is shut down
+/// afterwards instead of running its long-lived application parts. Without a subcommand, the
+/// application runs as usual.
+pub trait CliApplicationBuilder: ApplicationBuilder {
+    /// Adds command line support to the application.
+    ///
+    /// # Returns
+    /// An updated application builder with command line support added.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use nexustack::{
+    ///     application_builder,
+    ///     Application as _,
+    ///     ApplicationBuilder as _,
+    ///     cli::CliApplicationBuilder as _,
+    /// };
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let app = application_builder()
+    ///     .add_cli()
+    ///     .build()?;
+    ///
+    /// app.run().await?;
+    ///
+    /// #   Ok(())
+    /// # }
+    /// ```
+    fn add_cli(
+        self,
+    ) -> impl ApplicationBuilder<Chain = Node<CliApplicationPartBuilder, Self::Chain>>
+    where
+        Self: Sized;
+
+    /// Configures the subcommands of the application.
+    fn configure_cli<I: Index, F>(
+        self,
+        configure: F,
+    ) -> impl ApplicationBuilder<Chain = Self::Chain>
+    where
+        Self: Sized,
+        Self::Chain: Cli<I>,
+        F: FnOnce(&mut Self::Chain);
+}
+
+impl<B: ApplicationBuilder> CliApplicationBuilder for B {
+    fn add_cli(
+        self,
+    ) -> impl ApplicationBuilder<Chain = Node<CliApplicationPartBuilder, Self::Chain>>
+    where
+        Self: Sized,
+    {
+        self.add_application_part_with_factory(|| CliApplicationPartBuilder {
+            commands: Vec::new(),
+            dependencies: Vec::new(),
+            args: None,
+        })
+    }
+
+    fn configure_cli<I: Index, F>(
+        self,
+        configure: F,
+    ) -> impl ApplicationBuilder<Chain = Self::Chain>
+    where
+        Self: Sized,
+        Self::Chain: Cli<I>,
+        F: FnOnce(&mut Self::Chain),
+    {
+        self.configure_chain(configure)
+    }
+}
+
+/// A trait for configuring the subcommands of an application.
+///
+/// This trait is typically implemented by application parts or nodes in the application builder
+/// chain.
+pub trait Cli<Index> {
+    /// Adds a subcommand of the specified type to the application.
+    ///
+    /// # Type Parameters
+    /// - `Command`: The type of the subcommand to add. The type must implement the [`CliCommand`] trait.
+    ///
+    /// # Returns
+    /// A mutable reference to `Self`, enabling method chaining.
+    fn add_cli_command<Command>(&mut self) -> &mut Self
+    where
+        Command: CliCommand + 'static;
+
+    /// Sets the command line arguments to parse instead of the arguments of the process.
+    ///
+    /// The first argument is the binary name. This is mainly useful for testing.
+    ///
+    /// # Returns
+    /// A mutable reference to `Self`, enabling method chaining.
+    fn set_args<Args, Arg>(&mut self, args: Args) -> &mut Self
+    where
+        Args: IntoIterator<Item = Arg>,
+        Arg: Into<OsString>;
+}
+
+impl<Head, Tail, HeadIndex> Cli<InHead<HeadIndex>> for Node<Head, Tail>
+where
+    HeadIndex: Index,
+    Head: Cli<HeadIndex>,
+{
+    fn add_cli_command<Command>(&mut self) -> &mut Self
+    where
+        Command: CliCommand + 'static,
+    {
+        self.head.add_cli_command::<Command>();
+        self
+    }
+
+    fn set_args<Args, Arg>(&mut self, args: Args) -> &mut Self
+    where
+        Args: IntoIterator<Item = Arg>,
+        Arg: Into<OsString>,
+    {
+        self.head.set_args(args);
+        self
+    }
+}
+
+impl<Head, Tail, TailIndex> Cli<InTail<TailIndex>> for Node<Head, Tail>
+where
+    TailIndex: Index,
+    Tail: Cli<TailIndex>,
+{
+    fn add_cli_command<Command>(&mut self) -> &mut Self
+    where
+        Command: CliCommand + 'static,
+    {
+        self.tail.add_cli_command::<Command>();
+        self
+    }
+
+    fn set_args<Args, Arg>(&mut self, args: Args) -> &mut Self
+    where
+        Args: IntoIterator<Item = Arg>,
+        Arg: Into<OsString>,
+    {
+        self.tail.set_args(args);
+        self
+    }
+}
+
+impl Cli<Here> for CliApplicationPartBuilder {
+    fn add_cli_command<Command>(&mut self) -> &mut Self
+    where
+        Command: CliCommand + 'static,
+    {
+        self.commands.push(CliCommandEntry {
+            command: Command::command(),
+            run: Box::new(|service_provider, matches| {
+                Box::pin(Command::run(service_provider, matches))
+            }),
+        });
+        self.dependencies.extend(Command::dependencies());
+        self
+    }
+
+    fn set_args<Args, Arg>(&mut self, args: Args) -> &mut Self
+    where
+        Args: IntoIterator<Item = Arg>,
+        Arg: Into<OsString>,
+    {
+        self.args = Some(args.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+type CliCommandRun =
+    Box<dyn Fn(ServiceProvider, ArgMatches) -> BoxFuture<'static, CliResult> + Send + Sync>;
+
+struct CliCommandEntry {
+    command: Command,
+    run: CliCommandRun,
+}
+
+pub struct CliApplicationPartBuilder {
+    commands: Vec<CliCommandEntry>,
+    dependencies: Vec<ServiceToken>,
+    args: Option<Vec<OsString>>,
+}
+
+impl ApplicationPartBuilder for CliApplicationPartBuilder {
+    type ApplicationPart = CliApplicationPart;
+
+    fn build(
+        self,
+        service_provider: ServiceProvider,
+    ) -> crate::inject::ConstructionResult<Self::ApplicationPart> {
+        Ok(CliApplicationPart {
+            commands: self.commands,
+            args: self.args,
+            service_provider,
+        })
+    }
+
+    fn dependencies(&self) -> Vec<ServiceToken> {
+        self.dependencies.clone()
+    }
+}
+
+pub struct CliApplicationPart {
+    commands: Vec<CliCommandEntry>,
+    args: Option<Vec<OsString>>,
+    service_provider: ServiceProvider,
+}
+
+impl CliApplicationPart {
+    fn root_command(&self) -> Command {
+        // The binary name shown in the usage is taken from the first argument when parsing.
+        Command::new("app").subcommands(self.commands.iter().map(|entry| entry.command.clone()))
+    }
+}
+
+impl ApplicationPart for CliApplicationPart {
+    type Error = CliError;
+
+    fn startup_order() -> i32 {
+        i32::MIN
+    }
+
+    #[tracing::instrument(name = "cli.dispatch", skip(self, cancellation_token))]
+    async fn before_startup(
+        &mut self,
+        cancellation_token: CancellationToken,
+    ) -> Result<(), Self::Error> {
+        let args = self
+            .args
+            .take()
+            .unwrap_or_else(|| std::env::args_os().collect());
+
+        let matches = match self.root_command().try_get_matches_from(args) {
+            Ok(matches) => matches,
+            Err(err)
+                if matches!(
+                    err.kind(),
+                    ErrorKind::DisplayHelp | ErrorKind::DisplayVersion
+                ) =>
+            {
+                let _ = err.print();
+                cancellation_token.cancel();
+                return Ok(());
+            }
+            Err(err) => return Err(CliError::InvalidArguments(err)),
+        };
+
+        let Some((name, matches)) = matches.subcommand() else {
+            tracing::debug!("No subcommand given, running application");
+            return Ok(());
+        };
+
+        let entry = self
+            .commands
+            .iter()
+            .find(|entry| entry.command.get_name() == name)
+            .expect("Parsed subcommand must be registered");
+
+        let start = Instant::now();
+        tracing::debug!(subcommand = name, "Running subcommand");
+
+        let service_scope = self
+            .service_provider
+            .resolve::<ServiceScope>()
+            .map_err(|err| CliError::RunError(err.into()))
+            .inspect_err(|err| tracing::error!(%err, "Failed to resolve service scope"))?;
+
+        let result = cancellation_token
+            .run_until_cancelled((entry.run)(
+                service_scope.service_provider().clone(),
+                matches.clone(),
+            ))
+            .await
+            .ok_or(CliError::Canceled)
+            .flatten();
+
+        // The subcommand replaces the long-lived application parts, so the application shuts down.
+        cancellation_token.cancel();
+
+        result
+            .inspect(|()| {
+                tracing::debug!(took_ms = start.elapsed().as_millis(), "Subcommand executed successfully");
+            })
+            .inspect_err(|err| {
+                tracing::error!(took_ms = start.elapsed().as_millis(), %err, "Error during subcommand execution");
+            })
+    }
+
+    async fn run(&mut self, _cancellation_token: CancellationToken) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        Application as _, ApplicationBuilder as _, ApplicationPart, ApplicationPartBuilder,
+        application_builder,
+        cli::{Cli as _, CliApplicationBuilder as _, CliCommand, CliError, CliResult},
+        inject::{ConstructionResult, ServiceProvider},
+    };
+    use clap::{Arg, ArgMatches, Command};
+    use std::{
+        convert::Infallible,
+        sync::{Arc, Mutex},
+    };
+    use tokio_util::sync::CancellationToken;
+
+    #[derive(Clone, Default)]
+    struct Migrator {
+        migrated_to: Arc<Mutex<Option<String>>>,
+    }
+
+    struct MigrateCommand;
+
+    impl CliCommand for MigrateCommand {
+        fn command() -> Command {
+            Command::new("migrate").arg(Arg::new("to").long("to"))
+        }
+
+        async fn run(service_provider: ServiceProvider, matches: ArgMatches) -> CliResult {
+            let migrator = service_provider
+                .resolve::<Migrator>()
+                .map_err(|err| CliError::RunError(err.into()))?;
+
+            *migrator.migrated_to.lock().unwrap() = matches.get_one::<String>("to").cloned();
+            Ok(())
+        }
+    }
+
+    /// A long-lived application part that runs until the application is canceled.
+    #[derive(Default)]
+    struct ServerPart;
+
+    impl ApplicationPart for ServerPart {
+        type Error = Infallible;
+
+        async fn run(&mut self, cancellation_token: CancellationToken) -> Result<(), Self::Error> {
+            cancellation_token.cancelled().await;
+            Ok(())
+        }
+    }
+
+    impl ApplicationPartBuilder for ServerPart {
+        type ApplicationPart = Self;
+
+        fn build(self, _service_provider: ServiceProvider) -> ConstructionResult<Self> {
+            Ok(self)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subcommand_runs_with_resolved_services() {
+        let migrator = Migrator::default();
+
+        let app = application_builder()
+            .configure_services(|services| {
+                services.add_value(migrator.clone());
+            })
+            .add_application_part::<ServerPart>()
+            .add_cli()
+            .configure_cli(|cli| {
+                cli.add_cli_command::<MigrateCommand>()
+                    .set_args(["app", "migrate", "--to", "3"]);
+            })
+            .build()
+            .unwrap();
+
+        app.run_with_cancellation_token(CancellationToken::new())
+            .await
+            .unwrap();
+
+        pretty_assertions::assert_eq!(*migrator.migrated_to.lock().unwrap(), Some("3".to_owned()));
+    }
+}
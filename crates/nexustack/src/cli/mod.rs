@@ -0,0 +1,59 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use crate::inject::{ServiceProvider, ServiceToken};
+use clap::{ArgMatches, Command};
+
+mod error;
+mod feature;
+
+pub use error::{CliError, CliResult};
+pub use feature::{Cli, CliApplicationBuilder};
+
+/// A module that re-exports the [`mod@clap`] crate.
+///
+/// This module provides access to all the types and functionality of the
+/// [`clap`](https://docs.rs/clap) crate. For detailed documentation, refer to
+/// the [`clap` crate documentation](https://docs.rs/clap).
+#[path = ""]
+pub mod args {
+    pub use ::clap::*;
+}
+
+/// A trait representing a subcommand of the application's command line interface.
+///
+/// Implement this trait to define the arguments and the execution logic of a subcommand.
+pub trait CliCommand {
+    /// Defines the subcommand, including its name and arguments.
+    ///
+    /// # Returns
+    /// The [`Command`] describing the subcommand.
+    fn command() -> Command;
+
+    /// Executes the logic for the subcommand.
+    ///
+    /// # Parameters
+    /// - `service_provider`: A scoped [`ServiceProvider`] instance that provides access to
+    ///   the application's services and dependencies.
+    /// - `matches`: The parsed arguments of the subcommand.
+    ///
+    /// # Returns
+    /// A future that resolves to a [`CliResult`] indicating the success or failure of the subcommand.
+    fn run(
+        service_provider: ServiceProvider,
+        matches: ArgMatches,
+    ) -> impl Future<Output = CliResult> + Send;
+
+    /// Returns the services that are resolved from the service provider when running the subcommand.
+    ///
+    /// This is used to compute the services used by the application, see
+    /// [`crate::inject::ServiceCollection::find_unused`]. Defaults to no dependencies.
+    #[must_use]
+    fn dependencies() -> Vec<ServiceToken> {
+        Vec::new()
+    }
+}
@@ -6,9 +6,13 @@
  */
 
 use crate::cron::{CronError, CronResult};
-use chrono::{DateTime, TimeZone, Utc};
-use std::pin::Pin;
-use tokio::time::sleep;
+use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+use std::{
+    pin::Pin,
+    sync::{Arc, Mutex, PoisonError},
+    time::Duration,
+};
+use tokio::{sync::Notify, time::sleep};
 use tokio_util::sync::CancellationToken;
 
 /// A trait that abstracts access to the current date and time.
@@ -143,3 +147,125 @@ where
         Box::pin(delay_until_impl(self, date_time, cancellation_token))
     }
 }
+
+#[derive(Debug)]
+struct MockCronClockState<Timezone: chrono::TimeZone> {
+    now: Mutex<DateTime<Timezone>>,
+    changed: Notify,
+}
+
+/// A manually driven implementation of the `CronClock` trait for tests.
+///
+/// Time only moves when [`MockCronClock::advance`] or [`MockCronClock::set`] is called, so jobs
+/// fire on a controlled schedule without real waiting. Clones share the same time, which allows
+/// registering one clone as a service while the test keeps another to drive it.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone as _, Utc};
+/// use nexustack::cron::{CronClock as _, MockCronClock};
+/// use std::time::Duration;
+///
+/// let clock = MockCronClock::new(Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap());
+/// clock.advance(Duration::from_secs(30));
+///
+/// assert_eq!(clock.now(), Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 30).unwrap());
+/// ```
+#[derive(Clone, Debug)]
+pub struct MockCronClock<Timezone: chrono::TimeZone = Utc> {
+    state: Arc<MockCronClockState<Timezone>>,
+}
+
+impl<Timezone: chrono::TimeZone> MockCronClock<Timezone> {
+    /// Creates a mock clock that stands still at `now` and operates in the time zone of `now`.
+    #[must_use]
+    pub fn new(now: DateTime<Timezone>) -> Self {
+        Self {
+            state: Arc::new(MockCronClockState {
+                now: Mutex::new(now),
+                changed: Notify::new(),
+            }),
+        }
+    }
+
+    /// Sets the current time of the clock and wakes up all pending delays that are due.
+    pub fn set(&self, now: DateTime<Timezone>) {
+        *self
+            .state
+            .now
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = now;
+        self.state.changed.notify_waiters();
+    }
+
+    /// Moves the current time of the clock forward and wakes up all pending delays that are due.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `duration` exceeds the range of [`TimeDelta`].
+    pub fn advance(&self, duration: Duration) {
+        let duration = TimeDelta::from_std(duration).expect("duration out of range");
+        {
+            let mut now = self
+                .state
+                .now
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            *now += duration;
+        }
+        self.state.changed.notify_waiters();
+    }
+}
+
+impl<Timezone> CronClock for MockCronClock<Timezone>
+where
+    Timezone: chrono::TimeZone + Send + Sync,
+    <Timezone as TimeZone>::Offset: Send,
+{
+    type TimeZone = Timezone;
+    type DelayUntilFuture<'a>
+        = Pin<Box<dyn Future<Output = CronResult<DateTime<Timezone>>> + Send + 'a>>
+    where
+        Timezone: 'a;
+
+    fn timezone(&self) -> Self::TimeZone {
+        self.now().timezone()
+    }
+
+    fn now(&self) -> DateTime<Self::TimeZone> {
+        self.state
+            .now
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    fn delay_until(
+        &self,
+        date_time: DateTime<Self::TimeZone>,
+        cancellation_token: CancellationToken,
+    ) -> Self::DelayUntilFuture<'_> {
+        Box::pin(async move {
+            loop {
+                // Register for the next change before reading the time, so no update is missed.
+                let changed = self.state.changed.notified();
+                tokio::pin!(changed);
+                changed.as_mut().enable();
+
+                let now = self.now();
+
+                if now >= date_time {
+                    return Ok(now);
+                }
+
+                tokio::select! {
+                    () = changed => { }
+                    () = cancellation_token.cancelled() => {
+                        return Err(CronError::Canceled);
+                    }
+                }
+            }
+        })
+    }
+}
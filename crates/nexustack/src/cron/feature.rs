@@ -8,11 +8,19 @@
 use crate::{
     ApplicationBuilder, ApplicationPart, ApplicationPartBuilder, Index,
     application::{Here, InHead, InTail, Node},
-    cron::{CronClock, CronError, CronJob, CronResult, DefaultCronClock},
-    inject::{ServiceProvider, ServiceScope},
+    cron::{CronClock, CronError, CronJob, CronLock, CronResult, CronRunReport, DefaultCronClock},
+    inject::{ServiceProvider, ServiceScope, ServiceToken},
+};
+use chrono::{DateTime, TimeZone, Utc};
+use cron::Schedule;
+use std::{
+    borrow::Cow,
+    fmt::Write as _,
+    iter::Peekable,
+    marker::PhantomData,
+    sync::Arc,
+    time::{Duration, Instant},
 };
-use chrono::TimeZone;
-use std::{borrow::Cow, fmt::Write as _, marker::PhantomData, time::Instant};
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
 
@@ -105,6 +113,92 @@ pub trait CronApplicationBuilder: ApplicationBuilder {
         Self: Sized,
         Self::Chain: Cron<I>,
         F: FnOnce(&mut Self::Chain);
+
+    /// Registers an observer that is invoked after each execution of a cron job.
+    ///
+    /// The observer receives a [`CronRunReport`] containing the name of the cron job, the time
+    /// the execution took and whether it succeeded. This can be used to export metrics, e.g. to
+    /// Prometheus. Canceled executions are not reported.
+    ///
+    /// # Example
+    /// ```rust
+    /// use nexustack::{
+    ///     application_builder,
+    ///     ApplicationBuilder as _,
+    ///     cron::CronApplicationBuilder as _,
+    /// };
+    ///
+    /// let app = application_builder()
+    ///     .add_cron_with_default_clock()
+    ///     .with_run_observer(|report| {
+    ///         println!(
+    ///             "{} took {:?} (success: {})",
+    ///             report.name(),
+    ///             report.duration(),
+    ///             report.is_success(),
+    ///         );
+    ///     });
+    /// ```
+    fn with_run_observer<I: Index, F>(
+        self,
+        observer: F,
+    ) -> impl ApplicationBuilder<Chain = Self::Chain>
+    where
+        Self: Sized,
+        Self::Chain: Cron<I>,
+        F: Fn(&CronRunReport<'_>) + Send + Sync + 'static;
+
+    /// Re-evaluates the schedules of all cron jobs periodically.
+    ///
+    /// By default, the schedule of a cron job is resolved once at application startup. With a
+    /// refresh interval, [`CronJob::schedule`] is re-evaluated after each interval, so that
+    /// changes to a configuration-backed schedule (see `schedule_with`) take effect without a
+    /// restart. A cron job that is currently executing completes its run before the refreshed
+    /// schedule is applied. If the schedule cannot be refreshed, the current schedule is kept.
+    ///
+    /// # Example
+    /// ```rust
+    /// use nexustack::{
+    ///     application_builder,
+    ///     ApplicationBuilder as _,
+    ///     cron::CronApplicationBuilder as _,
+    /// };
+    /// use std::time::Duration;
+    ///
+    /// let app = application_builder()
+    ///     .add_cron_with_default_clock()
+    ///     .with_schedule_refresh(Duration::from_secs(60));
+    /// ```
+    fn with_schedule_refresh<I: Index>(
+        self,
+        interval: Duration,
+    ) -> impl ApplicationBuilder<Chain = Self::Chain>
+    where
+        Self: Sized,
+        Self::Chain: Cron<I>;
+
+    /// Uses the specified lock to ensure each scheduled run is executed by a single instance only.
+    ///
+    /// Before executing a scheduled run, the cron job acquires the run from the lock. If another
+    /// instance already acquired the run, it is skipped. See [`CronLock`] for details.
+    ///
+    /// # Example
+    /// ```rust
+    /// use nexustack::{
+    ///     application_builder,
+    ///     ApplicationBuilder as _,
+    ///     cron::{CronApplicationBuilder as _, InMemoryCronLock},
+    /// };
+    ///
+    /// let app = application_builder()
+    ///     .add_cron_with_default_clock()
+    ///     .with_lock(InMemoryCronLock::new());
+    /// ```
+    fn with_lock<I: Index, L>(self, lock: L) -> impl ApplicationBuilder<Chain = Self::Chain>
+    where
+        Self: Sized,
+        Self::Chain: Cron<I>,
+        L: CronLock + 'static;
 }
 
 impl<B: ApplicationBuilder> CronApplicationBuilder for B {
@@ -118,6 +212,10 @@ impl<B: ApplicationBuilder> CronApplicationBuilder for B {
             _clock: PhantomData,
             cron_job_names: String::new(),
             cron_task_factories: Vec::new(),
+            dependencies: vec![ServiceToken::create::<Clock>()],
+            run_observers: Vec::new(),
+            schedule_refresh: None,
+            lock: None,
         })
     }
 
@@ -144,6 +242,44 @@ impl<B: ApplicationBuilder> CronApplicationBuilder for B {
     {
         self.configure_chain(configure)
     }
+
+    fn with_run_observer<I: Index, F>(
+        self,
+        observer: F,
+    ) -> impl ApplicationBuilder<Chain = Self::Chain>
+    where
+        Self: Sized,
+        Self::Chain: Cron<I>,
+        F: Fn(&CronRunReport<'_>) + Send + Sync + 'static,
+    {
+        self.configure_chain(|chain| {
+            chain.add_run_observer(observer);
+        })
+    }
+
+    fn with_schedule_refresh<I: Index>(
+        self,
+        interval: Duration,
+    ) -> impl ApplicationBuilder<Chain = Self::Chain>
+    where
+        Self: Sized,
+        Self::Chain: Cron<I>,
+    {
+        self.configure_chain(move |chain| {
+            chain.set_schedule_refresh(interval);
+        })
+    }
+
+    fn with_lock<I: Index, L>(self, lock: L) -> impl ApplicationBuilder<Chain = Self::Chain>
+    where
+        Self: Sized,
+        Self::Chain: Cron<I>,
+        L: CronLock + 'static,
+    {
+        self.configure_chain(|chain| {
+            chain.set_lock(lock);
+        })
+    }
 }
 
 /// A trait for configuring and managing cron jobs within an application.
@@ -164,6 +300,28 @@ pub trait Cron<Index> {
     fn add_cron_job<Job>(&mut self) -> &mut Self
     where
         Job: CronJob + 'static;
+
+    /// Adds an observer that is invoked after each execution of a cron job.
+    ///
+    /// # Returns
+    /// A mutable reference to `Self`, enabling method chaining.
+    fn add_run_observer<F>(&mut self, observer: F) -> &mut Self
+    where
+        F: Fn(&CronRunReport<'_>) + Send + Sync + 'static;
+
+    /// Sets the interval after which the schedules of all cron jobs are re-evaluated.
+    ///
+    /// # Returns
+    /// A mutable reference to `Self`, enabling method chaining.
+    fn set_schedule_refresh(&mut self, interval: Duration) -> &mut Self;
+
+    /// Sets the lock that ensures each scheduled run is executed by a single instance only.
+    ///
+    /// # Returns
+    /// A mutable reference to `Self`, enabling method chaining.
+    fn set_lock<L>(&mut self, lock: L) -> &mut Self
+    where
+        L: CronLock + 'static;
 }
 
 impl<Head, Tail, HeadIndex> Cron<InHead<HeadIndex>> for Node<Head, Tail>
@@ -178,6 +336,27 @@ where
         self.head.add_cron_job::<Job>();
         self
     }
+
+    fn add_run_observer<F>(&mut self, observer: F) -> &mut Self
+    where
+        F: Fn(&CronRunReport<'_>) + Send + Sync + 'static,
+    {
+        self.head.add_run_observer(observer);
+        self
+    }
+
+    fn set_schedule_refresh(&mut self, interval: Duration) -> &mut Self {
+        self.head.set_schedule_refresh(interval);
+        self
+    }
+
+    fn set_lock<L>(&mut self, lock: L) -> &mut Self
+    where
+        L: CronLock + 'static,
+    {
+        self.head.set_lock(lock);
+        self
+    }
 }
 
 impl<Head, Tail, TailIndex> Cron<InTail<TailIndex>> for Node<Head, Tail>
@@ -192,6 +371,27 @@ where
         self.tail.add_cron_job::<Job>();
         self
     }
+
+    fn add_run_observer<F>(&mut self, observer: F) -> &mut Self
+    where
+        F: Fn(&CronRunReport<'_>) + Send + Sync + 'static,
+    {
+        self.tail.add_run_observer(observer);
+        self
+    }
+
+    fn set_schedule_refresh(&mut self, interval: Duration) -> &mut Self {
+        self.tail.set_schedule_refresh(interval);
+        self
+    }
+
+    fn set_lock<L>(&mut self, lock: L) -> &mut Self
+    where
+        L: CronLock + 'static,
+    {
+        self.tail.set_lock(lock);
+        self
+    }
 }
 
 impl<Clock> Cron<Here> for CronApplicationPartBuilder<Clock>
@@ -204,10 +404,13 @@ where
         Job: CronJob + 'static,
     {
         self.cron_task_factories.push(Box::new(
-            |service_provider: ServiceProvider, cancellation_token: CancellationToken| {
-                cron_job_task::<Job, Clock>(service_provider, cancellation_token)
+            |service_provider: ServiceProvider,
+             cancellation_token: CancellationToken,
+             options: CronTaskOptions| {
+                cron_job_task::<Job, Clock>(service_provider, cancellation_token, options)
             },
         ));
+        self.dependencies.extend(Job::dependencies());
         if self.cron_job_names.is_empty() {
             write!(self.cron_job_names, "{}", cron_job_name::<Job>())
                 .expect("Failed to write cron job name");
@@ -217,11 +420,33 @@ where
         }
         self
     }
+
+    fn add_run_observer<F>(&mut self, observer: F) -> &mut Self
+    where
+        F: Fn(&CronRunReport<'_>) + Send + Sync + 'static,
+    {
+        self.run_observers.push(Arc::new(observer));
+        self
+    }
+
+    fn set_schedule_refresh(&mut self, interval: Duration) -> &mut Self {
+        self.schedule_refresh = Some(interval);
+        self
+    }
+
+    fn set_lock<L>(&mut self, lock: L) -> &mut Self
+    where
+        L: CronLock + 'static,
+    {
+        self.lock = Some(Arc::new(lock));
+        self
+    }
 }
 
 fn cron_job_task<Job, Clock>(
     service_provider: ServiceProvider,
     cancellation_token: CancellationToken,
+    options: CronTaskOptions,
 ) -> JoinHandle<CronResult>
 where
     Job: CronJob + 'static,
@@ -231,6 +456,7 @@ where
     tokio::spawn(run_cron_job::<Job, Clock>(
         service_provider,
         cancellation_token,
+        options,
     ))
 }
 
@@ -243,12 +469,13 @@ where
 
 #[tracing::instrument(
     name = "cron_job.run",
-    skip(service_provider, cancellation_token),
+    skip(service_provider, cancellation_token, run_observers),
     fields(cron_job = cron_job_name::<Job>().to_string())
 )]
 async fn execute_job<Job, Clock>(
     service_provider: ServiceProvider,
     cancellation_token: CancellationToken,
+    run_observers: &[CronRunObserver],
 ) -> CronResult<()>
 where
     Job: CronJob,
@@ -264,11 +491,25 @@ where
         .inspect_err(|err| tracing::error!(%err, "Failed to resolve service scope"))?;
     let scoped_service_provider = service_scope.service_provider();
 
-    cancellation_token
+    let result = cancellation_token
         .run_until_cancelled_owned(Job::run(scoped_service_provider.clone()))
         .await
         .ok_or_else(|| CronError::Canceled)
-        .flatten()
+        .flatten();
+
+    if !matches!(result, Err(CronError::Canceled)) {
+        let report = CronRunReport::new(
+            cron_job_name::<Job>(),
+            start.elapsed(),
+            result.as_ref().err(),
+        );
+
+        for run_observer in run_observers {
+            run_observer(&report);
+        }
+    }
+
+    result
         .inspect_err(|err| {
             if matches!(err, CronError::Canceled) {
                 tracing::debug!(took_ms = start.elapsed().as_millis(), "Cron job execution was canceled");
@@ -281,14 +522,64 @@ where
         })
 }
 
+/// Executes the scheduled run at `fire_time`, unless another instance acquired the run.
+async fn execute_scheduled_job<Job, Clock>(
+    service_provider: ServiceProvider,
+    cancellation_token: CancellationToken,
+    options: &CronTaskOptions,
+    fire_time: DateTime<Utc>,
+) -> CronResult<()>
+where
+    Job: CronJob,
+    Clock: CronClock + Send + 'static,
+    <<Clock as CronClock>::TimeZone as TimeZone>::Offset: Send,
+{
+    let Some(lock) = &options.lock else {
+        return execute_job::<Job, Clock>(
+            service_provider,
+            cancellation_token,
+            &options.run_observers,
+        )
+        .await;
+    };
+
+    let job_name = cron_job_name::<Job>();
+
+    match lock.acquire(&job_name, fire_time).await {
+        Ok(true) => {}
+        Ok(false) => {
+            tracing::debug!(
+                fire_time = %fire_time.to_rfc3339(),
+                "Skipping cron job run acquired by another instance"
+            );
+            return Ok(());
+        }
+        Err(err) => {
+            tracing::error!(%err, fire_time = %fire_time.to_rfc3339(), "Failed to acquire cron lock, skipping run");
+            return Ok(());
+        }
+    }
+
+    let result =
+        execute_job::<Job, Clock>(service_provider, cancellation_token, &options.run_observers)
+            .await;
+
+    if let Err(err) = lock.release(&job_name, fire_time).await {
+        tracing::error!(%err, fire_time = %fire_time.to_rfc3339(), "Failed to release cron lock");
+    }
+
+    result
+}
+
 #[tracing::instrument(
     name = "cron_job.task",
-    skip(service_provider, cancellation_token),
+    skip(service_provider, cancellation_token, options),
     fields(cron_job = cron_job_name::<Job>().to_string())
 )]
 async fn run_cron_job<Job, Clock>(
     service_provider: ServiceProvider,
     cancellation_token: CancellationToken,
+    options: CronTaskOptions,
 ) -> CronResult<()>
 where
     Job: CronJob,
@@ -303,12 +594,20 @@ where
         .map_err(|err| CronError::RunError(err.into()))
         .inspect_err(|err| tracing::error!(%err, "Failed to resolve clock"))?;
 
-    let schedule = cancellation_token
-        .clone()
-        .run_until_cancelled_owned(Job::schedule(service_provider.clone()))
+    if let Some(delay) = Job::run_once_after() {
+        run_delayed_job::<Job, Clock>(clock, service_provider, cancellation_token, &options, delay)
+            .await?;
+
+        tracing::debug!(
+            took_ms = start.elapsed().as_millis(),
+            "Cron job task completed successfully"
+        );
+
+        return Ok(());
+    }
+
+    let schedule = resolve_schedule::<Job>(&service_provider, &cancellation_token)
         .await
-        .ok_or_else(|| CronError::Canceled)
-        .flatten()
         .inspect_err(|err| {
             if matches!(err, CronError::Canceled) {
                 tracing::debug!("Cron job task was canceled");
@@ -317,10 +616,49 @@ where
             }
         })?;
 
-    let now = clock.now();
-    let upcoming_iter = schedule.after(&now);
+    let jitter = Job::jitter();
+    let mut rng = fastrand::Rng::new();
+
+    let mut refresh_at = next_schedule_refresh(&clock, options.schedule_refresh);
+    let mut upcoming_iter = schedule.after_owned(clock.now()).peekable();
+
+    while let Some(upcoming) = upcoming_iter.peek().cloned() {
+        if let Some(refresh) = refresh_at.clone()
+            && refresh < upcoming
+        {
+            clock
+                .delay_until(refresh, cancellation_token.clone())
+                .await
+                .inspect_err(|err| {
+                    if matches!(err, CronError::Canceled) {
+                        tracing::debug!("Cron job task was canceled");
+                    } else {
+                        tracing::error!(%err, "Failed to delay until next schedule refresh");
+                    }
+                })?;
+
+            match resolve_schedule::<Job>(&service_provider, &cancellation_token).await {
+                Ok(schedule) => {
+                    tracing::trace!("Refreshed schedule for cron job");
+                    upcoming_iter = schedule.after_owned(clock.now()).peekable();
+                }
+                Err(CronError::Canceled) => {
+                    tracing::debug!("Cron job task was canceled");
+                    return Err(CronError::Canceled);
+                }
+                Err(err) => {
+                    tracing::warn!(%err, "Failed to refresh schedule, keeping the current schedule");
+                }
+            }
+
+            refresh_at = next_schedule_refresh(&clock, options.schedule_refresh);
+            continue;
+        }
+
+        upcoming_iter.next();
+        let fire_time = upcoming.with_timezone(&Utc);
+        let upcoming = jittered(upcoming, jitter, &mut rng);
 
-    for upcoming in upcoming_iter {
         tracing::trace!(
             next_run = %upcoming.to_rfc3339(),
             "Next scheduled run for cron job",
@@ -337,7 +675,15 @@ where
                 }
             })?;
 
-        execute_job::<Job, Clock>(service_provider.clone(), cancellation_token.clone()).await?;
+        for fire_time in due_fire_times::<Job, _>(fire_time, &mut upcoming_iter, &clock.now()) {
+            execute_scheduled_job::<Job, Clock>(
+                service_provider.clone(),
+                cancellation_token.clone(),
+                &options,
+                fire_time,
+            )
+            .await?;
+        }
     }
 
     tracing::debug!(
@@ -348,13 +694,146 @@ where
     Ok(())
 }
 
-type CronTaskFactory =
-    Box<dyn FnOnce(ServiceProvider, CancellationToken) -> JoinHandle<CronResult> + Send + Sync>;
+/// Runs a one-shot job once `delay` elapsed, after which the job is retired.
+async fn run_delayed_job<Job, Clock>(
+    clock: Clock,
+    service_provider: ServiceProvider,
+    cancellation_token: CancellationToken,
+    options: &CronTaskOptions,
+    delay: Duration,
+) -> CronResult<()>
+where
+    Job: CronJob,
+    Clock: CronClock + Send + 'static,
+    <<Clock as CronClock>::TimeZone as TimeZone>::Offset: Send,
+{
+    let run_at = chrono::TimeDelta::from_std(delay)
+        .ok()
+        .and_then(|delay| clock.now().checked_add_signed(delay))
+        .ok_or_else(|| CronError::ScheduleError("delay of the single run is out of range".into()))
+        .inspect_err(|err| tracing::error!(%err, "Failed to schedule single run"))?;
+
+    tracing::trace!(
+        next_run = %run_at.to_rfc3339(),
+        "Single run for cron job",
+    );
+
+    clock
+        .delay_until(run_at, cancellation_token.clone())
+        .await
+        .inspect_err(|err| {
+            if matches!(err, CronError::Canceled) {
+                tracing::debug!("Cron job task was canceled");
+            } else {
+                tracing::error!(%err, "Failed to delay until single run");
+            }
+        })?;
+
+    execute_job::<Job, Clock>(service_provider, cancellation_token, &options.run_observers).await
+}
+
+async fn resolve_schedule<Job: CronJob>(
+    service_provider: &ServiceProvider,
+    cancellation_token: &CancellationToken,
+) -> CronResult<Schedule> {
+    cancellation_token
+        .clone()
+        .run_until_cancelled_owned(Job::schedule(service_provider.clone()))
+        .await
+        .ok_or_else(|| CronError::Canceled)
+        .flatten()
+}
+
+/// Returns the fire times of the runs to execute once the run scheduled at `fire_time` is due.
+///
+/// The run is late if the runs scheduled after it are due as well, even when delayed by the full jitter, e.g.
+/// because the process was suspended or a previous run took longer than the interval between two runs. In this case,
+/// the run and all runs due until `now` are consumed as missed runs, of which the job's
+/// [`crate::cron::MissedRunPolicy`] selects the runs to catch up. Runs that are only due because of the jitter are
+/// not missed, so that a jitter exceeding the interval between two runs does not drop or bunch up runs.
+fn due_fire_times<Job: CronJob, Tz: TimeZone>(
+    fire_time: DateTime<Utc>,
+    upcoming_iter: &mut Peekable<impl Iterator<Item = DateTime<Tz>>>,
+    now: &DateTime<Tz>,
+) -> Vec<DateTime<Utc>> {
+    let mut missed = vec![fire_time];
+
+    let jitter = chrono::TimeDelta::from_std(Job::jitter()).unwrap_or(chrono::TimeDelta::MAX);
+    let is_missed = |next: &DateTime<Tz>| {
+        next.clone()
+            .checked_add_signed(jitter)
+            .is_some_and(|latest| latest <= *now)
+    };
+
+    while let Some(next) = upcoming_iter.next_if(is_missed) {
+        missed.push(next.with_timezone(&Utc));
+    }
+
+    if missed.len() == 1 {
+        return missed;
+    }
+
+    let catch_up_runs = Job::missed_run_policy().catch_up_runs(missed.len());
+
+    tracing::debug!(
+        missed = missed.len(),
+        catch_up_runs,
+        "Cron job missed scheduled runs"
+    );
+
+    missed.split_off(missed.len() - catch_up_runs)
+}
+
+fn next_schedule_refresh<Clock: CronClock>(
+    clock: &Clock,
+    schedule_refresh: Option<Duration>,
+) -> Option<DateTime<Clock::TimeZone>> {
+    let schedule_refresh = chrono::TimeDelta::from_std(schedule_refresh?).ok()?;
+    clock.now().checked_add_signed(schedule_refresh)
+}
+
+/// Offsets `upcoming` by a random duration within `0..=jitter`.
+fn jittered<Tz: TimeZone>(
+    upcoming: DateTime<Tz>,
+    jitter: Duration,
+    rng: &mut fastrand::Rng,
+) -> DateTime<Tz> {
+    if jitter.is_zero() {
+        return upcoming;
+    }
+
+    let max_nanos = u64::try_from(jitter.as_nanos()).unwrap_or(u64::MAX);
+    let offset = Duration::from_nanos(rng.u64(0..=max_nanos));
+
+    chrono::TimeDelta::from_std(offset)
+        .ok()
+        .and_then(|offset| upcoming.clone().checked_add_signed(offset))
+        .unwrap_or(upcoming)
+}
+
+type CronRunObserver = Arc<dyn Fn(&CronRunReport<'_>) + Send + Sync>;
+
+#[derive(Clone)]
+struct CronTaskOptions {
+    run_observers: Arc<[CronRunObserver]>,
+    schedule_refresh: Option<Duration>,
+    lock: Option<Arc<dyn CronLock>>,
+}
+
+type CronTaskFactory = Box<
+    dyn FnOnce(ServiceProvider, CancellationToken, CronTaskOptions) -> JoinHandle<CronResult>
+        + Send
+        + Sync,
+>;
 
 pub struct CronApplicationPartBuilder<Clock> {
     _clock: PhantomData<fn() -> Clock>,
     cron_job_names: String,
     cron_task_factories: Vec<CronTaskFactory>,
+    dependencies: Vec<ServiceToken>,
+    run_observers: Vec<CronRunObserver>,
+    schedule_refresh: Option<Duration>,
+    lock: Option<Arc<dyn CronLock>>,
 }
 
 impl<Clock> ApplicationPartBuilder for CronApplicationPartBuilder<Clock> {
@@ -367,14 +846,24 @@ impl<Clock> ApplicationPartBuilder for CronApplicationPartBuilder<Clock> {
         Ok(CronApplicationPart {
             cron_job_names: self.cron_job_names,
             cron_task_factories: self.cron_task_factories,
+            options: CronTaskOptions {
+                run_observers: self.run_observers.into(),
+                schedule_refresh: self.schedule_refresh,
+                lock: self.lock,
+            },
             service_provider,
         })
     }
+
+    fn dependencies(&self) -> Vec<ServiceToken> {
+        self.dependencies.clone()
+    }
 }
 
 pub struct CronApplicationPart {
     cron_job_names: String,
     cron_task_factories: Vec<CronTaskFactory>,
+    options: CronTaskOptions,
     service_provider: ServiceProvider,
 }
 
@@ -393,7 +882,13 @@ impl ApplicationPart for CronApplicationPart {
         let cron_tasks: Vec<JoinHandle<Result<(), CronError>>> = self
             .cron_task_factories
             .drain(..)
-            .map(|factory| factory(self.service_provider.clone(), cancellation_token.clone()))
+            .map(|factory| {
+                factory(
+                    self.service_provider.clone(),
+                    cancellation_token.clone(),
+                    self.options.clone(),
+                )
+            })
             .collect::<Vec<_>>();
 
         for cron_task in cron_tasks {
@@ -450,3 +945,532 @@ const _: () = {
 
     ok::<CronApplicationPartBuilder<DefaultCronClock>>();
 };
+
+#[cfg(test)]
+mod test {
+    use super::{CronRunObserver, CronTaskOptions, execute_job, jittered, run_cron_job};
+    use crate::{
+        cron::{
+            CronClock, CronError, CronJob, CronLock, CronResult, CronRunReport, InMemoryCronLock,
+            MissedRunPolicy, MockCronClock,
+        },
+        inject::{ServiceCollection, ServiceProvider},
+    };
+    use chrono::{DateTime, TimeDelta, TimeZone as _, Utc};
+    use cron::Schedule;
+    use std::{
+        future::{Ready, ready},
+        sync::{
+            Arc, Mutex,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::Duration,
+    };
+    use tokio_util::sync::CancellationToken;
+
+    /// A clock that jumps to the requested time and is suspended once during the first delay.
+    #[derive(Clone)]
+    struct SuspendingClock {
+        now: Arc<Mutex<DateTime<Utc>>>,
+        suspension: Arc<Mutex<Option<TimeDelta>>>,
+        end: DateTime<Utc>,
+    }
+
+    impl CronClock for SuspendingClock {
+        type TimeZone = Utc;
+        type DelayUntilFuture<'a> = Ready<CronResult<DateTime<Utc>>>;
+
+        fn timezone(&self) -> Self::TimeZone {
+            Utc
+        }
+
+        fn now(&self) -> DateTime<Utc> {
+            *self.now.lock().unwrap()
+        }
+
+        fn delay_until(
+            &self,
+            date_time: DateTime<Utc>,
+            _cancellation_token: CancellationToken,
+        ) -> Self::DelayUntilFuture<'_> {
+            let suspension = self.suspension.lock().unwrap().take();
+            let mut now = self.now.lock().unwrap();
+            *now = (*now).max(date_time) + suspension.unwrap_or_default();
+
+            if *now > self.end {
+                return ready(Err(CronError::Canceled));
+            }
+
+            ready(Ok(*now))
+        }
+    }
+
+    macro_rules! counting_job {
+        ($name:ident, $runs:ident, $policy:expr) => {
+            static $runs: AtomicUsize = AtomicUsize::new(0);
+
+            struct $name;
+
+            impl CronJob for $name {
+                async fn schedule(_service_provider: ServiceProvider) -> CronResult<Schedule> {
+                    Ok("* * * * * *".parse().unwrap())
+                }
+
+                async fn run(_service_provider: ServiceProvider) -> CronResult {
+                    $runs.fetch_add(1, Ordering::SeqCst);
+                    Ok(())
+                }
+
+                fn missed_run_policy() -> MissedRunPolicy {
+                    $policy
+                }
+            }
+        };
+    }
+
+    counting_job!(SkipJob, SKIP_RUNS, MissedRunPolicy::Skip);
+    counting_job!(RunOnceJob, RUN_ONCE_RUNS, MissedRunPolicy::RunOnce);
+    counting_job!(RunAllJob, RUN_ALL_RUNS, MissedRunPolicy::RunAll);
+
+    async fn run_suspended<Job: CronJob>() {
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+
+        // Is suspended while waiting for 12:00:01 until 12:00:04.5 (missing four runs) and runs at 12:00:05 again.
+        let clock = SuspendingClock {
+            now: Arc::new(Mutex::new(start)),
+            suspension: Arc::new(Mutex::new(Some(TimeDelta::milliseconds(3500)))),
+            end: start + TimeDelta::seconds(5),
+        };
+
+        let service_provider = {
+            let mut services = ServiceCollection::new();
+            services.add_value(clock);
+            services.build()
+        };
+
+        let result = run_cron_job::<Job, SuspendingClock>(
+            service_provider,
+            CancellationToken::new(),
+            CronTaskOptions {
+                run_observers: Arc::from([]),
+                schedule_refresh: None,
+                lock: None,
+            },
+        )
+        .await;
+
+        assert!(matches!(result, Err(CronError::Canceled)));
+    }
+
+    #[tokio::test]
+    async fn test_missed_runs_are_skipped() {
+        run_suspended::<SkipJob>().await;
+        pretty_assertions::assert_eq!(SKIP_RUNS.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_missed_runs_are_caught_up_once() {
+        run_suspended::<RunOnceJob>().await;
+        pretty_assertions::assert_eq!(RUN_ONCE_RUNS.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_missed_runs_are_all_caught_up() {
+        run_suspended::<RunAllJob>().await;
+        pretty_assertions::assert_eq!(RUN_ALL_RUNS.load(Ordering::SeqCst), 5);
+    }
+
+    counting_job!(LockedJob, LOCKED_RUNS, MissedRunPolicy::Skip);
+
+    #[tokio::test]
+    async fn test_lock_runs_each_schedule_once_across_instances() {
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+        let lock: Arc<dyn CronLock> = Arc::new(InMemoryCronLock::new());
+
+        let run_instance = || {
+            let clock = SuspendingClock {
+                now: Arc::new(Mutex::new(start)),
+                suspension: Arc::new(Mutex::new(None)),
+                end: start + TimeDelta::seconds(3),
+            };
+
+            let service_provider = {
+                let mut services = ServiceCollection::new();
+                services.add_value(clock);
+                services.build()
+            };
+
+            run_cron_job::<LockedJob, SuspendingClock>(
+                service_provider,
+                CancellationToken::new(),
+                CronTaskOptions {
+                    run_observers: Arc::from([]),
+                    schedule_refresh: None,
+                    lock: Some(lock.clone()),
+                },
+            )
+        };
+
+        let (first, second) = tokio::join!(run_instance(), run_instance());
+
+        assert!(matches!(first, Err(CronError::Canceled)));
+        assert!(matches!(second, Err(CronError::Canceled)));
+        pretty_assertions::assert_eq!(LOCKED_RUNS.load(Ordering::SeqCst), 3);
+    }
+
+    counting_job!(MockClockJob, MOCK_CLOCK_RUNS, MissedRunPolicy::Skip);
+
+    #[tokio::test]
+    async fn test_mock_clock_drives_job() {
+        let clock = MockCronClock::new(Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap());
+        let cancellation_token = CancellationToken::new();
+
+        let service_provider = {
+            let mut services = ServiceCollection::new();
+            services.add_value(clock.clone());
+            services.build()
+        };
+
+        let job = run_cron_job::<MockClockJob, MockCronClock>(
+            service_provider,
+            cancellation_token.clone(),
+            CronTaskOptions {
+                run_observers: Arc::from([]),
+                schedule_refresh: None,
+                lock: None,
+            },
+        );
+
+        let driver = async {
+            for _ in 0..10 {
+                tokio::task::yield_now().await;
+            }
+
+            // The job does not fire as long as the clock stands still.
+            pretty_assertions::assert_eq!(MOCK_CLOCK_RUNS.load(Ordering::SeqCst), 0);
+
+            for expected in 1..=3 {
+                clock.advance(Duration::from_secs(1));
+
+                while MOCK_CLOCK_RUNS.load(Ordering::SeqCst) < expected {
+                    tokio::task::yield_now().await;
+                }
+            }
+
+            cancellation_token.cancel();
+        };
+
+        let (result, ()) = tokio::join!(job, driver);
+
+        assert!(matches!(result, Err(CronError::Canceled)));
+        pretty_assertions::assert_eq!(MOCK_CLOCK_RUNS.load(Ordering::SeqCst), 3);
+    }
+
+    static JITTERED_RUNS: AtomicUsize = AtomicUsize::new(0);
+
+    /// A job that runs every second with a jitter exceeding the interval between two runs.
+    struct JitteredJob;
+
+    impl CronJob for JitteredJob {
+        async fn schedule(_service_provider: ServiceProvider) -> CronResult<Schedule> {
+            Ok("* * * * * *".parse().unwrap())
+        }
+
+        async fn run(_service_provider: ServiceProvider) -> CronResult {
+            JITTERED_RUNS.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+
+        fn jitter() -> Duration {
+            Duration::from_secs(5)
+        }
+
+        fn missed_run_policy() -> MissedRunPolicy {
+            MissedRunPolicy::Skip
+        }
+    }
+
+    #[tokio::test]
+    async fn test_jitter_exceeding_interval_does_not_skip_runs() {
+        let clock = MockCronClock::new(Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap());
+        let cancellation_token = CancellationToken::new();
+
+        let service_provider = {
+            let mut services = ServiceCollection::new();
+            services.add_value(clock.clone());
+            services.build()
+        };
+
+        let job = run_cron_job::<JitteredJob, MockCronClock>(
+            service_provider,
+            cancellation_token.clone(),
+            CronTaskOptions {
+                run_observers: Arc::from([]),
+                schedule_refresh: None,
+                lock: None,
+            },
+        );
+
+        let driver = async {
+            // Advances the clock to 12:00:20 in small steps, so that each run is executed close to its jittered time.
+            for _ in 0..200 {
+                clock.advance(Duration::from_millis(100));
+
+                for _ in 0..10 {
+                    tokio::task::yield_now().await;
+                }
+            }
+
+            cancellation_token.cancel();
+        };
+
+        let (result, ()) = tokio::join!(job, driver);
+
+        assert!(matches!(result, Err(CronError::Canceled)));
+
+        // The runs until 12:00:15 are due even when delayed by the full jitter. No run is skipped as missed, as the
+        // job is never late by more than the jitter.
+        let runs = JITTERED_RUNS.load(Ordering::SeqCst);
+        assert!(
+            (15..=20).contains(&runs),
+            "unexpected number of runs: {runs}"
+        );
+    }
+
+    static DELAYED_RUNS: AtomicUsize = AtomicUsize::new(0);
+
+    /// A job that runs once ten seconds after startup.
+    struct DelayedJob;
+
+    impl CronJob for DelayedJob {
+        async fn schedule(_service_provider: ServiceProvider) -> CronResult<Schedule> {
+            unreachable!("a job that runs once after a delay has no schedule")
+        }
+
+        async fn run(_service_provider: ServiceProvider) -> CronResult {
+            DELAYED_RUNS.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+
+        fn run_once_after() -> Option<Duration> {
+            Some(Duration::from_secs(10))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delayed_job_runs_exactly_once() {
+        let clock = MockCronClock::new(Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap());
+
+        let service_provider = {
+            let mut services = ServiceCollection::new();
+            services.add_value(clock.clone());
+            services.build()
+        };
+
+        let job = run_cron_job::<DelayedJob, MockCronClock>(
+            service_provider,
+            CancellationToken::new(),
+            CronTaskOptions {
+                run_observers: Arc::from([]),
+                schedule_refresh: None,
+                lock: None,
+            },
+        );
+
+        let driver = async {
+            clock.advance(Duration::from_secs(9));
+
+            for _ in 0..10 {
+                tokio::task::yield_now().await;
+            }
+
+            pretty_assertions::assert_eq!(DELAYED_RUNS.load(Ordering::SeqCst), 0);
+
+            clock.advance(Duration::from_secs(1));
+        };
+
+        let (result, ()) = tokio::join!(job, driver);
+
+        // The task completes after the single run instead of waiting for further runs.
+        assert!(result.is_ok());
+        pretty_assertions::assert_eq!(DELAYED_RUNS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_jittered_stays_within_window() {
+        let mut rng = fastrand::Rng::with_seed(42);
+        let upcoming = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+        let jitter = Duration::from_secs(30);
+
+        for _ in 0..1000 {
+            let fire_at = jittered(upcoming, jitter, &mut rng);
+
+            assert!(fire_at >= upcoming);
+            assert!(fire_at <= upcoming + chrono::TimeDelta::seconds(30));
+        }
+    }
+
+    #[test]
+    fn test_jittered_without_jitter() {
+        let mut rng = fastrand::Rng::with_seed(42);
+        let upcoming = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+
+        pretty_assertions::assert_eq!(jittered(upcoming, Duration::ZERO, &mut rng), upcoming);
+    }
+
+    static FLAKY_RUNS: AtomicUsize = AtomicUsize::new(0);
+
+    /// A job that succeeds on its first run and fails on all further runs.
+    struct FlakyJob;
+
+    impl CronJob for FlakyJob {
+        async fn schedule(_service_provider: ServiceProvider) -> CronResult<Schedule> {
+            Ok("* * * * * *".parse().unwrap())
+        }
+
+        async fn run(_service_provider: ServiceProvider) -> CronResult {
+            if FLAKY_RUNS.fetch_add(1, Ordering::SeqCst) == 0 {
+                Ok(())
+            } else {
+                Err(CronError::RunError("flaky".into()))
+            }
+        }
+
+        fn name() -> std::borrow::Cow<'static, str> {
+            "flaky".into()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_observer_receives_reports() {
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let run_observers: Arc<[CronRunObserver]> = Arc::from([Arc::new({
+            let reports = reports.clone();
+            move |report: &CronRunReport<'_>| {
+                reports
+                    .lock()
+                    .unwrap()
+                    .push((report.name().to_owned(), report.is_success()));
+            }
+        }) as CronRunObserver]);
+        let service_provider = ServiceCollection::new().build();
+
+        let first = execute_job::<FlakyJob, SuspendingClock>(
+            service_provider.clone(),
+            CancellationToken::new(),
+            &run_observers,
+        )
+        .await;
+        let second = execute_job::<FlakyJob, SuspendingClock>(
+            service_provider,
+            CancellationToken::new(),
+            &run_observers,
+        )
+        .await;
+
+        assert!(first.is_ok());
+        assert!(second.is_err());
+        pretty_assertions::assert_eq!(
+            *reports.lock().unwrap(),
+            vec![("flaky".to_owned(), true), ("flaky".to_owned(), false)]
+        );
+    }
+
+    /// A clock that jumps to the requested time and records all requested times.
+    #[derive(Clone)]
+    struct RecordingClock {
+        now: Arc<Mutex<DateTime<Utc>>>,
+        delays: Arc<Mutex<Vec<DateTime<Utc>>>>,
+        end: DateTime<Utc>,
+    }
+
+    impl CronClock for RecordingClock {
+        type TimeZone = Utc;
+        type DelayUntilFuture<'a> = Ready<CronResult<DateTime<Utc>>>;
+
+        fn timezone(&self) -> Self::TimeZone {
+            Utc
+        }
+
+        fn now(&self) -> DateTime<Utc> {
+            *self.now.lock().unwrap()
+        }
+
+        fn delay_until(
+            &self,
+            date_time: DateTime<Utc>,
+            _cancellation_token: CancellationToken,
+        ) -> Self::DelayUntilFuture<'_> {
+            self.delays.lock().unwrap().push(date_time);
+            let mut now = self.now.lock().unwrap();
+            *now = (*now).max(date_time);
+
+            if *now > self.end {
+                return ready(Err(CronError::Canceled));
+            }
+
+            ready(Ok(*now))
+        }
+    }
+
+    static CONFIG_SCHEDULE_RESOLUTIONS: AtomicUsize = AtomicUsize::new(0);
+
+    /// A job whose configured schedule changes from every minute to every five seconds.
+    struct ConfigJob;
+
+    impl CronJob for ConfigJob {
+        async fn schedule(_service_provider: ServiceProvider) -> CronResult<Schedule> {
+            let schedule = if CONFIG_SCHEDULE_RESOLUTIONS.fetch_add(1, Ordering::SeqCst) == 0 {
+                "0 * * * * *"
+            } else {
+                "*/5 * * * * *"
+            };
+
+            Ok(schedule.parse().unwrap())
+        }
+
+        async fn run(_service_provider: ServiceProvider) -> CronResult {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_schedule_refresh_updates_next_run() {
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+        let delays = Arc::new(Mutex::new(Vec::new()));
+        let clock = RecordingClock {
+            now: Arc::new(Mutex::new(start)),
+            delays: delays.clone(),
+            end: start + TimeDelta::seconds(15),
+        };
+
+        let service_provider = {
+            let mut services = ServiceCollection::new();
+            services.add_value(clock);
+            services.build()
+        };
+
+        let result = run_cron_job::<ConfigJob, RecordingClock>(
+            service_provider,
+            CancellationToken::new(),
+            CronTaskOptions {
+                run_observers: Arc::from([]),
+                schedule_refresh: Some(Duration::from_secs(10)),
+                lock: None,
+            },
+        )
+        .await;
+
+        assert!(matches!(result, Err(CronError::Canceled)));
+
+        // The refresh at 12:00:10 replaces the next run at 12:01:00 with a run at 12:00:15.
+        pretty_assertions::assert_eq!(
+            *delays.lock().unwrap(),
+            vec![
+                start + TimeDelta::seconds(10),
+                start + TimeDelta::seconds(15),
+                start + TimeDelta::seconds(20),
+            ]
+        );
+    }
+}
@@ -0,0 +1,111 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use crate::cron::CronResult;
+use chrono::{DateTime, Utc};
+use std::{
+    collections::HashMap,
+    pin::Pin,
+    sync::{Arc, Mutex},
+};
+
+/// The future returned by the methods of [`CronLock`].
+pub type CronLockFuture<'a, T> = Pin<Box<dyn Future<Output = CronResult<T>> + Send + 'a>>;
+
+/// A lock that ensures that each scheduled run of a cron job is executed by a single instance only.
+///
+/// When multiple replicas of an application run the same cron jobs, each replica tries to
+/// acquire the lock for a scheduled run before executing it. Only the replica that acquires the
+/// lock executes the run, all other replicas skip it. Runs are identified by the name of the
+/// cron job and the scheduled fire time (without jitter), so all replicas agree on them.
+///
+/// For a single instance, [`InMemoryCronLock`] can be used. Deployments with multiple replicas
+/// need a shared backend, e.g. a database row or a Redis key per job that stores the latest
+/// acquired fire time and is updated with a conditional write.
+pub trait CronLock: Send + Sync {
+    /// Tries to acquire the lock for the scheduled run of the cron job `job_name` at `fire_time`.
+    ///
+    /// # Returns
+    /// `true` if the lock was acquired and the run should be executed by this instance, `false`
+    /// if another instance already acquired the run.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lock backend cannot be reached. The run is skipped in that case.
+    fn acquire<'a>(
+        &'a self,
+        job_name: &'a str,
+        fire_time: DateTime<Utc>,
+    ) -> CronLockFuture<'a, bool>;
+
+    /// Releases the lock for the scheduled run of the cron job `job_name` at `fire_time` after
+    /// the run completed.
+    ///
+    /// Implementations must not hand out the same run again after it was released, as this
+    /// would execute the run a second time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lock backend cannot be reached.
+    fn release<'a>(&'a self, job_name: &'a str, fire_time: DateTime<Utc>)
+    -> CronLockFuture<'a, ()>;
+}
+
+/// A [`CronLock`] that is shared between all clones of the lock within a single process.
+///
+/// A run is acquired if its fire time is later than the latest fire time acquired for the same
+/// cron job.
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryCronLock {
+    acquired: Arc<Mutex<HashMap<String, DateTime<Utc>>>>,
+}
+
+impl InMemoryCronLock {
+    /// Creates a new in-memory cron lock.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CronLock for InMemoryCronLock {
+    fn acquire<'a>(
+        &'a self,
+        job_name: &'a str,
+        fire_time: DateTime<Utc>,
+    ) -> CronLockFuture<'a, bool> {
+        let is_acquired = {
+            let mut acquired = self
+                .acquired
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+            match acquired.get_mut(job_name) {
+                Some(latest) if *latest >= fire_time => false,
+                Some(latest) => {
+                    *latest = fire_time;
+                    true
+                }
+                None => {
+                    acquired.insert(job_name.to_owned(), fire_time);
+                    true
+                }
+            }
+        };
+
+        Box::pin(std::future::ready(Ok(is_acquired)))
+    }
+
+    fn release<'a>(
+        &'a self,
+        _job_name: &'a str,
+        _fire_time: DateTime<Utc>,
+    ) -> CronLockFuture<'a, ()> {
+        // The latest acquired fire time is kept, so that released runs are not acquired again.
+        Box::pin(std::future::ready(Ok(())))
+    }
+}
@@ -0,0 +1,36 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+/// Determines how a cron job handles scheduled runs that were missed.
+///
+/// Runs are missed when the scheduled time of one or more runs elapsed without the job being
+/// executed, for example because the process was suspended or a previous run took longer than
+/// the interval between two scheduled runs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum MissedRunPolicy {
+    /// Missed runs are skipped and the job continues with the next upcoming run.
+    #[default]
+    Skip,
+
+    /// The job is run once to catch up, regardless of how many runs were missed.
+    RunOnce,
+
+    /// The job is run once for each missed run.
+    RunAll,
+}
+
+impl MissedRunPolicy {
+    /// Returns the number of catch-up runs for the given number of missed runs.
+    #[must_use]
+    pub const fn catch_up_runs(self, missed: usize) -> usize {
+        match self {
+            Self::Skip => 0,
+            Self::RunOnce => (missed > 0) as usize,
+            Self::RunAll => missed,
+        }
+    }
+}
@@ -5,18 +5,24 @@
  * Licensed under the MIT license. See LICENSE file in the project root for details.
  */
 
-use std::borrow::Cow;
+use std::{borrow::Cow, time::Duration};
 
-use crate::inject::ServiceProvider;
+use crate::inject::{ServiceProvider, ServiceToken};
 use cron::Schedule;
 
 mod clock;
 mod error;
 mod feature;
+mod lock;
+mod missed;
+mod report;
 
-pub use clock::{CronClock, DefaultCronClock};
+pub use clock::{CronClock, DefaultCronClock, MockCronClock};
 pub use error::{CronError, CronResult};
 pub use feature::{Cron, CronApplicationBuilder};
+pub use lock::{CronLock, CronLockFuture, InMemoryCronLock};
+pub use missed::MissedRunPolicy;
+pub use report::CronRunReport;
 
 pub use nexustack_macros::cron_jobs;
 
@@ -64,6 +70,51 @@ pub trait CronJob {
     /// A future that resolves to a [`CronResult`] indicating the success or failure of the job.
     fn run(service_provider: ServiceProvider) -> impl Future<Output = CronResult> + Send;
 
+    /// Returns the jitter window of the cron job.
+    ///
+    /// Each scheduled run is delayed by a random duration of at most the returned jitter, which
+    /// spreads the load when many instances run the same schedule. The jitter should be smaller
+    /// than the interval between two scheduled runs.
+    ///
+    /// # Returns
+    /// The maximum delay added to each scheduled run. By default, this is [`Duration::ZERO`].
+    #[must_use]
+    fn jitter() -> Duration {
+        Duration::ZERO
+    }
+
+    /// Returns how the cron job handles scheduled runs that were missed.
+    ///
+    /// # Returns
+    /// The [`MissedRunPolicy`] of the cron job. By default, this is [`MissedRunPolicy::Skip`].
+    #[must_use]
+    fn missed_run_policy() -> MissedRunPolicy {
+        MissedRunPolicy::Skip
+    }
+
+    /// Returns the delay after which the cron job runs exactly once.
+    ///
+    /// If a delay is returned, the job runs a single time once the delay after startup elapsed
+    /// and is retired afterwards. Its schedule is not used in that case.
+    ///
+    /// # Returns
+    /// The delay of the single run. By default, this is [`None`], so the job follows its schedule.
+    #[must_use]
+    fn run_once_after() -> Option<Duration> {
+        None
+    }
+
+    /// Returns the services that are resolved from the service provider when running the cron job.
+    ///
+    /// This is used to compute the services used by the application, see
+    /// [`crate::inject::ServiceCollection::find_unused`]. The `#[cron]` macro generates the dependencies from
+    /// the `#[cron::service]` parameters of the job. Services resolved by a `schedule_with` function are not
+    /// tracked. Defaults to no dependencies.
+    #[must_use]
+    fn dependencies() -> Vec<ServiceToken> {
+        Vec::new()
+    }
+
     /// Returns the name of this cron job as a string.
     ///
     /// # Returns
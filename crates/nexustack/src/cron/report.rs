@@ -0,0 +1,59 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use crate::cron::CronError;
+use std::{borrow::Cow, time::Duration};
+
+/// A report about a single execution of a cron job.
+///
+/// Reports are passed to the run observers registered via
+/// [`CronApplicationBuilder::with_run_observer`](crate::cron::CronApplicationBuilder::with_run_observer)
+/// after each execution, e.g. to export metrics.
+#[derive(Debug)]
+pub struct CronRunReport<'a> {
+    name: Cow<'static, str>,
+    duration: Duration,
+    error: Option<&'a CronError>,
+}
+
+impl<'a> CronRunReport<'a> {
+    pub(crate) const fn new(
+        name: Cow<'static, str>,
+        duration: Duration,
+        error: Option<&'a CronError>,
+    ) -> Self {
+        Self {
+            name,
+            duration,
+            error,
+        }
+    }
+
+    /// The name of the cron job.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The time the execution took.
+    #[must_use]
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Whether the execution completed successfully.
+    #[must_use]
+    pub const fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// The error the execution failed with, if any.
+    #[must_use]
+    pub const fn error(&self) -> Option<&'a CronError> {
+        self.error
+    }
+}
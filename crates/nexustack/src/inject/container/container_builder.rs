@@ -7,7 +7,7 @@
 
 use crate::inject::{
     container::{
-        Container,
+        Container, ShutdownHooks,
         container_entry::{ContainerEntry, UntypedContainerEntry},
         container_entry_builder::{
             ScopedUntypedContainerEntryBuilder, SingletonContainerEntryBuilder,
@@ -61,29 +61,38 @@ impl ContainerBuilderEntry {
 pub(crate) struct ContainerBuilder {
     entries: HashMap<TypeId, RefCell<ContainerBuilderEntry>>,
     container: Arc<AtomicOnceCell<Container>>,
+    shutdown_hooks: Arc<ShutdownHooks>,
     parent_service_provider: Option<ServiceProvider>,
 }
 
 impl ContainerBuilder {
     pub(crate) fn new(
         mut entry_builders: Vec<Box<dyn UntypedContainerEntryBuilder>>,
-        scoped_builders: Option<Vec<Box<dyn ScopedUntypedContainerEntryBuilder + Send + Sync>>>,
+        scoped_builders: Option<Arc<[Box<dyn ScopedUntypedContainerEntryBuilder + Send + Sync>]>>,
         parent_service_provider: Option<ServiceProvider>,
     ) -> Self {
         let container: Arc<AtomicOnceCell<Container>> = Arc::new(AtomicOnceCell::new());
-        let inner_service_provider = ServiceProvider::create_weak(Arc::downgrade(&container));
+        let shutdown_hooks = Arc::new(ShutdownHooks::new(parent_service_provider.clone()));
+        let inner_service_provider = ServiceProvider::create_weak(
+            Arc::downgrade(&container),
+            Arc::downgrade(&shutdown_hooks),
+        );
 
         entry_builders.push(Box::new(TransientContainerEntryBuilder::new({
             let inner_service_provider = inner_service_provider.clone();
-            let scoped_builders = scoped_builders.unwrap_or_default();
+            let scoped_builders = scoped_builders.unwrap_or_else(|| Arc::new([]));
             move |_| {
                 let entry_builders = scoped_builders
                     .iter()
                     .map(|builder| builder.to_builder())
                     .collect::<Vec<_>>();
 
-                let scope_container_builder =
-                    Self::new(entry_builders, None, Some(inner_service_provider.clone()));
+                // Scopes created from within the scope get their own scoped services as well.
+                let scope_container_builder = Self::new(
+                    entry_builders,
+                    Some(scoped_builders.clone()),
+                    Some(inner_service_provider.clone()),
+                );
 
                 let scope_service_provider = scope_container_builder.build();
 
@@ -106,6 +115,7 @@ impl ContainerBuilder {
                 })
                 .collect(),
             container,
+            shutdown_hooks,
             parent_service_provider,
         }
     }
@@ -140,7 +150,7 @@ impl ContainerBuilder {
         // TODO: Do not discard error!
         _ = self.container.set(container);
 
-        ServiceProvider::create(self.container)
+        ServiceProvider::create(self.container, self.shutdown_hooks)
     }
 
     pub(crate) fn resolve_core<TService: 'static>(
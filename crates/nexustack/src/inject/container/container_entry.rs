@@ -10,7 +10,7 @@ use crate::inject::{
     injector::Injector,
     service_token::ServiceToken,
 };
-use std::any::Any;
+use std::{any::Any, sync::Arc};
 
 type TransientServiceFactory<TService> =
     dyn Fn(&Injector) -> ConstructionResult<TService> + Send + Sync;
@@ -26,7 +26,7 @@ pub(crate) enum ContainerEntry<TService> {
 }
 
 impl<TService: 'static> ContainerEntry<TService> {
-    pub(crate) fn transient(factory: Box<TransientServiceFactory<TService>>) -> Self {
+    pub(crate) fn transient(factory: Arc<TransientServiceFactory<TService>>) -> Self {
         Self::Transient(TransientContainerEntry { factory })
     }
 
@@ -66,7 +66,7 @@ impl<TService: 'static> UntypedContainerEntry for ContainerEntry<TService> {
 }
 
 pub(crate) struct TransientContainerEntry<TService> {
-    factory: Box<TransientServiceFactory<TService>>,
+    factory: Arc<TransientServiceFactory<TService>>,
 }
 
 impl<TService: 'static> TransientContainerEntry<TService> {
@@ -76,8 +76,7 @@ impl<TService: 'static> TransientContainerEntry<TService> {
             ConstructionError::InjectionError(injection_error) => injection_error,
             ConstructionError::Custom(error) => InjectionError::Custom {
                 service: ServiceToken::create::<TService>(),
-                // TODO: Is this correct?
-                dependency_chain: injector.resolve_dependency_chain(),
+                dependency_chain: injector.resolve_requesting_dependency_chain(),
                 source: error.into(),
             },
         })
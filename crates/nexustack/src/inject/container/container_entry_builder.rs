@@ -32,7 +32,7 @@ pub(crate) trait UntypedContainerEntryBuilder {
 }
 
 pub(crate) struct TransientContainerEntryBuilder<TService> {
-    factory: Box<ScopedServiceFactory<TService>>,
+    factory: Arc<ScopedServiceFactory<TService>>,
 }
 
 impl<TService> TransientContainerEntryBuilder<TService> {
@@ -40,11 +40,28 @@ impl<TService> TransientContainerEntryBuilder<TService> {
         factory: impl Fn(&Injector) -> ConstructionResult<TService> + Send + Sync + 'static,
     ) -> Self {
         Self {
-            factory: Box::new(factory),
+            factory: Arc::new(factory),
+        }
+    }
+}
+
+// Not derived, as the service itself does not need to be cloneable.
+impl<TService> Clone for TransientContainerEntryBuilder<TService> {
+    fn clone(&self) -> Self {
+        Self {
+            factory: self.factory.clone(),
         }
     }
 }
 
+impl<TService: Send + Sync + 'static> ScopedUntypedContainerEntryBuilder
+    for TransientContainerEntryBuilder<TService>
+{
+    fn to_builder(&self) -> Box<dyn UntypedContainerEntryBuilder> {
+        Box::new(self.clone())
+    }
+}
+
 impl<TService: Send + Sync + 'static> UntypedContainerEntryBuilder
     for TransientContainerEntryBuilder<TService>
 {
@@ -93,8 +110,7 @@ impl<TService: Send + Sync + 'static> UntypedContainerEntryBuilder
                 ConstructionError::InjectionError(injection_error) => injection_error,
                 ConstructionError::Custom(error) => InjectionError::Custom {
                     service: ServiceToken::create::<TService>(),
-                    // TODO: Is this correct?
-                    dependency_chain: injector.resolve_dependency_chain(),
+                    dependency_chain: injector.resolve_requesting_dependency_chain(),
                     source: error.into(),
                 },
             }),
@@ -146,8 +162,7 @@ impl<TService: Clone + Send + Sync + 'static> UntypedContainerEntryBuilder
                 ConstructionError::InjectionError(injection_error) => injection_error,
                 ConstructionError::Custom(error) => InjectionError::Custom {
                     service: ServiceToken::create::<TService>(),
-                    // TODO: Is this correct?
-                    dependency_chain: injector.resolve_dependency_chain(),
+                    dependency_chain: injector.resolve_requesting_dependency_chain(),
                     source: error.into(),
                 },
             }),
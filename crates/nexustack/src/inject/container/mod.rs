@@ -20,7 +20,48 @@ use crate::inject::{
     service_token::ServiceToken,
 };
 use container_entry::{ContainerEntry, UntypedContainerEntry};
-use std::{any::TypeId, collections::HashMap};
+use futures_util::future::BoxFuture;
+use std::{
+    any::TypeId,
+    collections::HashMap,
+    sync::{Mutex, PoisonError},
+};
+
+pub(crate) type ShutdownHook = Box<dyn FnOnce() -> BoxFuture<'static, ()> + Send>;
+
+/// The shutdown hooks registered with a container.
+///
+/// The hooks live beside the container rather than inside of it, so that services can register hooks while the
+/// container is still being built, i.e. from within the constructors of singleton and scoped services.
+pub(crate) struct ShutdownHooks {
+    parent_service_provider: Option<ServiceProvider>,
+    hooks: Mutex<Vec<ShutdownHook>>,
+}
+
+impl ShutdownHooks {
+    pub(crate) const fn new(parent_service_provider: Option<ServiceProvider>) -> Self {
+        Self {
+            parent_service_provider,
+            hooks: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub(crate) fn add(&self, hook: ShutdownHook) {
+        // Shutdown hooks are application-wide, so scopes forward them to the root container.
+        if let Some(parent_service_provider) = &self.parent_service_provider {
+            parent_service_provider.add_shutdown_hook(hook);
+        } else {
+            self.hooks
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(hook);
+        }
+    }
+
+    pub(crate) fn take(&self) -> Vec<ShutdownHook> {
+        std::mem::take(&mut *self.hooks.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+}
 
 pub(crate) struct Container {
     entries: HashMap<TypeId, Box<dyn UntypedContainerEntry + Send + Sync>>,
@@ -0,0 +1,90 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use crate::inject::{
+    injectable::{FromInjector, Injectable},
+    injection_error::ConstructionResult,
+    injector::Injector,
+    service_provider::ServiceProvider,
+};
+use std::marker::PhantomData;
+
+/// A factory that constructs a fresh service on each call to [`Factory::create`].
+///
+/// Unlike resolving a service, the lifetime the service is registered with is ignored. The service
+/// is always constructed anew, with its dependencies resolved from the service provider the [`Factory`]
+/// was resolved from, i.e. within the same scope. The service does not need to be registered itself.
+/// Register the factory via [`crate::inject::ServiceCollection::add_factory`].
+///
+/// # Type arguments
+///
+/// * `TService` - The type of the service to construct.
+///
+/// # Example
+///
+/// ```rust
+/// use nexustack::inject::injectable;
+/// use nexustack::inject::Factory;
+/// use nexustack::inject::ServiceCollection;
+///
+/// struct MigrateCommand { }
+///
+/// #[injectable]
+/// impl MigrateCommand {
+///     pub fn new() -> Self {
+///         Self { }
+///     }
+/// }
+///
+/// let mut services = ServiceCollection::new();
+/// services.add_factory::<MigrateCommand>();
+/// let service_provider = services.build();
+///
+/// let command_factory = service_provider.resolve::<Factory<MigrateCommand>>().unwrap();
+/// let command = command_factory.create().unwrap();
+/// ```
+pub struct Factory<TService> {
+    service_provider: ServiceProvider,
+    _service: PhantomData<fn() -> TService>,
+}
+
+impl<TService: FromInjector + 'static> Factory<TService> {
+    /// Constructs a fresh service.
+    ///
+    /// # Errors
+    ///  * [`crate::inject::ConstructionError`] when the service cannot be created or one of its dependencies cannot be resolved
+    ///    either due to a resolution error or when a constructor/factory function
+    ///    has raised a custom error. See the [`crate::inject::ConstructionError`] enum for further information.
+    ///
+    pub fn create(&self) -> ConstructionResult<TService> {
+        self.service_provider.construct::<TService>()
+    }
+}
+
+// Not derived, as the service itself does not need to be cloneable.
+impl<TService> Clone for Factory<TService> {
+    fn clone(&self) -> Self {
+        Self {
+            service_provider: self.service_provider.clone(),
+            _service: PhantomData,
+        }
+    }
+}
+
+impl<TService> FromInjector for Factory<TService> {
+    fn from_injector(injector: &Injector) -> ConstructionResult<Self>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            service_provider: injector.resolve::<ServiceProvider>()?,
+            _service: PhantomData,
+        })
+    }
+}
+
+impl<TService> Injectable for Factory<TService> {}
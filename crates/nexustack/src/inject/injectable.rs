@@ -5,7 +5,9 @@
  * Licensed under the MIT license. See LICENSE file in the project root for details.
  */
 
-use crate::inject::{injection_error::ConstructionResult, injector::Injector};
+use crate::inject::{
+    injection_error::ConstructionResult, injector::Injector, service_token::ServiceToken,
+};
 use std::sync::Arc;
 
 // The injector is a non-send, non-sync type with a lifetime to ensure that it can not escape the
@@ -68,7 +70,20 @@ pub trait FromInjector {
 /// implementors to implement the [`FromInjector`] trait. Not all services that can be resolved from an injector
 /// are also injectable into other services. If a service should only be constructed from an injector but never
 /// be injectable into other services implement only the [`FromInjector`] trait and not the [`Injectable`] trait.
-pub trait Injectable: FromInjector {}
+pub trait Injectable: FromInjector {
+    /// Returns the services that are resolved from the [`Injector`] when constructing the implementing service.
+    ///
+    /// This is used to compute the transitive dependency closure of services, e.g. by
+    /// [`crate::inject::ServiceCollection::find_unused`]. The `#[injectable]` macro generates the dependencies from
+    /// the constructor parameters or fields of the service. Defaults to no dependencies.
+    #[must_use]
+    fn dependencies() -> Vec<ServiceToken>
+    where
+        Self: Sized,
+    {
+        Vec::new()
+    }
+}
 
 impl<T: FromInjector> FromInjector for Arc<T> {
     fn from_injector(injector: &Injector) -> ConstructionResult<Self>
@@ -79,4 +94,8 @@ impl<T: FromInjector> FromInjector for Arc<T> {
     }
 }
 
-impl<T: Injectable> Injectable for Arc<T> {}
+impl<T: Injectable> Injectable for Arc<T> {
+    fn dependencies() -> Vec<ServiceToken> {
+        T::dependencies()
+    }
+}
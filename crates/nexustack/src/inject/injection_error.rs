@@ -23,6 +23,8 @@ pub type InjectionResult<T> = std::result::Result<T, InjectionError>;
 /// A construction result that represents the result of a service construction
 pub type ConstructionResult<T> = std::result::Result<T, ConstructionError>;
 
+/// Renders the service together with the services that required it, e.g.
+/// `A required by B required by C`.
 fn format_service<'r>(
     service: &'r ServiceToken,
     dependency_chain: &[ServiceToken],
@@ -31,23 +33,13 @@ fn format_service<'r>(
         return Cow::Borrowed(service.type_name());
     }
 
-    let mut result = String::new();
-    result.push_str(service.type_name());
-    result.push(' ');
-    result.push('(');
-
-    for (index, dependency) in dependency_chain.iter().enumerate() {
-        if index > 0 {
-            result.push(' ');
-            result.push('-');
-            result.push('>');
-            result.push(' ');
-        }
+    let mut result = String::from(service.type_name());
+
+    for dependency in dependency_chain {
+        result.push_str(" required by ");
         result.push_str(dependency.type_name());
     }
 
-    result.push(')');
-
     Cow::Owned(result)
 }
 
@@ -58,7 +50,7 @@ pub enum InjectionError {
     /// Raised when the service provider is not initialized yet. It can only be used after
     /// the service-collection was built
     #[error(
-        "cannot resolve service {} from uninitialized service-provider",
+        "cannot resolve services from uninitialized service-provider while resolving {}",
         format_service(service, dependency_chain)
     )]
     UninitializedServiceProvider {
@@ -72,7 +64,7 @@ pub enum InjectionError {
 
     /// Raised when the service provider was dropped and can no longer be used
     #[error(
-        "cannot resolve service {} from dropped service-provider",
+        "cannot resolve services from dropped service-provider while resolving {}",
         format_service(service, dependency_chain)
     )]
     DroppedServiceProvider {
@@ -88,7 +80,7 @@ pub enum InjectionError {
     /// Raised when a service cannot be resolved due to a cyclic reference its dependency chain.
     /// This can be worked around by using a weak service reference
     #[error(
-        "cannot resolve service {} with cyclic reference",
+        "cyclic reference while resolving {}",
         format_service(service, dependency_chain)
     )]
     CyclicReference {
@@ -101,7 +93,10 @@ pub enum InjectionError {
     },
 
     /// Raised when the requested service was not found in the service provider
-    #[error("service {} not found", format_service(service, dependency_chain))]
+    #[error(
+        "service not found while resolving {}",
+        format_service(service, dependency_chain)
+    )]
     ServiceNotFound {
         /// The [`ServiceToken`] that describes the service that failed to be resolved
         service: ServiceToken,
@@ -114,7 +109,7 @@ pub enum InjectionError {
     /// Raised when the requested service cannot be constructed as there occurred an error during its
     /// construction
     #[error(
-        "service {} cannot be constructed due to an error",
+        "service cannot be constructed due to an error while resolving {}",
         format_service(service, dependency_chain)
     )]
     Custom {
@@ -162,6 +157,9 @@ impl InjectionError {
 
     /// Accesses the list of [`crate::inject::ServiceToken`]s that describe the dependency chain
     /// of the service resolution operation
+    ///
+    /// The chain starts with the service that required the failed service and ends with the
+    /// service that was resolved from the provider.
     #[allow(clippy::must_use_candidate)]
     pub const fn dependency_chain(&self) -> &Vec<ServiceToken> {
         match self {
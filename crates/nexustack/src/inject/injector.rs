@@ -10,7 +10,7 @@ use crate::inject::{
     injection_error::{InjectionError, InjectionResult},
     service_token::ServiceToken,
 };
-use std::{any::TypeId, marker::PhantomData};
+use std::{any::TypeId, marker::PhantomData, sync::Arc};
 
 /// Represents a service injector that is used to perform the actual injection of services
 /// into dependent services.
@@ -56,6 +56,23 @@ impl<'i> Injector<'i> {
         }
     }
 
+    /// Creates an injector that resolves the dependencies of `service_token` on behalf of this injector, like the
+    /// resolution of a dependency of the service of this injector would.
+    #[cfg(test)]
+    pub(crate) const fn nested(&'i self, service_token: ServiceToken) -> Self {
+        Injector {
+            inner: match self.inner {
+                InjectorInner::Container(container) => InjectorInner::Container(container),
+                InjectorInner::ContainerBuilder(container_builder) => {
+                    InjectorInner::ContainerBuilder(container_builder)
+                }
+            },
+            service_token,
+            parent: Some(self),
+            _not_send_sync: PhantomData,
+        }
+    }
+
     fn has_service_type_in_chain(&self, service_type: TypeId) -> bool {
         self.service_token.type_id() == &service_type
             || self.parent.is_some_and(|parent_injector| {
@@ -76,6 +93,12 @@ impl<'i> Injector<'i> {
         result
     }
 
+    pub(crate) fn resolve_requesting_dependency_chain(&self) -> Vec<ServiceToken> {
+        self.parent
+            .map(Self::resolve_dependency_chain)
+            .unwrap_or_default()
+    }
+
     /// Resolves a service from the provider. If the service cannot be resolved, an [`InjectionError`] is returned.
     ///
     /// # Type arguments
@@ -137,9 +160,139 @@ impl<'i> Injector<'i> {
             }
         }
     }
+
+    /// Resolves a service from the provider, if it is registered. If the service is registered but cannot be
+    /// resolved, an [`InjectionError`] is returned.
+    ///
+    /// Only a missing registration of `TService` itself results in `None`. Missing dependencies of a registered
+    /// service are reported as error.
+    ///
+    /// # Type arguments
+    ///
+    /// * `TService` - The type of the service to resolve from the provider.
+    ///
+    /// # Errors
+    ///  * `InjectionError` when the service is registered but cannot be resolved either due to a resolution error or when a
+    ///    constructor/factory function has raised a custom error. See the [`crate::inject::InjectionError`] enum for further
+    ///    information.
+    ///
+    pub fn try_resolve<TService: 'static>(&self) -> InjectionResult<Option<TService>> {
+        match self.resolve::<TService>() {
+            Ok(service) => Ok(Some(service)),
+            Err(InjectionError::ServiceNotFound { service, .. })
+                if service.type_id() == &TypeId::of::<TService>() =>
+            {
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Resolves a shared service from the provider. If the service cannot be resolved, an [`InjectionError`] is returned.
+    ///
+    /// This is equivalent to resolving `Arc<TService>`. See [`crate::inject::ServiceProvider::resolve_shared`].
+    ///
+    /// # Type arguments
+    ///
+    /// * `TService` - The type of the service to resolve from the provider.
+    ///
+    /// # Errors
+    ///  * `InjectionError` when the service cannot be resolved either due to a resolution error or when a constructor/factory function
+    ///    has raised a custom error. See the [`crate::inject::InjectionError`] enum for further information.
+    ///
+    pub fn resolve_shared<TService: 'static>(&self) -> InjectionResult<Arc<TService>> {
+        self.resolve::<Arc<TService>>()
+    }
 }
 
 enum InjectorInner<'i> {
     Container(&'i Container),
     ContainerBuilder(&'i ContainerBuilder),
 }
+
+#[cfg(test)]
+mod test {
+    use super::Injector;
+    use crate::inject::{InjectionError, container::Container, service_token::ServiceToken};
+    use std::collections::HashMap;
+
+    struct Handler;
+    struct Repository;
+    struct Database;
+
+    fn type_names(dependency_chain: &[ServiceToken]) -> Vec<&str> {
+        dependency_chain
+            .iter()
+            .map(ServiceToken::type_name)
+            .collect()
+    }
+
+    #[test]
+    fn test_dependency_chain_starts_at_innermost_service() {
+        let container = Container::new(HashMap::new(), None);
+        let handler = Injector::from_container(&container, ServiceToken::create::<Handler>(), None);
+        let repository = handler.nested(ServiceToken::create::<Repository>());
+
+        pretty_assertions::assert_eq!(
+            type_names(&repository.resolve_dependency_chain()),
+            vec![
+                std::any::type_name::<Repository>(),
+                std::any::type_name::<Handler>()
+            ]
+        );
+        pretty_assertions::assert_eq!(
+            type_names(&repository.resolve_requesting_dependency_chain()),
+            vec![std::any::type_name::<Handler>()]
+        );
+    }
+
+    #[test]
+    fn test_nested_miss_reports_full_chain() {
+        let container = Container::new(HashMap::new(), None);
+        let handler = Injector::from_container(&container, ServiceToken::create::<Handler>(), None);
+        let repository = handler.nested(ServiceToken::create::<Repository>());
+
+        let Err(InjectionError::ServiceNotFound {
+            service,
+            dependency_chain,
+        }) = repository.resolve::<Database>()
+        else {
+            panic!("expected the resolution of `Database` to fail with `ServiceNotFound`");
+        };
+
+        pretty_assertions::assert_eq!(service.type_name(), std::any::type_name::<Database>());
+        pretty_assertions::assert_eq!(
+            type_names(&dependency_chain),
+            vec![
+                std::any::type_name::<Repository>(),
+                std::any::type_name::<Handler>()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cyclic_reference_reports_full_chain() {
+        let container = Container::new(HashMap::new(), None);
+        let handler = Injector::from_container(&container, ServiceToken::create::<Handler>(), None);
+        let repository = handler.nested(ServiceToken::create::<Repository>());
+        let database = repository.nested(ServiceToken::create::<Database>());
+
+        let Err(InjectionError::CyclicReference {
+            service,
+            dependency_chain,
+        }) = database.resolve::<Handler>()
+        else {
+            panic!("expected the resolution of `Handler` to fail with `CyclicReference`");
+        };
+
+        pretty_assertions::assert_eq!(service.type_name(), std::any::type_name::<Handler>());
+        pretty_assertions::assert_eq!(
+            type_names(&dependency_chain),
+            vec![
+                std::any::type_name::<Database>(),
+                std::any::type_name::<Repository>(),
+                std::any::type_name::<Handler>()
+            ]
+        );
+    }
+}
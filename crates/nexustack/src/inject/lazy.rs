@@ -0,0 +1,103 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use crate::inject::{
+    injectable::{FromInjector, Injectable},
+    injection_error::{ConstructionResult, InjectionResult},
+    injector::Injector,
+    service_provider::ServiceProvider,
+};
+use std::sync::OnceLock;
+
+/// A lazily resolved service. Resolving a [`Lazy`] is cheap, the service itself is resolved on the first
+/// call to [`Lazy::get`] and cached afterwards.
+///
+/// The service is resolved from the service provider the [`Lazy`] was resolved from, i.e. within the
+/// same scope. Register the lazy service via [`crate::inject::ServiceCollection::add_lazy`].
+///
+/// # Remarks
+/// Singleton and scoped services are constructed when their service provider is built. Deferring the
+/// construction is therefore only effective for transient services.
+///
+/// # Type arguments
+///
+/// * `TService` - The type of the lazily resolved service.
+///
+/// # Example
+///
+/// ```rust
+/// use nexustack::inject::injectable;
+/// use nexustack::inject::Lazy;
+/// use nexustack::inject::ServiceCollection;
+///
+/// #[derive(Clone)]
+/// struct ReportGenerator { }
+///
+/// #[injectable]
+/// impl ReportGenerator {
+///     pub fn new() -> Self {
+///         Self { }
+///     }
+/// }
+///
+/// struct MyService {
+///     report_generator: Lazy<ReportGenerator>,
+/// }
+///
+/// #[injectable]
+/// impl MyService {
+///     pub fn new(report_generator: Lazy<ReportGenerator>) -> Self {
+///         Self { report_generator }
+///     }
+/// }
+///
+/// let mut services = ServiceCollection::new();
+/// services.add_transient::<ReportGenerator>()
+///     .add_lazy::<ReportGenerator>()
+///     .add_transient::<MyService>();
+/// let service_provider = services.build();
+///
+/// let my_service = service_provider.resolve::<MyService>().unwrap();
+/// let report_generator = my_service.report_generator.get().unwrap();
+/// ```
+pub struct Lazy<TService> {
+    service_provider: ServiceProvider,
+    service: OnceLock<TService>,
+}
+
+impl<TService: 'static> Lazy<TService> {
+    /// Resolves the service on the first call and returns the cached service afterwards.
+    ///
+    /// # Errors
+    ///  * `crate::inject::InjectionError` when the service cannot be resolved either due to a resolution error or when a constructor/factory function
+    ///    has raised a custom error. See the [`crate::inject::InjectionError`] enum for further information. Errors are
+    ///    not cached, the next call tries to resolve the service again.
+    ///
+    pub fn get(&self) -> InjectionResult<&TService> {
+        if let Some(service) = self.service.get() {
+            return Ok(service);
+        }
+
+        let service = self.service_provider.resolve::<TService>()?;
+
+        Ok(self.service.get_or_init(|| service))
+    }
+}
+
+impl<TService> FromInjector for Lazy<TService> {
+    fn from_injector(injector: &Injector) -> ConstructionResult<Self>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            service_provider: injector.resolve::<ServiceProvider>()?,
+            service: OnceLock::new(),
+        })
+    }
+}
+
+impl<TService> Injectable for Lazy<TService> {}
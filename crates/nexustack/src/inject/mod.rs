@@ -6,9 +6,11 @@
  */
 
 mod container;
+mod factory;
 mod injectable;
 mod injection_error;
 mod injector;
+mod lazy;
 mod service_collection;
 mod service_provider;
 mod service_scope;
@@ -17,11 +19,13 @@ mod service_token;
 #[cfg(feature = "derive")]
 pub use nexustack_macros::injectable;
 
+pub use factory::Factory;
 pub use injectable::{FromInjector, Injectable};
 pub use injection_error::{
     ConstructionError, ConstructionResult, InjectionError, InjectionResult, IntoConstructionResult,
 };
 pub use injector::Injector;
+pub use lazy::Lazy;
 pub use service_collection::ServiceCollection;
 pub use service_provider::ServiceProvider;
 pub use service_scope::ServiceScope;
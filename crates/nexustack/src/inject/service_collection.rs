@@ -11,10 +11,18 @@ use crate::inject::{
         SingletonContainerEntryBuilder, TransientContainerEntryBuilder,
         UntypedContainerEntryBuilder,
     },
-    injectable::Injectable,
+    factory::Factory,
+    injectable::{FromInjector, Injectable},
     injection_error::ConstructionResult,
     injector::Injector,
+    lazy::Lazy,
     service_provider::ServiceProvider,
+    service_token::ServiceToken,
+};
+use std::{
+    any::TypeId,
+    collections::{HashSet, VecDeque},
+    sync::Arc,
 };
 
 /// Represents a service-collection that can be used to register and collection services.
@@ -24,6 +32,14 @@ use crate::inject::{
 pub struct ServiceCollection {
     root_builders: Vec<Box<dyn UntypedContainerEntryBuilder>>,
     scoped_builders: Vec<Box<dyn ScopedUntypedContainerEntryBuilder + Send + Sync>>,
+    registrations: Vec<ServiceRegistration>,
+    used_services: Vec<ServiceToken>,
+}
+
+/// A registered service together with the services it depends on.
+struct ServiceRegistration {
+    service: ServiceToken,
+    dependencies: Vec<ServiceToken>,
 }
 
 impl Default for ServiceCollection {
@@ -39,6 +55,8 @@ impl ServiceCollection {
         Self {
             root_builders: Vec::new(),
             scoped_builders: Vec::new(),
+            registrations: Vec::new(),
+            used_services: Vec::new(),
         }
     }
 
@@ -72,7 +90,7 @@ impl ServiceCollection {
             if self.scoped_builders.is_empty() {
                 None
             } else {
-                Some(self.scoped_builders)
+                Some(self.scoped_builders.into())
             },
             None,
         );
@@ -119,6 +137,7 @@ impl ServiceCollection {
     ) -> &mut Self {
         self.root_builders
             .push(Box::new(SingletonContainerEntryBuilder::new(|_| Ok(value))));
+        self.register::<TService>(Vec::new());
 
         self
     }
@@ -175,11 +194,54 @@ impl ServiceCollection {
             .push(Box::new(SingletonContainerEntryBuilder::new(
                 TService::from_injector,
             )));
+        self.register::<TService>(TService::dependencies());
 
         self
     }
 
-    /// Adds a singleton service to the service collection. The service must implement the [Injectable] trait.
+    /// Adds a shared singleton service to the service collection. The service must implement the [Injectable] trait.
+    ///
+    /// The service is registered as `Arc<TService>`, so that it does not need to implement [Clone] and all
+    /// resolutions share the same allocation. Resolve it via [`ServiceProvider::resolve_shared`] or as `Arc<TService>`.
+    ///
+    /// # Type arguments
+    ///
+    /// * `TService` - The type of the service to register.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nexustack::inject::injectable;
+    /// use nexustack::inject::ServiceCollection;
+    /// use std::sync::Arc;
+    ///
+    /// struct MyService {
+    ///     pub buffer: Vec<u8>
+    /// }
+    ///
+    /// #[injectable]
+    /// impl MyService {
+    ///     pub fn new() -> Self {
+    ///         Self { buffer: vec![0; 1024] }
+    ///     }
+    /// }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton_shared::<MyService>();
+    /// let service_provider = services.build();
+    ///
+    /// let my_service_1 = service_provider.resolve_shared::<MyService>().unwrap();
+    /// let my_service_2 = service_provider.resolve_shared::<MyService>().unwrap();
+    ///
+    /// assert!(Arc::ptr_eq(&my_service_1, &my_service_2));
+    /// ```
+    pub fn add_singleton_shared<TService: Send + Sync + Injectable + 'static>(
+        &mut self,
+    ) -> &mut Self {
+        self.add_singleton::<Arc<TService>>()
+    }
+
+    /// Adds a scoped service to the service collection. The service must implement the [Injectable] trait.
     ///
     /// # Type arguments
     ///
@@ -237,6 +299,121 @@ impl ServiceCollection {
             .push(Box::new(ScopedContainerEntryBuilder::new(
                 TService::from_injector,
             )));
+        self.register::<TService>(TService::dependencies());
+
+        self
+    }
+
+    /// Adds a shared scoped service to the service collection. The service must implement the [Injectable] trait.
+    ///
+    /// The service is registered as `Arc<TService>`, so that it does not need to implement [Clone] and all
+    /// resolutions within a scope share the same allocation. Resolve it via [`ServiceProvider::resolve_shared`] or
+    /// as `Arc<TService>`.
+    ///
+    /// # Type arguments
+    ///
+    /// * `TService` - The type of the service to register.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nexustack::inject::injectable;
+    /// use nexustack::inject::ServiceCollection;
+    /// use nexustack::inject::ServiceScope;
+    /// use std::sync::Arc;
+    ///
+    /// struct MyService { }
+    ///
+    /// #[injectable]
+    /// impl MyService {
+    ///     pub fn new() -> Self {
+    ///         Self { }
+    ///     }
+    /// }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_scoped_shared::<MyService>();
+    /// let service_provider = services.build();
+    ///
+    /// let service_scope_1 = service_provider.resolve::<ServiceScope>().unwrap();
+    /// let my_service_1 = service_scope_1.service_provider().resolve_shared::<MyService>().unwrap();
+    ///
+    /// let service_scope_2 = service_provider.resolve::<ServiceScope>().unwrap();
+    /// let my_service_2 = service_scope_2.service_provider().resolve_shared::<MyService>().unwrap();
+    ///
+    /// assert!(!Arc::ptr_eq(&my_service_1, &my_service_2));
+    /// ```
+    pub fn add_scoped_shared<TService: Send + Sync + Injectable + 'static>(&mut self) -> &mut Self {
+        self.add_scoped::<Arc<TService>>()
+    }
+
+    /// Adds a scoped service to the service collection that can be resolved as the trait object `Box<TTrait>`.
+    /// The service must implement the [Injectable] trait.
+    ///
+    /// The service itself is registered as scoped service, as with [`ServiceCollection::add_scoped`]. Each
+    /// resolution of `Box<TTrait>` boxes a clone of the service instance of the scope.
+    ///
+    /// # Type arguments
+    ///
+    /// * `TTrait` - The trait object type to resolve the service as, e.g. `dyn MyTrait`. The trait object must be
+    ///   [Send] and [Sync], i.e. the trait must have these as supertraits.
+    /// * `TService` - The type of the service to register.
+    ///
+    /// # Argument
+    ///
+    /// * 'unsize' - Converts the boxed service into the trait object. As the conversion cannot be expressed
+    ///   generically, this is the identity closure `|service| service`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nexustack::inject::injectable;
+    /// use nexustack::inject::ServiceCollection;
+    /// use nexustack::inject::ServiceScope;
+    ///
+    /// trait Greeter: Send + Sync {
+    ///     fn greet(&self) -> String;
+    /// }
+    ///
+    /// #[derive(Clone)]
+    /// struct EnglishGreeter { }
+    ///
+    /// #[injectable]
+    /// impl EnglishGreeter {
+    ///     pub fn new() -> Self {
+    ///         Self { }
+    ///     }
+    /// }
+    ///
+    /// impl Greeter for EnglishGreeter {
+    ///     fn greet(&self) -> String {
+    ///         "Hello".to_owned()
+    ///     }
+    /// }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_scoped_as::<dyn Greeter, EnglishGreeter>(|service| service);
+    /// let service_provider = services.build();
+    ///
+    /// let service_scope = service_provider.resolve::<ServiceScope>().unwrap();
+    /// let greeter = service_scope.service_provider().resolve::<Box<dyn Greeter>>().unwrap();
+    ///
+    /// assert_eq!("Hello", greeter.greet());
+    /// ```
+    pub fn add_scoped_as<TTrait, TService>(
+        &mut self,
+        unsize: fn(Box<TService>) -> Box<TTrait>,
+    ) -> &mut Self
+    where
+        TTrait: ?Sized + Send + Sync + 'static,
+        TService: Clone + Send + Sync + Injectable + 'static,
+    {
+        self.add_scoped::<TService>();
+        self.scoped_builders
+            .push(Box::new(TransientContainerEntryBuilder::new(
+                move |injector| Ok(unsize(Box::new(injector.resolve::<TService>()?))),
+            )));
+        self.register::<Box<TTrait>>(vec![ServiceToken::create::<TService>()]);
 
         self
     }
@@ -292,6 +469,99 @@ impl ServiceCollection {
             .push(Box::new(TransientContainerEntryBuilder::new(
                 TService::from_injector,
             )));
+        self.register::<TService>(TService::dependencies());
+
+        self
+    }
+
+    /// Adds a lazily resolved service to the service collection, so that [`Lazy<TService>`] can be resolved and
+    /// injected. The service itself must be registered separately.
+    ///
+    /// # Type arguments
+    ///
+    /// * `TService` - The type of the lazily resolved service.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nexustack::inject::injectable;
+    /// use nexustack::inject::Lazy;
+    /// use nexustack::inject::ServiceCollection;
+    ///
+    /// #[derive(Clone)]
+    /// struct MyService { }
+    ///
+    /// #[injectable]
+    /// impl MyService {
+    ///     pub fn new() -> Self {
+    ///         Self { }
+    ///     }
+    /// }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_transient::<MyService>()
+    ///     .add_lazy::<MyService>();
+    /// let service_provider = services.build();
+    ///
+    /// let lazy_service = service_provider.resolve::<Lazy<MyService>>().unwrap();
+    /// let my_service = lazy_service.get().unwrap();
+    /// ```
+    pub fn add_lazy<TService: Send + Sync + 'static>(&mut self) -> &mut Self {
+        // Registered in every scope as well, so that the service is resolved from the nearest scope.
+        self.root_builders
+            .push(Box::new(TransientContainerEntryBuilder::new(
+                Lazy::<TService>::from_injector,
+            )));
+        self.scoped_builders
+            .push(Box::new(TransientContainerEntryBuilder::new(
+                Lazy::<TService>::from_injector,
+            )));
+        self.register::<Lazy<TService>>(vec![ServiceToken::create::<TService>()]);
+
+        self
+    }
+
+    /// Adds a service factory to the service collection, so that [`Factory<TService>`] can be resolved and
+    /// injected. The service itself does not need to be registered.
+    ///
+    /// # Type arguments
+    ///
+    /// * `TService` - The type of the service constructed by the factory.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nexustack::inject::injectable;
+    /// use nexustack::inject::Factory;
+    /// use nexustack::inject::ServiceCollection;
+    ///
+    /// struct MyService { }
+    ///
+    /// #[injectable]
+    /// impl MyService {
+    ///     pub fn new() -> Self {
+    ///         Self { }
+    ///     }
+    /// }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_factory::<MyService>();
+    /// let service_provider = services.build();
+    ///
+    /// let my_service_factory = service_provider.resolve::<Factory<MyService>>().unwrap();
+    /// let my_service = my_service_factory.create().unwrap();
+    /// ```
+    pub fn add_factory<TService: 'static>(&mut self) -> &mut Self {
+        // Registered in every scope as well, so that the service is constructed within the nearest scope.
+        self.root_builders
+            .push(Box::new(TransientContainerEntryBuilder::new(
+                Factory::<TService>::from_injector,
+            )));
+        self.scoped_builders
+            .push(Box::new(TransientContainerEntryBuilder::new(
+                Factory::<TService>::from_injector,
+            )));
+        self.register::<Factory<TService>>(vec![ServiceToken::create::<TService>()]);
 
         self
     }
@@ -364,6 +634,7 @@ impl ServiceCollection {
     ) -> &mut Self {
         self.root_builders
             .push(Box::new(SingletonContainerEntryBuilder::new(factory)));
+        self.register::<TService>(Vec::new());
 
         self
     }
@@ -441,6 +712,7 @@ impl ServiceCollection {
     ) -> &mut Self {
         self.scoped_builders
             .push(Box::new(ScopedContainerEntryBuilder::new(factory)));
+        self.register::<TService>(Vec::new());
 
         self
     }
@@ -513,7 +785,116 @@ impl ServiceCollection {
     ) -> &mut Self {
         self.root_builders
             .push(Box::new(TransientContainerEntryBuilder::new(factory)));
+        self.register::<TService>(Vec::new());
 
         self
     }
+
+    /// Marks a service as used, e.g. because it is resolved by an application part. The service and its transitive
+    /// dependencies are not reported by [`ServiceCollection::find_unused`].
+    ///
+    /// # Type arguments
+    ///
+    /// * `TService` - The type of the used service.
+    pub fn mark_used<TService: 'static>(&mut self) -> &mut Self {
+        self.used_services.push(ServiceToken::create::<TService>());
+
+        self
+    }
+
+    /// Returns the registered services that are not reachable from any used service, in registration order.
+    ///
+    /// A service is reachable if it was marked as used via [`ServiceCollection::mark_used`] or is a transitive
+    /// dependency of such a service. Dependencies are known for services implementing [`Injectable`] as well as for
+    /// trait objects, [`Lazy`] and [`Factory`] registrations. Dependencies resolved within factory functions are not
+    /// tracked, so these must be marked as used explicitly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nexustack::inject::injectable;
+    /// use nexustack::inject::ServiceCollection;
+    ///
+    /// #[derive(Clone)]
+    /// struct Dependency { }
+    ///
+    /// #[injectable]
+    /// impl Dependency {
+    ///     pub fn new() -> Self {
+    ///         Self { }
+    ///     }
+    /// }
+    ///
+    /// #[derive(Clone)]
+    /// struct MyService { }
+    ///
+    /// #[injectable]
+    /// impl MyService {
+    ///     pub fn new(dependency: Dependency) -> Self {
+    ///         Self { }
+    ///     }
+    /// }
+    ///
+    /// #[derive(Clone)]
+    /// struct UnusedService { }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_singleton::<Dependency>()
+    ///     .add_singleton::<MyService>()
+    ///     .add_value(UnusedService { })
+    ///     .mark_used::<MyService>();
+    ///
+    /// let unused = services.find_unused();
+    ///
+    /// assert_eq!(1, unused.len());
+    /// assert_eq!(std::any::type_name::<UnusedService>(), unused[0].type_name());
+    /// ```
+    #[must_use]
+    pub fn find_unused(&self) -> Vec<ServiceToken> {
+        self.find_unused_with([])
+    }
+
+    /// Returns the registered services that are not reachable from any used service or any of the specified
+    /// services, see [`ServiceCollection::find_unused`].
+    pub(crate) fn find_unused_with(
+        &self,
+        used_services: impl IntoIterator<Item = ServiceToken>,
+    ) -> Vec<ServiceToken> {
+        let mut reachable = HashSet::new();
+        let mut pending = self
+            .used_services
+            .iter()
+            .cloned()
+            .chain(used_services)
+            .collect::<VecDeque<_>>();
+
+        while let Some(service) = pending.pop_front() {
+            if !reachable.insert(*service.type_id()) {
+                continue;
+            }
+
+            pending.extend(
+                self.registrations
+                    .iter()
+                    .filter(|registration| registration.service.type_id() == service.type_id())
+                    .flat_map(|registration| registration.dependencies.iter().cloned()),
+            );
+        }
+
+        let mut reported = HashSet::<TypeId>::new();
+
+        self.registrations
+            .iter()
+            .filter(|registration| !reachable.contains(registration.service.type_id()))
+            .filter(|registration| reported.insert(*registration.service.type_id()))
+            .map(|registration| registration.service.clone())
+            .collect()
+    }
+
+    fn register<TService: 'static>(&mut self, dependencies: Vec<ServiceToken>) {
+        self.registrations.push(ServiceRegistration {
+            service: ServiceToken::create::<TService>(),
+            dependencies,
+        });
+    }
 }
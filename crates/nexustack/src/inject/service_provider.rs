@@ -7,8 +7,9 @@
 
 use crate::inject::{
     ConstructionResult, FromInjector,
-    container::Container,
+    container::{Container, ShutdownHook, ShutdownHooks},
     injection_error::{InjectionError, InjectionResult},
+    service_scope::ServiceScope,
     service_token::ServiceToken,
 };
 use crate::utils::{AtomicOnceCell, ensure_send, ensure_sync};
@@ -27,15 +28,30 @@ pub struct ServiceProvider {
 }
 
 impl ServiceProvider {
-    pub(crate) const fn create(container: Arc<AtomicOnceCell<Container>>) -> Self {
+    pub(crate) const fn create(
+        container: Arc<AtomicOnceCell<Container>>,
+        shutdown_hooks: Arc<ShutdownHooks>,
+    ) -> Self {
         Self {
-            inner: ServiceProviderInner::Container(container),
+            inner: ServiceProviderInner::Container(container, shutdown_hooks),
         }
     }
 
-    pub(crate) const fn create_weak(container: Weak<AtomicOnceCell<Container>>) -> Self {
+    pub(crate) const fn create_weak(
+        container: Weak<AtomicOnceCell<Container>>,
+        shutdown_hooks: Weak<ShutdownHooks>,
+    ) -> Self {
         Self {
-            inner: ServiceProviderInner::ContainerWeak(container),
+            inner: ServiceProviderInner::ContainerWeak(container, shutdown_hooks),
+        }
+    }
+
+    fn shutdown_hooks(&self) -> Option<Arc<ShutdownHooks>> {
+        match &self.inner {
+            ServiceProviderInner::Container(_, shutdown_hooks) => Some(shutdown_hooks.clone()),
+            ServiceProviderInner::ContainerWeak(_, shutdown_hooks_weak) => {
+                shutdown_hooks_weak.upgrade()
+            }
         }
     }
 
@@ -106,8 +122,10 @@ impl ServiceProvider {
     ///  
     pub fn resolve<TService: 'static>(&self) -> InjectionResult<TService> {
         match &self.inner {
-            ServiceProviderInner::Container(container) => Self::resolve_from_container(container),
-            ServiceProviderInner::ContainerWeak(container_weak) => {
+            ServiceProviderInner::Container(container, _) => {
+                Self::resolve_from_container(container)
+            }
+            ServiceProviderInner::ContainerWeak(container_weak, _) => {
                 container_weak.upgrade().map_or_else(
                     || {
                         Err(InjectionError::DroppedServiceProvider {
@@ -122,6 +140,113 @@ impl ServiceProvider {
         }
     }
 
+    /// Resolves a shared service from the provider. If the service cannot be resolved, an [`InjectionError`] is returned.
+    ///
+    /// Shared services are registered via [`crate::inject::ServiceCollection::add_singleton_shared`] or
+    /// [`crate::inject::ServiceCollection::add_scoped_shared`] and are handed out as reference-counted pointers
+    /// instead of clones. This is equivalent to resolving `Arc<TService>`.
+    ///
+    /// # Type arguments
+    ///
+    /// * `TService` - The type of the service to resolve from the provider.
+    ///
+    /// # Errors
+    ///  * `crate::inject::InjectionError` when the service cannot be resolved either due to a resolution error or when a constructor/factory function
+    ///    has raised a custom error. See the [`crate::inject::InjectionError`] enum for further information.
+    ///
+    pub fn resolve_shared<TService: 'static>(&self) -> InjectionResult<Arc<TService>> {
+        self.resolve::<Arc<TService>>()
+    }
+
+    /// Resolves a service from the provider, falling back to [`Default::default`] if the service is not registered.
+    ///
+    /// Only a missing registration of `TService` itself results in the default value. If the service is registered
+    /// but cannot be constructed, e.g. because one of its dependencies is missing, the error is returned as is.
+    ///
+    /// # Type arguments
+    ///
+    /// * `TService` - The type of the service to resolve from the provider.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nexustack::inject::ServiceCollection;
+    ///
+    /// #[derive(Clone, Default, Debug, PartialEq)]
+    /// struct RetryOptions {
+    ///     attempts: u32,
+    /// }
+    ///
+    /// let service_provider = ServiceCollection::new().build();
+    ///
+    /// let options = service_provider.resolve_or_default::<RetryOptions>().unwrap();
+    /// assert_eq!(options, RetryOptions::default());
+    /// ```
+    ///
+    /// # Errors
+    ///  * `crate::inject::InjectionError` when the service is registered but cannot be resolved either due to a resolution
+    ///    error or when a constructor/factory function has raised a custom error. See the [`crate::inject::InjectionError`]
+    ///    enum for further information.
+    ///
+    pub fn resolve_or_default<TService: Default + 'static>(&self) -> InjectionResult<TService> {
+        match self.resolve::<TService>() {
+            Err(InjectionError::ServiceNotFound {
+                service,
+                dependency_chain,
+            }) if dependency_chain.is_empty()
+                && service.type_id() == ServiceToken::create::<TService>().type_id() =>
+            {
+                Ok(TService::default())
+            }
+            result => result,
+        }
+    }
+
+    /// Creates a new child scope of the provider.
+    ///
+    /// The scoped services of the child scope are fresh instances, constructed when the scope is created, whereas
+    /// singletons and values are resolved from the provider and thus shared between the provider and all of its
+    /// scopes. Creating a scope from a scoped provider yields a nested scope with its own scoped services.
+    ///
+    /// The scope keeps its scoped services alive for as long as the [`ServiceScope`] or its provider exists, but
+    /// only holds a weak reference to the parent provider. The parent provider must therefore outlive the scope;
+    /// once it is dropped, services that are not scoped can no longer be resolved from the scope and a
+    /// [`InjectionError::DroppedServiceProvider`] error is returned instead.
+    ///
+    /// This is equivalent to resolving [`ServiceScope`] from the provider.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nexustack::inject::injectable;
+    /// use nexustack::inject::ServiceCollection;
+    ///
+    /// #[derive(Clone)]
+    /// struct MyService { }
+    ///
+    /// #[injectable]
+    /// impl MyService {
+    ///     pub fn new() -> Self {
+    ///         Self { }
+    ///     }
+    /// }
+    ///
+    /// let mut services = ServiceCollection::new();
+    /// services.add_scoped::<MyService>();
+    /// let service_provider = services.build();
+    ///
+    /// let service_scope = service_provider.create_scope().unwrap();
+    /// let my_service = service_scope.service_provider().resolve::<MyService>().unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///  * `crate::inject::InjectionError` when the provider has already been dropped. See the
+    ///    [`crate::inject::InjectionError`] enum for further information.
+    ///
+    pub fn create_scope(&self) -> InjectionResult<ServiceScope> {
+        self.resolve::<ServiceScope>()
+    }
+
     /// Creates a service that implemented [`FromInjector`] with the required dependencies loaded from the provider.
     /// If the service cannot be created or a dependency cannot be resolved, a [`crate::inject::ConstructionError`] is returned.
     ///
@@ -166,8 +291,10 @@ impl ServiceProvider {
     ///
     pub fn construct<TService: FromInjector + 'static>(&self) -> ConstructionResult<TService> {
         match &self.inner {
-            ServiceProviderInner::Container(container) => Self::construct_from_container(container),
-            ServiceProviderInner::ContainerWeak(container_weak) => {
+            ServiceProviderInner::Container(container, _) => {
+                Self::construct_from_container(container)
+            }
+            ServiceProviderInner::ContainerWeak(container_weak, _) => {
                 container_weak.upgrade().map_or_else(
                     || {
                         Err(InjectionError::DroppedServiceProvider {
@@ -182,10 +309,55 @@ impl ServiceProvider {
             }
         }
     }
+
+    /// Registers a hook that is invoked when the application shuts down.
+    ///
+    /// The hooks are invoked during the application's `before_shutdown` phase, after the `before_shutdown` phases
+    /// of all application parts completed. They are invoked one after the other in the reverse order of their
+    /// registration, so that services registering hooks during their construction are cleaned up before their
+    /// dependencies. Hooks registered via a scoped service provider are application-wide and outlive the scope.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nexustack::inject::ServiceCollection;
+    ///
+    /// let service_provider = ServiceCollection::new().build();
+    ///
+    /// service_provider.on_shutdown(|| async {
+    ///     println!("Flushing buffers");
+    /// });
+    /// ```
+    pub fn on_shutdown<F, Fut>(&self, hook: F)
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.add_shutdown_hook(Box::new(|| Box::pin(hook())));
+    }
+
+    pub(crate) fn add_shutdown_hook(&self, hook: ShutdownHook) {
+        // Without the hooks, the application is gone and there is nothing to shut down anymore.
+        if let Some(shutdown_hooks) = self.shutdown_hooks() {
+            shutdown_hooks.add(hook);
+        }
+    }
+
+    /// Invokes all registered shutdown hooks in the reverse order of their registration.
+    pub(crate) async fn run_shutdown_hooks(&self) {
+        let shutdown_hooks = self
+            .shutdown_hooks()
+            .map(|shutdown_hooks| shutdown_hooks.take())
+            .unwrap_or_default();
+
+        for shutdown_hook in shutdown_hooks.into_iter().rev() {
+            shutdown_hook().await;
+        }
+    }
 }
 
 #[derive(Clone)]
 enum ServiceProviderInner {
-    Container(Arc<AtomicOnceCell<Container>>),
-    ContainerWeak(Weak<AtomicOnceCell<Container>>),
+    Container(Arc<AtomicOnceCell<Container>>, Arc<ShutdownHooks>),
+    ContainerWeak(Weak<AtomicOnceCell<Container>>, Weak<ShutdownHooks>),
 }
@@ -8,7 +8,11 @@
 use crate::inject::service_provider::ServiceProvider;
 
 /// Represents a service-scope within the global service provider. A service-scope
-/// can be used to access scoped services.
+/// can be used to access scoped services and is created via [`ServiceProvider::create_scope`].
+///
+/// The scoped services of a scope are constructed exactly once, when the scope is
+/// created. Resolving them afterwards only hands out the existing instances, so a
+/// scope can be shared between threads or tasks without racing on construction.
 ///
 /// # Example
 ///
@@ -18,7 +18,9 @@ pub struct ServiceToken {
 }
 
 impl ServiceToken {
-    pub(crate) fn create<TService: 'static>() -> Self {
+    /// Creates the token representing the service type `TService`.
+    #[must_use]
+    pub fn create<TService: 'static>() -> Self {
         Self {
             type_id: TypeId::of::<TService>(),
             type_name: type_name::<TService>(),
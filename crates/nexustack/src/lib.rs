@@ -28,6 +28,10 @@ pub mod openapi;
 #[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", "src/cron/README.md"))]
 pub mod cron;
 
+#[cfg(feature = "cli")]
+#[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", "src/cli/README.md"))]
+pub mod cli;
+
 #[cfg(feature = "derive")]
 pub use nexustack_macros::module;
 
@@ -0,0 +1,178 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use crate::openapi::{example::SchemaExamples, schema::Schema, schema_builder::SchemaBuilder};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as _};
+use std::{fmt::Display, ops::Deref};
+
+/// A [`String`] containing at least `MIN` and at most `MAX` characters.
+///
+/// The bounds are enforced on construction and deserialization and are emitted as `minLength` and `maxLength` in the
+/// schema of the type. Like in JSON Schema, the length is measured in characters, not in bytes. A `MAX` of
+/// [`usize::MAX`] leaves the length unbounded.
+///
+/// # Example
+///
+/// ```rust
+/// use nexustack::openapi::BoundedString;
+///
+/// let name = BoundedString::<1, 32>::try_from("Ferris".to_owned()).unwrap();
+/// assert_eq!("Ferris", &*name);
+///
+/// assert!(BoundedString::<1, 32>::try_from(String::new()).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BoundedString<const MIN: usize, const MAX: usize>(String);
+
+/// A [`String`] containing at least one character.
+pub type NonEmptyString = BoundedString<1, { usize::MAX }>;
+
+impl<const MIN: usize, const MAX: usize> BoundedString<MIN, MAX> {
+    /// Constructs a bounded string from the specified value.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BoundedStringError`] if the number of characters is not within `MIN..=MAX`.
+    pub fn new(value: String) -> Result<Self, BoundedStringError> {
+        const { assert!(MIN <= MAX, "MIN must not be greater than MAX") };
+
+        let len = value.chars().count();
+
+        if (MIN..=MAX).contains(&len) {
+            Ok(Self(value))
+        } else {
+            Err(BoundedStringError {
+                len,
+                min: MIN,
+                max: MAX,
+            })
+        }
+    }
+
+    /// Returns the contained string.
+    #[must_use]
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> Deref for BoundedString<MIN, MAX> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> AsRef<str> for BoundedString<MIN, MAX> {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> Display for BoundedString<MIN, MAX> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> TryFrom<String> for BoundedString<MIN, MAX> {
+    type Error = BoundedStringError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> From<BoundedString<MIN, MAX>> for String {
+    fn from(value: BoundedString<MIN, MAX>) -> Self {
+        value.0
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> Serialize for BoundedString<MIN, MAX> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, const MIN: usize, const MAX: usize> Deserialize<'de> for BoundedString<MIN, MAX> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+
+        Self::new(value).map_err(D::Error::custom)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> Schema for BoundedString<MIN, MAX> {
+    type Example = String;
+    type Examples = std::vec::IntoIter<Self::Example>;
+
+    #[inline]
+    fn describe<B>(schema_builder: B) -> Result<B::Ok, B::Error>
+    where
+        B: SchemaBuilder<Self::Examples>,
+    {
+        let is_human_readable = schema_builder.is_human_readable();
+
+        schema_builder.describe_str(
+            Some(MIN),
+            (MAX != usize::MAX).then_some(MAX),
+            None,
+            None,
+            None,
+            None,
+            || {
+                let mut examples = <str as SchemaExamples>::examples(is_human_readable)?
+                    .filter(|example| (MIN..=MAX).contains(&example.chars().count()))
+                    .map(ToOwned::to_owned)
+                    .collect::<Vec<_>>();
+
+                if examples.is_empty() {
+                    examples.push("a".repeat(MIN));
+                }
+
+                Ok(examples)
+            },
+            false,
+        )
+    }
+}
+
+/// The error returned when constructing a [`BoundedString`] from a value with a length outside its bounds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoundedStringError {
+    len: usize,
+    min: usize,
+    max: usize,
+}
+
+impl Display for BoundedStringError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.max == usize::MAX {
+            write!(
+                f,
+                "expected at least {} characters, found {}",
+                self.min, self.len
+            )
+        } else {
+            write!(
+                f,
+                "expected between {} and {} characters, found {}",
+                self.min, self.max, self.len
+            )
+        }
+    }
+}
+
+impl std::error::Error for BoundedStringError {}
@@ -0,0 +1,194 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use crate::openapi::{
+    example::SchemaExamples,
+    schema::Schema,
+    schema_builder::{IntoSchemaBuilder, SchemaBuilder},
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as _};
+use std::{fmt::Display, ops::Deref};
+
+/// A [`Vec`] containing at least `MIN` and at most `MAX` items.
+///
+/// The bounds are enforced on construction and deserialization and are emitted as `minItems` and `maxItems` in the
+/// schema of the type.
+///
+/// # Example
+///
+/// ```rust
+/// use nexustack::openapi::BoundedVec;
+///
+/// let tags = BoundedVec::<&str, 1, 10>::try_from(vec!["rust", "openapi"]).unwrap();
+/// assert_eq!(2, tags.len());
+///
+/// assert!(BoundedVec::<&str, 1, 10>::try_from(Vec::new()).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BoundedVec<T, const MIN: usize, const MAX: usize>(Vec<T>);
+
+impl<T, const MIN: usize, const MAX: usize> BoundedVec<T, MIN, MAX> {
+    /// Constructs a bounded vector from the specified items.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BoundedVecError`] if the number of items is not within `MIN..=MAX`.
+    pub fn new(items: Vec<T>) -> Result<Self, BoundedVecError> {
+        const { assert!(MIN <= MAX, "MIN must not be greater than MAX") };
+
+        if (MIN..=MAX).contains(&items.len()) {
+            Ok(Self(items))
+        } else {
+            Err(BoundedVecError {
+                len: items.len(),
+                min: MIN,
+                max: MAX,
+            })
+        }
+    }
+
+    /// Returns the contained items.
+    #[must_use]
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> Deref for BoundedVec<T, MIN, MAX> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> AsRef<[T]> for BoundedVec<T, MIN, MAX> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> TryFrom<Vec<T>> for BoundedVec<T, MIN, MAX> {
+    type Error = BoundedVecError;
+
+    fn try_from(items: Vec<T>) -> Result<Self, Self::Error> {
+        Self::new(items)
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> From<BoundedVec<T, MIN, MAX>> for Vec<T> {
+    fn from(value: BoundedVec<T, MIN, MAX>) -> Self {
+        value.0
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> IntoIterator for BoundedVec<T, MIN, MAX> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T, const MIN: usize, const MAX: usize> IntoIterator for &'a BoundedVec<T, MIN, MAX> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> Serialize for BoundedVec<T, MIN, MAX>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T, const MIN: usize, const MAX: usize> Deserialize<'de> for BoundedVec<T, MIN, MAX>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let items = Vec::<T>::deserialize(deserializer)?;
+        let len = items.len();
+
+        Self::new(items).map_err(|_| {
+            D::Error::invalid_length(len, &format!("between {MIN} and {MAX} items").as_str())
+        })
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> Schema for BoundedVec<T, MIN, MAX>
+where
+    T: Schema,
+{
+    type Example = Vec<<T as Schema>::Example>;
+    type Examples = <[Self::Example; 2] as IntoIterator>::IntoIter;
+
+    #[inline]
+    fn describe<B>(schema_builder: B) -> Result<B::Ok, B::Error>
+    where
+        B: SchemaBuilder<Self::Examples>,
+    {
+        let is_human_readable = schema_builder.is_human_readable();
+        let example = |len: usize| {
+            std::iter::repeat(())
+                .flat_map(
+                    |()| match <T as SchemaExamples>::examples(is_human_readable) {
+                        Ok(p) => either::Either::Left(p.map(Ok)),
+                        Err(e) => either::Either::Right(std::iter::once(Err(e))),
+                    },
+                )
+                .take(len)
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        <T as Schema>::describe(
+            schema_builder
+                .describe_seq(
+                    Some(MIN),
+                    Some(MAX),
+                    false,
+                    None,
+                    || Ok([example(MIN)?, example(MAX.min(MIN.max(2)))?]),
+                    false,
+                )?
+                .into_schema_builder(),
+        )
+    }
+}
+
+/// The error returned when constructing a [`BoundedVec`] from a number of items outside its bounds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoundedVecError {
+    len: usize,
+    min: usize,
+    max: usize,
+}
+
+impl Display for BoundedVecError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expected between {} and {} items, found {}",
+            self.min, self.max, self.len
+        )
+    }
+}
+
+impl std::error::Error for BoundedVecError {}
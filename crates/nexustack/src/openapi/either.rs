@@ -84,6 +84,48 @@ where
         }
     }
 
+    fn describe_field_examples<X: serde::Serialize>(
+        &mut self,
+        key: &'static str,
+        examples: impl IntoIterator<Item = X>,
+    ) -> Result<(), Self::Error> {
+        match self {
+            Self::Left(left) => left.describe_field_examples(key, examples),
+            Self::Right(right) => right.describe_field_examples(key, examples),
+        }
+    }
+
+    fn deny_unknown_fields(&mut self) -> Result<(), Self::Error> {
+        match self {
+            Self::Left(left) => left.deny_unknown_fields(),
+            Self::Right(right) => right.deny_unknown_fields(),
+        }
+    }
+
+    fn describe_translations(
+        &mut self,
+        translations: &[(&'static str, &'static str)],
+    ) -> Result<(), Self::Error> {
+        match self {
+            Self::Left(left) => left.describe_translations(translations),
+            Self::Right(right) => right.describe_translations(translations),
+        }
+    }
+
+    fn describe_example_names(&mut self, names: &[&'static str]) -> Result<(), Self::Error> {
+        match self {
+            Self::Left(left) => left.describe_example_names(names),
+            Self::Right(right) => right.describe_example_names(names),
+        }
+    }
+
+    fn describe_modifier(&mut self, modifier: FieldMod) -> Result<(), Self::Error> {
+        match self {
+            Self::Left(left) => left.describe_modifier(modifier),
+            Self::Right(right) => right.describe_modifier(modifier),
+        }
+    }
+
     fn end(self) -> Result<Self::Ok, Self::Error> {
         match self {
             Self::Left(left) => left.end(),
@@ -312,6 +354,48 @@ where
         }
     }
 
+    fn describe_element_examples<K: Schema + serde::Serialize, X: serde::Serialize>(
+        &mut self,
+        key: K,
+        examples: impl IntoIterator<Item = X>,
+    ) -> Result<(), Self::Error> {
+        match self {
+            Self::Left(left) => left.describe_element_examples(key, examples),
+            Self::Right(right) => right.describe_element_examples(key, examples),
+        }
+    }
+
+    fn deny_additional_elements(&mut self) -> Result<(), Self::Error> {
+        match self {
+            Self::Left(left) => left.deny_additional_elements(),
+            Self::Right(right) => right.deny_additional_elements(),
+        }
+    }
+
+    fn describe_translations(
+        &mut self,
+        translations: &[(&'static str, &'static str)],
+    ) -> Result<(), Self::Error> {
+        match self {
+            Self::Left(left) => left.describe_translations(translations),
+            Self::Right(right) => right.describe_translations(translations),
+        }
+    }
+
+    fn describe_example_names(&mut self, names: &[&'static str]) -> Result<(), Self::Error> {
+        match self {
+            Self::Left(left) => left.describe_example_names(names),
+            Self::Right(right) => right.describe_example_names(names),
+        }
+    }
+
+    fn describe_modifier(&mut self, modifier: FieldMod) -> Result<(), Self::Error> {
+        match self {
+            Self::Left(left) => left.describe_modifier(modifier),
+            Self::Right(right) => right.describe_modifier(modifier),
+        }
+    }
+
     fn end(self) -> Result<Self::Ok, Self::Error> {
         match self {
             Self::Left(left) => left.end(),
@@ -387,6 +471,17 @@ where
         }
     }
 
+    fn describe_field_examples<X: serde::Serialize>(
+        &mut self,
+        key: &'static str,
+        examples: impl IntoIterator<Item = X>,
+    ) -> Result<(), Self::Error> {
+        match self {
+            Self::Left(left) => left.describe_field_examples(key, examples),
+            Self::Right(right) => right.describe_field_examples(key, examples),
+        }
+    }
+
     fn end(self) -> Result<(), Self::Error> {
         match self {
             Self::Left(left) => left.end(),
@@ -543,6 +638,30 @@ where
         }
     }
 
+    fn describe_translations(
+        &mut self,
+        translations: &[(&'static str, &'static str)],
+    ) -> Result<(), Self::Error> {
+        match self {
+            Self::Left(left) => left.describe_translations(translations),
+            Self::Right(right) => right.describe_translations(translations),
+        }
+    }
+
+    fn describe_example_names(&mut self, names: &[&'static str]) -> Result<(), Self::Error> {
+        match self {
+            Self::Left(left) => left.describe_example_names(names),
+            Self::Right(right) => right.describe_example_names(names),
+        }
+    }
+
+    fn describe_modifier(&mut self, modifier: FieldMod) -> Result<(), Self::Error> {
+        match self {
+            Self::Left(left) => left.describe_modifier(modifier),
+            Self::Right(right) => right.describe_modifier(modifier),
+        }
+    }
+
     fn end(self) -> Result<Self::Ok, Self::Error> {
         match self {
             Self::Left(left) => left.end(),
@@ -1133,6 +1252,18 @@ where
         }
     }
 
+    fn describe_any<I: IntoIterator<IntoIter = Ex>>(
+        self,
+        description: Option<&'static str>,
+        examples: impl Fn() -> Result<I, Self::Error>,
+        deprecated: bool,
+    ) -> Result<Self::Ok, Self::Error> {
+        match self {
+            Self::Left(left) => left.describe_any(description, examples, deprecated),
+            Self::Right(right) => right.describe_any(description, examples, deprecated),
+        }
+    }
+
     fn describe_unit_struct<I: IntoIterator<IntoIter = Ex>>(
         self,
         id: Option<SchemaId>,
@@ -116,7 +116,8 @@ impl<T: ?Sized + Schema> SchemaExamples for T {
 
 ////////////////////////////////////////////////////////////////////////////////
 
-struct CollectedExamples<E: Iterator<Item: Serialize + 'static>, Error: error::Error> {
+/// The builder returned by [`ExampleExtractor`] for compound schemas, which holds on to the examples of the schema.
+pub struct CollectedExamples<E: Iterator<Item: Serialize + 'static>, Error: error::Error> {
     examples: E,
     is_human_readable: bool,
     _err: PhantomData<fn() -> Error>,
@@ -776,13 +777,14 @@ impl<
 
 ////////////////////////////////////////////////////////////////////////////////
 
-struct ExampleExtractor<Error: error::Error> {
+/// A [`SchemaBuilder`] that ignores the described schema and yields its examples instead.
+pub struct ExampleExtractor<Error: error::Error> {
     is_human_readable: bool,
     _err: PhantomData<fn() -> Error>,
 }
 
 impl<Error: error::Error> ExampleExtractor<Error> {
-    fn new(is_human_readable: bool) -> Self {
+    pub(crate) fn new(is_human_readable: bool) -> Self {
         Self {
             is_human_readable,
             _err: PhantomData,
@@ -1165,3 +1167,16 @@ impl<E: Iterator<Item: Serialize + 'static>, Error: error::Error> SchemaBuilder<
         self.is_human_readable
     }
 }
+
+/// Serializes an example value, naming the path of the field that failed to serialize on error.
+pub(crate) fn serialize_example<T: Serialize, Error: error::Error>(
+    example: T,
+) -> Result<serde_json::Value, Error> {
+    serde_path_to_error::serialize(&example, serde_json::value::Serializer).map_err(|err| {
+        Error::custom(format!(
+            "failed to serialize example at `{}`: {}",
+            err.path(),
+            err.inner()
+        ))
+    })
+}
@@ -12,7 +12,7 @@
 use crate::openapi::{
     example::SchemaExamples,
     schema::Schema,
-    schema_builder::{SchemaBuilder, TupleSchemaBuilder},
+    schema_builder::{IntoSchemaBuilder, SchemaBuilder, TupleSchemaBuilder},
 };
 
 // Does not require T: Schema.
@@ -47,8 +47,12 @@ macro_rules! array_impls {
                     B: SchemaBuilder<Self::Examples>,
                 {
                     let is_human_readable = schema_builder.is_human_readable();
-                    let mut tuple_schema_builder = schema_builder.describe_tuple(
-                        2,
+                    // Arrays are described as sequences with an exact length, so that the element
+                    // schema is emitted only once.
+                    let seq_schema_builder = schema_builder.describe_seq(
+                        Some($len),
+                        Some($len),
+                        false,
                         None,
                         || {
                             Ok([
@@ -93,16 +97,7 @@ macro_rules! array_impls {
                         false,
                     )?;
 
-                    for _ in 0..$len {
-                        TupleSchemaBuilder::collect_element(
-                            &mut tuple_schema_builder,
-                            None,
-                            false,
-                            <T>::describe,
-                        )?;
-                    }
-
-                    tuple_schema_builder.end()
+                    <T as Schema>::describe(seq_schema_builder.into_schema_builder())
                 }
             }
         )+
@@ -115,3 +110,87 @@ array_impls! {
     21 22 23 24 25 26 27 28 29 30
     31 32
 }
+
+mod test {
+    #[test]
+    fn test_array_4_u8_schema() {
+        use crate::openapi::json::{SchemaCollection, Specification, build_schema_with_collection};
+        use std::{cell::RefCell, rc::Rc};
+
+        let schema_collection = Rc::new(RefCell::new(SchemaCollection::new()));
+
+        let schema = build_schema_with_collection::<[u8; 4]>(
+            Specification::OpenAPI3_1,
+            schema_collection.clone(),
+        )
+        .unwrap();
+
+        let schemas_object = Rc::try_unwrap(schema_collection)
+            .map_err(|_| "Should be the only Rc strong reference")
+            .unwrap()
+            .into_inner()
+            .to_schemas_object();
+
+        pretty_assertions::assert_eq!(
+            serde_json::to_value(schema).unwrap(),
+            serde_json::json!({
+                "examples": [
+                    [0, 1, 255, 0],
+                    [0, 1, 255, 0],
+                    [0, 1, 255, 0],
+                ],
+                "items": {
+                    "examples": [0, 1, 255],
+                    "maximum": 255,
+                    "minimum": 0,
+                    "type": "integer",
+                },
+                "maxItems": 4,
+                "minItems": 4,
+                "type": "array",
+            })
+        );
+        pretty_assertions::assert_eq!(
+            serde_json::to_value(schemas_object).unwrap(),
+            serde_json::json!({})
+        );
+    }
+
+    #[test]
+    fn test_array_2_string_schema() {
+        use crate::openapi::json::{SchemaCollection, Specification, build_schema_with_collection};
+        use std::{cell::RefCell, rc::Rc};
+
+        let schema_collection = Rc::new(RefCell::new(SchemaCollection::new()));
+
+        let schema = build_schema_with_collection::<[String; 2]>(
+            Specification::OpenAPI3_0,
+            schema_collection.clone(),
+        )
+        .unwrap();
+
+        let schemas_object = Rc::try_unwrap(schema_collection)
+            .map_err(|_| "Should be the only Rc strong reference")
+            .unwrap()
+            .into_inner()
+            .to_schemas_object();
+
+        pretty_assertions::assert_eq!(
+            serde_json::to_value(schema).unwrap(),
+            serde_json::json!({
+                "example": ["", "h"],
+                "items": {
+                    "example": "",
+                    "type": "string",
+                },
+                "maxItems": 2,
+                "minItems": 2,
+                "type": "array",
+            })
+        );
+        pretty_assertions::assert_eq!(
+            serde_json::to_value(schemas_object).unwrap(),
+            serde_json::json!({})
+        );
+    }
+}
@@ -0,0 +1,86 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use crate::openapi::{Schema, SchemaBuilder};
+use rust_decimal::Decimal;
+
+impl Schema for Decimal {
+    type Example = &'static str;
+    type Examples = <[Self::Example; 5] as IntoIterator>::IntoIter;
+
+    #[inline]
+    fn describe<B>(schema_builder: B) -> Result<B::Ok, B::Error>
+    where
+        B: SchemaBuilder<Self::Examples>,
+    {
+        // rust_decimal serializes decimals as strings (regardless of the serializer being
+        // human-readable) to avoid the precision loss of a floating point representation.
+        schema_builder.describe_str(
+            Some(1),
+            None,
+            Some(r"^-?\d+(\.\d+)?$"),
+            Some("decimal"),
+            None,
+            Some("A fixed-precision decimal number"),
+            || {
+                Ok([
+                    "0",
+                    "1",
+                    "-1.5",
+                    "3.1415926535",
+                    "79228162514264337593543950335",
+                ])
+            },
+            false,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_decimal_schema() {
+        use crate::openapi::json::{SchemaCollection, Specification, build_schema_with_collection};
+        use std::{cell::RefCell, rc::Rc};
+
+        let schema_collection = Rc::new(RefCell::new(SchemaCollection::new()));
+
+        let schema = build_schema_with_collection::<rust_decimal::Decimal>(
+            Specification::OpenAPI3_1,
+            schema_collection.clone(),
+        )
+        .unwrap();
+
+        let schemas_object = Rc::try_unwrap(schema_collection)
+            .map_err(|_| "Should be the only Rc strong reference")
+            .unwrap()
+            .into_inner()
+            .to_schemas_object();
+
+        pretty_assertions::assert_eq!(
+            serde_json::to_value(schema).unwrap(),
+            serde_json::json!({
+                "description": "A fixed-precision decimal number",
+                "examples": [
+                    "0",
+                    "1",
+                    "-1.5",
+                    "3.1415926535",
+                    "79228162514264337593543950335",
+                ],
+                "format": "decimal",
+                "minLength": 1,
+                "pattern": "^-?\\d+(\\.\\d+)?$",
+                "type": "string",
+            })
+        );
+        pretty_assertions::assert_eq!(
+            serde_json::to_value(schemas_object).unwrap(),
+            serde_json::json!({})
+        );
+    }
+}
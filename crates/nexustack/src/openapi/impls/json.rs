@@ -0,0 +1,70 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use crate::openapi::{
+    schema::Schema,
+    schema_builder::{MapSchemaBuilder, SchemaBuilder},
+};
+use serde_json::{Map, Value, json};
+
+impl Schema for Value {
+    type Example = Self;
+    type Examples = <[Self::Example; 5] as IntoIterator>::IntoIter;
+
+    #[inline]
+    fn describe<B>(schema_builder: B) -> Result<B::Ok, B::Error>
+    where
+        B: SchemaBuilder<Self::Examples>,
+    {
+        schema_builder.describe_any(
+            None,
+            || {
+                Ok([
+                    json!({ "key": "value" }),
+                    json!([1, 2, 3]),
+                    json!("text"),
+                    json!(42),
+                    json!(true),
+                ])
+            },
+            false,
+        )
+    }
+}
+
+impl Schema for Map<String, Value> {
+    type Example = Self;
+    type Examples = <[Self::Example; 2] as IntoIterator>::IntoIter;
+
+    #[inline]
+    fn describe<B>(schema_builder: B) -> Result<B::Ok, B::Error>
+    where
+        B: SchemaBuilder<Self::Examples>,
+    {
+        let mut map_schema_builder = schema_builder.describe_map(
+            None,
+            None,
+            || {
+                Ok([
+                    Self::new(),
+                    Self::from_iter([
+                        ("name".to_owned(), json!("Ferris")),
+                        ("tags".to_owned(), json!(["crab", "rust"])),
+                    ]),
+                ])
+            },
+            false,
+        )?;
+        map_schema_builder.collect_additional_elements(
+            String::describe,
+            None,
+            false,
+            Value::describe,
+        )?;
+        map_schema_builder.end()
+    }
+}
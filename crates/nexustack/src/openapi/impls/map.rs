@@ -92,3 +92,10 @@ map_impl! {
 
     HashMap<K: Eq + std::hash::Hash, V, H: std::hash::BuildHasher>
 }
+
+#[cfg(feature = "indexmap")]
+map_impl! {
+    use indexmap::IndexMap;
+
+    IndexMap<K: Eq + std::hash::Hash, V, H: std::hash::BuildHasher>
+}
@@ -12,6 +12,7 @@
 pub mod array;
 pub mod deref;
 pub mod ffi;
+pub mod json;
 pub mod map;
 pub mod net;
 pub mod primitives;
@@ -23,6 +24,9 @@ pub mod tuple;
 #[cfg(feature = "chrono")]
 pub mod chrono;
 
+#[cfg(feature = "decimal")]
+pub mod decimal;
+
 #[cfg(feature = "url")]
 pub mod url;
 
@@ -149,7 +149,7 @@ impl Schema for std::net::Ipv4Addr {
             )
         } else {
             let mut tuple_schema_builder =
-                schema_builder.describe_tuple(2, Some(description), examples, false)?;
+                schema_builder.describe_tuple(4, Some(description), examples, false)?;
 
             for _ in 0..4 {
                 tuple_schema_builder.collect_element(None, false, <u8 as Schema>::describe)?;
@@ -210,7 +210,7 @@ impl Schema for std::net::Ipv6Addr {
             )
         } else {
             let mut tuple_schema_builder =
-                schema_builder.describe_tuple(2, Some(description), examples, false)?;
+                schema_builder.describe_tuple(16, Some(description), examples, false)?;
 
             for _ in 0..16 {
                 tuple_schema_builder.collect_element(None, false, <u8 as Schema>::describe)?;
@@ -550,6 +550,34 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_ip_v4_addr_schema_openapi_3_0() {
+        use crate::openapi::json::{Specification, build_schema};
+
+        let schema = build_schema::<std::net::Ipv4Addr>(Specification::OpenAPI3_0).unwrap();
+        let schema = serde_json::to_value(schema).unwrap();
+
+        pretty_assertions::assert_eq!(schema["type"], "string");
+        pretty_assertions::assert_eq!(schema["format"], "ipv4");
+        pretty_assertions::assert_eq!(schema["example"], "1.2.3.4");
+        pretty_assertions::assert_eq!(schema["minLength"], 7);
+        pretty_assertions::assert_eq!(schema["maxLength"], 15);
+    }
+
+    #[test]
+    fn test_ip_v6_addr_schema_openapi_3_0() {
+        use crate::openapi::json::{Specification, build_schema};
+
+        let schema = build_schema::<std::net::Ipv6Addr>(Specification::OpenAPI3_0).unwrap();
+        let schema = serde_json::to_value(schema).unwrap();
+
+        pretty_assertions::assert_eq!(schema["type"], "string");
+        pretty_assertions::assert_eq!(schema["format"], "ipv6");
+        pretty_assertions::assert_eq!(schema["example"], "2001:db8:3333:4444:5555:6666:7777:8888");
+        pretty_assertions::assert_eq!(schema["minLength"], 2);
+        pretty_assertions::assert_eq!(schema["maxLength"], 39);
+    }
+
     #[test]
     fn test_ip_v6_addr_schema() {
         use crate::openapi::json::{SchemaCollection, Specification, build_schema_with_collection};
@@ -34,7 +34,7 @@ impl Schema for Nanos {
             None,
             None,
             None,
-            Some("Whole milliseconds that describing a subpart of a whole second"),
+            Some("Nanoseconds describing a subpart of a whole second"),
             || Ok([0, 300, 621, 1_000_000_000 - 1]),
             false,
         )
@@ -151,11 +151,12 @@ mod test {
         pretty_assertions::assert_eq!(
             serde_json::to_value(schema).unwrap(),
             serde_json::json!({
+                "format": "int32",
                 "type": "integer",
                 "minimum": 0,
                 "exclusiveMaximum": 1_000_000_000,
                 "examples": [0, 300, 621, 999_999_999],
-                "description": "Whole milliseconds that describing a subpart of a whole second"
+                "description": "Nanoseconds describing a subpart of a whole second"
             })
         );
         pretty_assertions::assert_eq!(
@@ -223,10 +224,11 @@ mod test {
                             ],
                             "maximum": 18_446_744_073_709_551_615_u64,
                             "minimum": 0,
+                            "format": "int64",
                             "type": "integer",
                         },
                         "nanos": {
-                            "description": "Whole milliseconds that describing a subpart of a whole second",
+                            "description": "Nanoseconds describing a subpart of a whole second",
                             "examples": [
                                 0,
                                 300,
@@ -235,6 +237,7 @@ mod test {
                             ],
                             "exclusiveMaximum": 1_000_000_000,
                             "minimum": 0,
+                            "format": "int32",
                             "type": "integer",
                         },
                     },
@@ -301,6 +304,7 @@ mod test {
                             ],
                             "exclusiveMaximum": 1_000_000_000,
                             "minimum": 0,
+                            "format": "int32",
                             "type": "integer",
                         },
                         "secs_since_epoch": {
@@ -312,6 +316,7 @@ mod test {
                             ],
                             "maximum": 18_446_744_073_709_551_615_u64,
                             "minimum": 0,
+                            "format": "int64",
                             "type": "integer",
                         },
                     },
@@ -172,6 +172,7 @@ mod test {
                         ],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                 ],
@@ -242,6 +243,7 @@ mod test {
                         ],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
@@ -254,6 +256,7 @@ mod test {
                         ],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                 ],
@@ -303,18 +306,21 @@ mod test {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                 ],
@@ -364,24 +370,28 @@ mod test {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                 ],
@@ -431,30 +441,35 @@ mod test {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                 ],
@@ -504,36 +519,42 @@ mod test {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                 ],
@@ -583,42 +604,49 @@ mod test {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                      {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                 ],
@@ -668,48 +696,56 @@ mod test {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                 ],
@@ -759,54 +795,63 @@ mod test {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                 ],
@@ -820,6 +865,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::too_many_lines)]
     fn test_tuple_10_i32_schema() {
         use crate::openapi::json::{SchemaCollection, Specification, build_schema_with_collection};
         use std::{cell::RefCell, rc::Rc};
@@ -857,60 +903,70 @@ mod test {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                      {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                      {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                 ],
@@ -970,66 +1026,77 @@ mod test {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                 ],
@@ -1090,72 +1157,84 @@ mod test {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                 ],
@@ -1217,78 +1296,91 @@ mod test {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                 ],
@@ -1351,84 +1443,98 @@ mod test {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                      {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                      {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                      {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                 ],
@@ -1492,90 +1598,105 @@ mod test {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                 ],
@@ -1640,96 +1761,112 @@ mod test {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                     {
                         "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer",
                     },
                 ],
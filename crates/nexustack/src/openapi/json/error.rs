@@ -11,6 +11,7 @@ use crate::{
 };
 use thiserror::Error;
 
+/// The error raised when a JSON schema cannot be constructed.
 #[derive(Clone, Debug, Error)]
 #[non_exhaustive]
 pub enum Error {
@@ -22,11 +23,13 @@ pub enum Error {
         conflicting_callsite,
     )]
     ConflictingDefinition {
+        /// The identifier of the conflicting schema
         schema_id: SchemaId,
+        /// The callsite of the conflicting definition
         conflicting_callsite: Callsite,
     },
     /// Raised when a custom error is thrown during the construction of a schema.
-    #[error("schema cannot be constructed due to an error")]
+    #[error("schema cannot be constructed due to an error: {0}")]
     Custom(
         /// The underlying construction error
         String,
@@ -34,6 +37,7 @@ pub enum Error {
 }
 
 impl Error {
+    /// Constructs a custom error with the specified message.
     pub fn custom<T>(msg: T) -> Self
     where
         T: std::fmt::Display,
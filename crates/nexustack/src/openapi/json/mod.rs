@@ -17,6 +17,7 @@
 //!
 //! - **Schema Generation**: Functions like [`build_schema`] and [`build_schema_with_collection`] allow you to generate schemas for any type implementing [`Schema`].
 //! - **Schema Collection**: Support for schema deduplication and referencing via [`SchemaCollection`], enabling reuse and reference of complex types.
+//! - **Bundling**: [`build_bundled_schema`] produces a self-contained JSON Schema document with all referenced schemas under `$defs`.
 //! - **Flexible Builders**: Implements a rich set of builder patterns for structs, tuples, enums, maps, combinators, and more, allowing fine-grained control over schema generation.
 //! - **Field Modifiers**: Expressive support for field modifiers such as `read_only`, `write_only`, `deprecated`, and default values.
 //! - **Nullability & Examples**: Handles nullable types and example values, adapting to `OpenAPI` version differences.
@@ -28,6 +29,7 @@
 //! Typical usage involves calling [`build_schema`] or [`build_schema_with_collection`] with a Rust type and desired specification. The module is designed to be extensible and composable, supporting advanced `OpenAPI` schema features.
 
 use crate::openapi::{
+    example::serialize_example,
     impossible::Impossible,
     json::schema_collection::SchemaCollectionResolutionError,
     nop::Nop,
@@ -39,6 +41,7 @@ use crate::openapi::{
         TupleSchemaBuilder, TupleStructSchemaBuilder, TupleVariantSchemaBuilder, VariantTag,
     },
 };
+use indexmap::IndexMap;
 use serde::Serialize;
 use serde_json::Value as JsonValue;
 use std::{
@@ -53,7 +56,7 @@ mod error;
 mod schema_collection;
 mod specification;
 
-use error::Error;
+pub use error::Error;
 pub use schema_collection::SchemaCollection;
 pub use specification::*;
 
@@ -149,6 +152,116 @@ pub fn build_schema_with_collection<T: Schema>(
     T::describe(schema_builder)
 }
 
+/// Build an OpenAPI-compatible JSON Schema for a Rust type and post-process it with the specified transform.
+///
+/// The transform runs on the final schema, e.g. to add vendor extensions or to adjust the generated output.
+///
+/// # Arguments
+/// * `specification` - The `OpenAPI` specification version to target (e.g., [`Specification::OpenAPI3_0`] or [`Specification::OpenAPI3_1`]).
+/// * `transform` - The function that receives the generated schema and returns the post-processed schema.
+///
+/// # Returns
+/// * `Result<SchemaOrReferenceObject, Error>` - On success, returns the post-processed schema object or reference. On failure, returns an error describing the problem.
+///
+/// # Errors
+///
+/// Returns an error if schema construction fails, see [`build_schema`], or if the transform returns an error.
+///
+/// # Example
+///
+/// ```rust
+/// use nexustack::openapi::json::SchemaOrReferenceObject;
+/// use nexustack::openapi::json::Specification;
+/// use nexustack::openapi::api_schema;
+/// use nexustack::openapi::json::build_schema_with_transform;
+///
+/// /// Custom struct definition
+/// #[api_schema]
+/// struct MyType {
+///     /// Field i
+///     i: i32,
+/// }
+///
+/// let schema = build_schema_with_transform::<MyType>(Specification::OpenAPI3_1, |mut schema| {
+///     if let SchemaOrReferenceObject::Schema(schema) = &mut schema {
+///         schema.extensions.insert("x-internal".to_owned(), true.into());
+///     }
+///     Ok(schema)
+/// });
+/// ```
+pub fn build_schema_with_transform<T: Schema>(
+    specification: Specification,
+    transform: impl FnOnce(SchemaOrReferenceObject) -> Result<SchemaOrReferenceObject, Error>,
+) -> Result<SchemaOrReferenceObject, Error> {
+    transform(build_schema::<T>(specification)?)
+}
+
+/// Build a self-contained JSON Schema document for a Rust type implementing [`Schema`].
+///
+/// The schema of the root type is inlined and all schemas it references are placed under `$defs`, so that every
+/// `$ref` resolves within the document. This makes the result usable with plain JSON Schema validators that know
+/// nothing about `OpenAPI` components. The document targets [`Specification::OpenAPI3_1`], which is compatible with
+/// JSON Schema Draft 2020-12.
+///
+/// # Returns
+/// * `Result<SchemaObject, Error>` - On success, returns the bundled schema document. On failure, returns an error describing the problem.
+///
+/// # Errors
+///
+/// Returns an error if schema construction fails, see [`build_schema_with_collection`].
+///
+/// # Example
+///
+/// ```rust
+/// use nexustack::openapi::api_schema;
+/// use nexustack::openapi::json::build_bundled_schema;
+///
+/// /// Custom struct definition
+/// #[api_schema]
+/// struct MyType {
+///     /// Field i
+///     i: i32,
+/// }
+///
+/// let document = build_bundled_schema::<MyType>().unwrap();
+/// assert!(document.defs.is_some());
+/// ```
+pub fn build_bundled_schema<T: Schema>() -> Result<SchemaObject, Error> {
+    const REF_BASE: &str = "#/$defs";
+
+    let schema_collection = Rc::new(RefCell::new(
+        SchemaCollection::new().with_ref_base(REF_BASE),
+    ));
+    let schema =
+        build_schema_with_collection::<T>(Specification::OpenAPI3_1, schema_collection.clone())?;
+    let defs = schema_collection.take().to_schemas_object();
+
+    let mut document = match schema {
+        SchemaOrReferenceObject::Schema(schema) => schema,
+        SchemaOrReferenceObject::Reference(reference) => {
+            let definition = reference
+                .r#ref
+                .strip_prefix(REF_BASE)
+                .and_then(|name| name.strip_prefix('/'))
+                .and_then(|name| defs.get(name));
+
+            match definition {
+                Some(SchemaOrReferenceObject::Schema(definition)) => definition.clone(),
+                _ => SchemaObject {
+                    all_of: Some(vec![reference.into()]),
+                    ..SchemaObject::default()
+                },
+            }
+        }
+    };
+
+    if !defs.is_empty() {
+        document.defs = Some(defs);
+    }
+
+    Ok(document)
+}
+
 macro_rules! set {
     () => {
         std::collections::BTreeSet::new()
@@ -160,10 +273,10 @@ macro_rules! set {
 
 macro_rules! map {
     () => {
-        std::collections::HashMap::new()
+        std::iter::FromIterator::from_iter([])
     };
     ($($k:expr => $v:expr),+ $(,)?) => {
-        std::collections::HashMap::from([
+        std::iter::FromIterator::from_iter([
             $(($k,$v),)+
         ])
     };
@@ -322,7 +435,7 @@ impl Transform<SchemaOrReferenceObject> for StructFieldTransform<'_> {
             .schema_builder
             .result_schema
             .properties
-            .get_or_insert_with(|| HashMap::with_capacity(self.schema_builder.len));
+            .get_or_insert_with(|| IndexMap::with_capacity(self.schema_builder.len));
 
         if !self.is_optional {
             self.schema_builder
@@ -450,6 +563,39 @@ impl StructSchemaBuilder for StructJsonSchemaBuilder {
         ))
     }
 
+    fn describe_field_examples<E: Serialize>(
+        &mut self,
+        key: &'static str,
+        examples: impl IntoIterator<Item = E>,
+    ) -> Result<(), Self::Error> {
+        add_property_examples(&mut self.result_schema, self.specification, key, examples)
+    }
+
+    fn deny_unknown_fields(&mut self) -> Result<(), Self::Error> {
+        self.result_schema.additional_properties = Some(AdditionalProperties::Boolean(false));
+        Ok(())
+    }
+
+    fn describe_translations(
+        &mut self,
+        translations: &[(&'static str, &'static str)],
+    ) -> Result<(), Self::Error> {
+        self.result_schema.extensions.insert(
+            "x-translations".to_owned(),
+            translations_extension(translations),
+        );
+        Ok(())
+    }
+
+    fn describe_example_names(&mut self, names: &[&'static str]) -> Result<(), Self::Error> {
+        name_examples(&mut self.result_schema, names)
+    }
+
+    fn describe_modifier(&mut self, modifier: FieldMod) -> Result<(), Self::Error> {
+        apply_modifier(&mut self.result_schema, modifier);
+        Ok(())
+    }
+
     fn end(self) -> Result<Self::Ok, Self::Error> {
         if let Some(schema_collection) = self.schema_collection {
             let mut schema_collection = schema_collection.borrow_mut();
@@ -935,6 +1081,99 @@ macro_rules! describe_unsigned_integer_key {
 
 struct MapKeyPatternBuilder;
 
+/// Records example values on a previously described property.
+///
+/// Referenced schemas are kept intact by wrapping the reference in an `allOf` together with the examples.
+fn add_property_examples<E: Serialize>(
+    schema: &mut SchemaObject,
+    specification: Specification,
+    key: &str,
+    examples: impl IntoIterator<Item = E>,
+) -> Result<(), Error> {
+    let examples = examples
+        .into_iter()
+        .map(serialize_example)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if examples.is_empty() {
+        return Ok(());
+    }
+
+    let property = schema
+        .properties
+        .as_mut()
+        .and_then(|properties| properties.get_mut(key))
+        .ok_or_else(|| Error::custom(format!("no entry for field {key} to attach examples to")))?;
+
+    let apply = |schema_object: &mut SchemaObject| match specification {
+        Specification::OpenAPI3_0 => {
+            schema_object.example = examples.into_iter().next();
+        }
+        Specification::OpenAPI3_1 => {
+            schema_object.examples = Some(specification::Examples::Vec(examples));
+        }
+    };
+
+    match property {
+        BoxSchemaOrReferenceObject::Schema(schema_object) => apply(schema_object),
+        BoxSchemaOrReferenceObject::Reference(reference_object) => {
+            let mut additional_schema = schema!();
+            apply(&mut additional_schema);
+            *property = all_of!(reference_object.clone(), additional_schema).into();
+        }
+    }
+
+    Ok(())
+}
+
+/// Builds the `x-translations` extension holding the translated descriptions per language tag.
+const fn apply_modifier(schema: &mut SchemaObject, modifier: FieldMod) {
+    match modifier {
+        FieldMod::Read => schema.read_only = Some(true),
+        FieldMod::Write => schema.write_only = Some(true),
+        FieldMod::ReadWrite => {}
+    }
+}
+
+// Converts the examples of the schema into the keyed map form, pairing them with the names by position. In
+// `OpenAPI` 3.0, where only a single unnamed example exists, the schema is left unchanged.
+fn name_examples(schema: &mut SchemaObject, names: &[&'static str]) -> Result<(), Error> {
+    let Some(specification::Examples::Vec(examples)) = schema.examples.take() else {
+        return Ok(());
+    };
+
+    if examples.len() != names.len() {
+        return Err(Error::custom(format!(
+            "expected {expected} example names, found {found}",
+            expected = examples.len(),
+            found = names.len()
+        )));
+    }
+
+    schema.examples = Some(specification::Examples::Map(
+        names
+            .iter()
+            .map(|name| Cow::Borrowed(*name))
+            .zip(examples)
+            .collect(),
+    ));
+
+    Ok(())
+}
+
+fn translations_extension(translations: &[(&'static str, &'static str)]) -> JsonValue {
+    translations
+        .iter()
+        .map(|(language, description)| {
+            (
+                (*language).to_owned(),
+                serde_json::json!({ "description": description }),
+            )
+        })
+        .collect::<serde_json::Map<_, _>>()
+        .into()
+}
+
 fn key_must_be_a_string() -> Error {
     Error::custom("key must be a string")
 }
@@ -1421,7 +1660,7 @@ impl Transform<SchemaOrReferenceObject> for NamedMapElementTransform<'_> {
             .get_or_insert_with(|| {
                 self.schema_builder
                     .len
-                    .map_or_else(HashMap::new, HashMap::with_capacity)
+                    .map_or_else(IndexMap::new, IndexMap::with_capacity)
             });
 
         if !self.is_optional {
@@ -1731,6 +1970,43 @@ impl MapSchemaBuilder for MapJsonSchemaBuilder {
         ))
     }
 
+    fn describe_element_examples<K: Schema + Serialize, E: Serialize>(
+        &mut self,
+        key: K,
+        examples: impl IntoIterator<Item = E>,
+    ) -> Result<(), Self::Error> {
+        let key = serialize_key(key)?;
+        add_property_examples(&mut self.result_schema, self.specification, &key, examples)
+    }
+
+    fn deny_additional_elements(&mut self) -> Result<(), Self::Error> {
+        // Additional elements that were already described (e.g. flattened maps) stay allowed.
+        self.result_schema
+            .additional_properties
+            .get_or_insert(AdditionalProperties::Boolean(false));
+        Ok(())
+    }
+
+    fn describe_translations(
+        &mut self,
+        translations: &[(&'static str, &'static str)],
+    ) -> Result<(), Self::Error> {
+        self.result_schema.extensions.insert(
+            "x-translations".to_owned(),
+            translations_extension(translations),
+        );
+        Ok(())
+    }
+
+    fn describe_example_names(&mut self, names: &[&'static str]) -> Result<(), Self::Error> {
+        name_examples(&mut self.result_schema, names)
+    }
+
+    fn describe_modifier(&mut self, modifier: FieldMod) -> Result<(), Self::Error> {
+        apply_modifier(&mut self.result_schema, modifier);
+        Ok(())
+    }
+
     fn end(self) -> Result<Self::Ok, Self::Error> {
         if let Some(schema_collection) = self.schema_collection {
             let mut schema_collection = schema_collection.borrow_mut();
@@ -1834,6 +2110,14 @@ impl StructVariantSchemaBuilder for StructVariantJsonSchemaBuilder<'_> {
             .describe_field_optional(key, modifier, default, description, deprecated)
     }
 
+    fn describe_field_examples<E: Serialize>(
+        &mut self,
+        key: &'static str,
+        examples: impl IntoIterator<Item = E>,
+    ) -> Result<(), Self::Error> {
+        self.inner.describe_field_examples(key, examples)
+    }
+
     fn end(self) -> Result<(), Self::Error> {
         let subschema = self.inner.end()?;
 
@@ -1867,14 +2151,19 @@ impl StructVariantSchemaBuilder for StructVariantJsonSchemaBuilder<'_> {
                 let mut subschema = subschema;
 
                 if let SchemaOrReferenceObject::Schema(schema_object) = &mut subschema {
-                    schema_object.properties.get_or_insert_default().insert(
-                        tag.into(),
-                        schema! {
-                            r#type: "string".into(),
-                            r#enum: vec![self.name.into()],
-                        }
-                        .into(),
-                    );
+                    // The tag is serialized before the fields of the variant.
+                    schema_object
+                        .properties
+                        .get_or_insert_default()
+                        .shift_insert(
+                            0,
+                            tag.into(),
+                            schema! {
+                                r#type: "string".into(),
+                                r#enum: vec![self.name.into()],
+                            }
+                            .into(),
+                        );
 
                     schema_object
                         .required
@@ -2135,14 +2424,19 @@ impl Transform<SchemaOrReferenceObject> for NewTypeVariantTransform<'_> {
                 }
 
                 if let SchemaOrReferenceObject::Schema(schema_object) = &mut subschema {
-                    schema_object.properties.get_or_insert_default().insert(
-                        tag.into(),
-                        schema! {
-                            r#type: "string".into(),
-                            r#enum: vec![self.name.into()],
-                        }
-                        .into(),
-                    );
+                    // The tag is serialized before the fields of the variant.
+                    schema_object
+                        .properties
+                        .get_or_insert_default()
+                        .shift_insert(
+                            0,
+                            tag.into(),
+                            schema! {
+                                r#type: "string".into(),
+                                r#enum: vec![self.name.into()],
+                            }
+                            .into(),
+                        );
 
                     schema_object
                         .required
@@ -2275,6 +2569,9 @@ struct EnumJsonSchemaBuilder {
     subschemas: Vec<SchemaOrReferenceObject>,
     variant_names: Vec<&'static str>,
     exhaustive: bool,
+    translations: Option<JsonValue>,
+    example_names: Vec<&'static str>,
+    modifier: FieldMod,
 }
 
 impl EnumJsonSchemaBuilder {
@@ -2312,6 +2609,9 @@ impl EnumJsonSchemaBuilder {
             subschemas: Vec::with_capacity(capacity),
             variant_names: Vec::with_capacity(capacity),
             exhaustive,
+            translations: None,
+            example_names: Vec::new(),
+            modifier: FieldMod::ReadWrite,
         }
     }
 }
@@ -2437,6 +2737,25 @@ impl EnumSchemaBuilder for EnumJsonSchemaBuilder {
         ))
     }
 
+    fn describe_translations(
+        &mut self,
+        translations: &[(&'static str, &'static str)],
+    ) -> Result<(), Self::Error> {
+        self.translations = Some(translations_extension(translations));
+        Ok(())
+    }
+
+    fn describe_example_names(&mut self, names: &[&'static str]) -> Result<(), Self::Error> {
+        self.example_names = names.to_vec();
+        Ok(())
+    }
+
+    fn describe_modifier(&mut self, modifier: FieldMod) -> Result<(), Self::Error> {
+        self.modifier = modifier;
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_lines)]
     fn end(mut self) -> Result<Self::Ok, Self::Error> {
         // TODO: Discriminator, nullable
 
@@ -2490,7 +2809,8 @@ impl EnumSchemaBuilder for EnumJsonSchemaBuilder {
                     self.subschemas.push(
                         schema! {
                             r#type: "object".into(),
-                            required: set! (tag.into(), content.into()),
+                            // Unknown unit variants, e.g. the `other` variant, have no content.
+                            required: set! (tag.into()),
                             properties: map!{
                                 tag.into() => schema! {
                                     r#type: "string".into(),
@@ -2512,6 +2832,12 @@ impl EnumSchemaBuilder for EnumJsonSchemaBuilder {
         result_schema.description = self.description.map(Into::into);
         result_schema.deprecated = if self.deprecated { Some(true) } else { None };
 
+        if let Some(translations) = self.translations {
+            result_schema
+                .extensions
+                .insert("x-translations".to_owned(), translations);
+        }
+
         match self.specification {
             Specification::OpenAPI3_0 => {
                 result_schema.example = self
@@ -2523,6 +2849,12 @@ impl EnumSchemaBuilder for EnumJsonSchemaBuilder {
             }
         }
 
+        if !self.example_names.is_empty() {
+            name_examples(&mut result_schema, &self.example_names)?;
+        }
+
+        apply_modifier(&mut result_schema, self.modifier);
+
         if let Some(schema_collection) = self.schema_collection {
             let mut schema_collection = schema_collection.borrow_mut();
             if let Some(schema_id) = self.id {
@@ -3133,9 +3465,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
     ) -> Result<Self::OptionSchemaBuilder, Self::Error> {
         let examples = examples()?
             .into_iter()
-            .map(serde_json::to_value)
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(Error::custom)?;
+            .map(serialize_example)
+            .collect::<Result<Vec<_>, _>>()?;
         // Keep the most outer description and examples
         self.description = self.description.or(description);
         self.examples = self.examples.or(Some(examples));
@@ -3156,9 +3487,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
         let examples = self.examples.unwrap_or(
             examples()?
                 .into_iter()
-                .map(serde_json::to_value)
-                .collect::<Result<Vec<_>, _>>()
-                .map_err(Error::custom)?,
+                .map(serialize_example)
+                .collect::<Result<Vec<_>, _>>()?,
         );
 
         result.description = self.description.or(description).map(Into::into);
@@ -3223,9 +3553,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
             self.examples.unwrap_or(
                 examples()?
                     .into_iter()
-                    .map(serde_json::to_value)
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(Error::custom)?
+                    .map(serialize_example)
+                    .collect::<Result<Vec<_>, _>>()?
             ),
             deprecated,
         )
@@ -3254,9 +3583,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
             self.examples.unwrap_or(
                 examples()?
                     .into_iter()
-                    .map(serde_json::to_value)
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(Error::custom)?
+                    .map(serialize_example)
+                    .collect::<Result<Vec<_>, _>>()?
             ),
             deprecated,
         )
@@ -3273,6 +3601,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
         examples: impl Fn() -> Result<I, Self::Error>,
         deprecated: bool,
     ) -> Result<Self::Ok, Self::Error> {
+        let format = format.or(Some("int32"));
+
         describe_integer!(
             i32,
             self,
@@ -3285,9 +3615,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
             self.examples.unwrap_or(
                 examples()?
                     .into_iter()
-                    .map(serde_json::to_value)
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(Error::custom)?
+                    .map(serialize_example)
+                    .collect::<Result<Vec<_>, _>>()?
             ),
             deprecated,
         )
@@ -3304,6 +3633,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
         examples: impl Fn() -> Result<I, Self::Error>,
         deprecated: bool,
     ) -> Result<Self::Ok, Self::Error> {
+        let format = format.or(Some("int64"));
+
         describe_integer!(
             i64,
             self,
@@ -3316,9 +3647,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
             self.examples.unwrap_or(
                 examples()?
                     .into_iter()
-                    .map(serde_json::to_value)
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(Error::custom)?
+                    .map(serialize_example)
+                    .collect::<Result<Vec<_>, _>>()?
             ),
             deprecated,
         )
@@ -3347,9 +3677,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
             self.examples.unwrap_or(
                 examples()?
                     .into_iter()
-                    .map(serde_json::to_value)
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(Error::custom)?
+                    .map(serialize_example)
+                    .collect::<Result<Vec<_>, _>>()?
             ),
             deprecated,
         )
@@ -3378,9 +3707,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
             self.examples.unwrap_or(
                 examples()?
                     .into_iter()
-                    .map(serde_json::to_value)
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(Error::custom)?
+                    .map(serialize_example)
+                    .collect::<Result<Vec<_>, _>>()?
             ),
             deprecated,
         )
@@ -3397,6 +3725,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
         examples: impl Fn() -> Result<I, Self::Error>,
         deprecated: bool,
     ) -> Result<Self::Ok, Self::Error> {
+        let format = format.or(Some("int32"));
+
         describe_integer!(
             u32,
             self,
@@ -3409,9 +3739,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
             self.examples.unwrap_or(
                 examples()?
                     .into_iter()
-                    .map(serde_json::to_value)
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(Error::custom)?
+                    .map(serialize_example)
+                    .collect::<Result<Vec<_>, _>>()?
             ),
             deprecated,
         )
@@ -3428,6 +3757,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
         examples: impl Fn() -> Result<I, Self::Error>,
         deprecated: bool,
     ) -> Result<Self::Ok, Self::Error> {
+        let format = format.or(Some("int64"));
+
         describe_integer!(
             u64,
             self,
@@ -3440,9 +3771,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
             self.examples.unwrap_or(
                 examples()?
                     .into_iter()
-                    .map(serde_json::to_value)
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(Error::custom)?
+                    .map(serialize_example)
+                    .collect::<Result<Vec<_>, _>>()?
             ),
             deprecated,
         )
@@ -3469,9 +3799,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
             self.examples.unwrap_or(
                 examples()?
                     .into_iter()
-                    .map(serde_json::to_value)
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(Error::custom)?,
+                    .map(serialize_example)
+                    .collect::<Result<Vec<_>, _>>()?,
             ),
             deprecated
         )
@@ -3498,9 +3827,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
             self.examples.unwrap_or(
                 examples()?
                     .into_iter()
-                    .map(serde_json::to_value)
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(Error::custom)?,
+                    .map(serialize_example)
+                    .collect::<Result<Vec<_>, _>>()?,
             ),
             deprecated
         )
@@ -3520,9 +3848,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
         let examples = self.examples.unwrap_or(
             examples()?
                 .into_iter()
-                .map(serde_json::to_value)
-                .collect::<Result<Vec<_>, _>>()
-                .map_err(Error::custom)?,
+                .map(serialize_example)
+                .collect::<Result<Vec<_>, _>>()?,
         );
 
         result.description = self.description.or(description).map(Into::into);
@@ -3620,9 +3947,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
 
         let examples = examples()?
             .into_iter()
-            .map(serde_json::to_value)
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(Error::custom)?;
+            .map(serialize_example)
+            .collect::<Result<Vec<_>, _>>()?;
 
         match self.specification {
             Specification::OpenAPI3_0 => {
@@ -3665,6 +3991,44 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
         Ok(result.into())
     }
 
+    fn describe_any<I: IntoIterator<IntoIter = E>>(
+        self,
+        description: Option<&'static str>,
+        examples: impl Fn() -> Result<I, Self::Error>,
+        deprecated: bool,
+    ) -> Result<Self::Ok, Self::Error> {
+        let mut result = schema! {};
+
+        let examples = self.examples.unwrap_or(
+            examples()?
+                .into_iter()
+                .map(serialize_example)
+                .collect::<Result<Vec<_>, _>>()?,
+        );
+
+        result.description = self.description.or(description).map(Into::into);
+
+        if deprecated || self.deprecated {
+            result.deprecated = Some(true);
+        }
+
+        match self.specification {
+            Specification::OpenAPI3_0 => {
+                result.example = examples.into_iter().next();
+
+                // An empty schema does not permit null values in OAS3.0
+                if self.nullable {
+                    result.nullable = Some(true);
+                }
+            }
+            Specification::OpenAPI3_1 => {
+                result.examples = Some(specification::Examples::Vec(examples));
+            }
+        }
+
+        Ok(result.into())
+    }
+
     fn describe_unit_struct<I: IntoIterator<IntoIter = E>>(
         self,
         id: Option<SchemaId>,
@@ -3760,9 +4124,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
 
         let examples = examples()?
             .into_iter()
-            .map(serde_json::to_value)
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(Error::custom)?;
+            .map(serialize_example)
+            .collect::<Result<Vec<_>, _>>()?;
         // Keep the most outer description and examples
         self.description = self.description.or(description);
         self.examples = self.examples.or(Some(examples));
@@ -3790,9 +4153,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
                 self.examples.or(Some(
                     examples()?
                         .into_iter()
-                        .map(serde_json::to_value)
-                        .collect::<Result<Vec<_>, _>>()
-                        .map_err(Error::custom)?,
+                        .map(serialize_example)
+                        .collect::<Result<Vec<_>, _>>()?,
                 )),
                 deprecated || self.deprecated,
                 min_len,
@@ -3819,9 +4181,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
             self.examples.or(Some(
                 examples()?
                     .into_iter()
-                    .map(serde_json::to_value)
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(Error::custom)?,
+                    .map(serialize_example)
+                    .collect::<Result<Vec<_>, _>>()?,
             )),
             deprecated || self.deprecated,
             self.nullable,
@@ -3874,9 +4235,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
             self.examples.or(Some(
                 examples()?
                     .into_iter()
-                    .map(serde_json::to_value)
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(Error::custom)?,
+                    .map(serialize_example)
+                    .collect::<Result<Vec<_>, _>>()?,
             )),
             deprecated || self.deprecated,
             self.nullable,
@@ -3928,9 +4288,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
             self.examples.or(Some(
                 examples()?
                     .into_iter()
-                    .map(serde_json::to_value)
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(Error::custom)?,
+                    .map(serialize_example)
+                    .collect::<Result<Vec<_>, _>>()?,
             )),
             deprecated,
             self.nullable,
@@ -3983,9 +4342,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
             self.examples.or(Some(
                 examples()?
                     .into_iter()
-                    .map(serde_json::to_value)
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(Error::custom)?,
+                    .map(serialize_example)
+                    .collect::<Result<Vec<_>, _>>()?,
             )),
             deprecated || self.deprecated,
             self.nullable,
@@ -4040,9 +4398,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
             self.examples.or(Some(
                 examples()?
                     .into_iter()
-                    .map(serde_json::to_value)
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(Error::custom)?,
+                    .map(serialize_example)
+                    .collect::<Result<Vec<_>, _>>()?,
             )),
             deprecated || self.deprecated,
             self.nullable,
@@ -4065,9 +4422,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
                 self.examples.or(Some(
                     examples()?
                         .into_iter()
-                        .map(serde_json::to_value)
-                        .collect::<Result<Vec<_>, _>>()
-                        .map_err(Error::custom)?,
+                        .map(serialize_example)
+                        .collect::<Result<Vec<_>, _>>()?,
                 )),
                 deprecated || self.deprecated,
                 self.nullable,
@@ -4091,9 +4447,8 @@ impl<E: Iterator<Item: Serialize + 'static>> SchemaBuilder<E> for JsonSchemaBuil
             self.examples.or(Some(
                 examples()?
                     .into_iter()
-                    .map(serde_json::to_value)
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(Error::custom)?,
+                    .map(serialize_example)
+                    .collect::<Result<Vec<_>, _>>()?,
             )),
             deprecated || self.deprecated,
             self.nullable,
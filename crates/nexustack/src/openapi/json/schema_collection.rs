@@ -9,7 +9,10 @@ use crate::{
     Callsite,
     openapi::{json::specification::SchemaOrReferenceObject, schema_builder::SchemaId},
 };
-use std::{borrow::Cow, collections::HashMap};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+};
 
 /// Errors that can occur during schema resolution in a [`SchemaCollection`].
 ///
@@ -41,7 +44,7 @@ pub struct SchemaCollection {
     /// The map of schema names to their schema object and callsite.
     entries: HashMap<&'static str, (SchemaOrReferenceObject, Callsite)>,
     /// The base path used for schema references.
-    base_path: &'static str,
+    base_path: Cow<'static, str>,
 }
 
 impl SchemaCollection {
@@ -66,11 +69,42 @@ impl SchemaCollection {
     #[must_use]
     pub fn with_base_path(base_path: &'static str) -> Self {
         Self {
-            base_path,
+            base_path: Cow::Borrowed(base_path),
             entries: HashMap::new(),
         }
     }
 
+    /// Replaces the base that schema references are resolved against.
+    ///
+    /// The base is a JSON pointer or URI prefix, e.g. `#/$defs` or `schemas.json#/definitions`, to which the schema
+    /// name is appended. A trailing `/` is ignored.
+    ///
+    /// # Arguments
+    /// * `ref_base` - The base to use for schema references.
+    ///
+    /// # Returns
+    ///
+    /// The `SchemaCollection` with the specified reference base.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nexustack::openapi::json::SchemaCollection;
+    ///
+    /// let collection = SchemaCollection::new().with_ref_base("#/$defs");
+    /// ```
+    #[must_use]
+    pub fn with_ref_base(mut self, ref_base: impl Into<Cow<'static, str>>) -> Self {
+        let mut ref_base = ref_base.into();
+
+        if ref_base.ends_with('/') {
+            ref_base.to_mut().pop();
+        }
+
+        self.base_path = ref_base;
+        self
+    }
+
     /// Resolves a reference to a schema by its [`SchemaId`].
     ///
     /// # Arguments
@@ -94,14 +128,12 @@ impl SchemaCollection {
             let (_, callsite) = entry;
 
             if callsite == schema_id.callsite() {
-                let base_path = self.base_path;
-                let name = schema_id.name();
-                return Ok(format!("{base_path}/{name}"));
+                return Ok(self.ref_path(schema_id));
             }
 
             return Err(SchemaCollectionResolutionError::ConflictingDefinition {
                 schema_id: schema_id.clone(),
-                conflicting_callsite: callsite.clone(),
+                conflicting_callsite: *callsite,
             });
         }
 
@@ -121,9 +153,13 @@ impl SchemaCollection {
     /// The reference string for the added schema.
     pub fn set(&mut self, schema_id: &SchemaId, schema: SchemaOrReferenceObject) -> String {
         self.entries
-            .insert(schema_id.name(), (schema, schema_id.callsite().clone()));
+            .insert(schema_id.name(), (schema, *schema_id.callsite()));
 
-        let base_path = self.base_path;
+        self.ref_path(schema_id)
+    }
+
+    fn ref_path(&self, schema_id: &SchemaId) -> String {
+        let base_path = &self.base_path;
         let name = schema_id.name();
 
         format!("{base_path}/{name}")
@@ -133,16 +169,14 @@ impl SchemaCollection {
     ///
     /// # Returns
     ///
-    /// A `HashMap` mapping schema names to their schema objects.
+    /// A `BTreeMap` mapping schema names to their schema objects. The schemas are ordered by name, so the
+    /// serialized object is stable across runs.
     #[must_use]
-    pub fn to_schemas_object(self) -> HashMap<Cow<'static, str>, SchemaOrReferenceObject> {
-        let mut result = HashMap::with_capacity(self.entries.len());
-
-        for (name, (schema, _)) in self.entries {
-            result.insert(Cow::Borrowed(name), schema);
-        }
-
-        result
+    pub fn to_schemas_object(self) -> BTreeMap<Cow<'static, str>, SchemaOrReferenceObject> {
+        self.entries
+            .into_iter()
+            .map(|(name, (schema, _))| (Cow::Borrowed(name), schema))
+            .collect()
     }
 }
 
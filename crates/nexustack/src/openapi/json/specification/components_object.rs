@@ -13,7 +13,7 @@ use super::{
 };
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Holds a set of reusable objects for different aspects of the OAS.
 ///
@@ -24,7 +24,7 @@ use std::collections::HashMap;
 pub struct ComponentsObject {
     /// An object to hold reusable Schema Objects.
     #[serde(rename = "schemas", default, skip_serializing_if = "Option::is_none")]
-    pub schemas: Option<HashMap<Cow<'static, str>, SchemaOrReferenceObject>>, // TODO: Serialize to JSON object
+    pub schemas: Option<BTreeMap<Cow<'static, str>, SchemaOrReferenceObject>>, // TODO: Serialize to JSON object
 
     /// An object to hold reusable Response Objects.
     #[serde(rename = "responses", default, skip_serializing_if = "Option::is_none")]
@@ -7,6 +7,8 @@
 
 use super::{ContactObject, LicenseObject};
 use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
 
 /// The object provides metadata about the API.
 ///
@@ -52,4 +54,41 @@ pub struct InfoObject {
     /// (which is distinct from the `OpenAPI` Specification version or the API implementation version).
     #[serde(rename = "version")]
     pub version: String,
+    /// Specification extensions (`x-` prefixed vendor fields) of the info.
+    #[serde(flatten, default, skip_serializing_if = "HashMap::is_empty")]
+    pub extensions: HashMap<String, JsonValue>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::InfoObject;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_extensions_are_flattened() {
+        let info = InfoObject {
+            title: "My API".into(),
+            summary: None,
+            description: None,
+            terms_of_service: None,
+            contact: None,
+            license: None,
+            version: "1.0.0".into(),
+            extensions: HashMap::from([(
+                "x-logo".into(),
+                serde_json::json!({ "url": "https://example.com/logo.png" }),
+            )]),
+        };
+
+        pretty_assertions::assert_eq!(
+            serde_json::to_value(info).unwrap(),
+            serde_json::json!({
+                "title": "My API",
+                "version": "1.0.0",
+                "x-logo": {
+                    "url": "https://example.com/logo.png"
+                }
+            })
+        );
+    }
 }
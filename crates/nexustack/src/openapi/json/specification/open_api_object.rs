@@ -10,6 +10,7 @@ use super::{
     PathsObject, ServerObject, TagObject,
 };
 use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
 use std::collections::HashMap;
 
 /// This is the root object of the `OpenAPI` document.
@@ -85,4 +86,7 @@ pub struct OpenAPIObject {
         skip_serializing_if = "Option::is_none"
     )]
     pub external_docs: Option<ExternalDocumentationObject>,
+    /// Specification extensions (`x-` prefixed vendor fields) of the document.
+    #[serde(flatten, default, skip_serializing_if = "HashMap::is_empty")]
+    pub extensions: HashMap<String, JsonValue>,
 }
@@ -10,6 +10,7 @@ use super::{
     RequestBodyOrReferenceObject, ResponseObject, ServerObject,
 };
 use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
 use std::{collections::HashMap, ops::Not};
 
 /// Describes a single API operation on a path.
@@ -110,4 +111,7 @@ pub struct OperationObject {
     /// it will be overridden by this value.
     #[serde(rename = "servers", default, skip_serializing_if = "Option::is_none")]
     pub servers: Option<Vec<ServerObject>>,
+    /// Specification extensions (`x-` prefixed vendor fields) of the operation.
+    #[serde(flatten, default, skip_serializing_if = "HashMap::is_empty")]
+    pub extensions: HashMap<String, JsonValue>,
 }
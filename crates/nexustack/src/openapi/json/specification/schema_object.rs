@@ -6,11 +6,12 @@
  */
 
 use super::{DiscriminatorObject, ExternalDocumentationObject, ReferenceObject, XmlObject};
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use serde_json::{Number as JsonNumber, Value as JsonValue};
 use std::{
     borrow::Cow,
-    collections::{BTreeSet, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
 };
 
 /// The Schema Object allows the definition of input and output data types.
@@ -150,7 +151,7 @@ pub struct SchemaObject {
         default,
         skip_serializing_if = "Option::is_none"
     )]
-    pub properties: Option<HashMap<Cow<'static, str>, BoxSchemaOrReferenceObject>>,
+    pub properties: Option<IndexMap<Cow<'static, str>, BoxSchemaOrReferenceObject>>,
 
     /// Defines additional properties allowed in an object.
     ///
@@ -315,6 +316,16 @@ pub struct SchemaObject {
     /// **`OpenAPI` 3.0 and 3.1**
     #[serde(rename = "enum", default, skip_serializing_if = "Option::is_none")]
     pub r#enum: Option<Vec<JsonValue>>,
+
+    /// The schema definitions that can be referenced from within the schema.
+    ///
+    /// **`OpenAPI` 3.1 only**. Not supported in `OpenAPI` 3.0.
+    #[serde(rename = "$defs", default, skip_serializing_if = "Option::is_none")]
+    pub defs: Option<BTreeMap<Cow<'static, str>, SchemaOrReferenceObject>>,
+
+    /// Specification extensions (`x-` prefixed vendor fields) of the schema.
+    #[serde(flatten, default, skip_serializing_if = "HashMap::is_empty")]
+    pub extensions: HashMap<String, JsonValue>,
 }
 
 /// Represents multiple examples for a schema.
@@ -13,6 +13,8 @@ pub mod __private;
 #[cfg(feature = "derive")]
 pub use nexustack_macros::api_schema;
 
+mod bounded_string;
+mod bounded_vec;
 mod either;
 mod error;
 mod example;
@@ -25,6 +27,8 @@ mod schema_builder;
 
 pub mod json;
 
+pub use bounded_string::{BoundedString, BoundedStringError, NonEmptyString};
+pub use bounded_vec::{BoundedVec, BoundedVecError};
 pub use error::Error;
 pub use example::SchemaExamples;
 pub use impossible::Impossible;
@@ -76,6 +76,33 @@ where
             .describe_field_optional(key, modifier, default, description, deprecated)
     }
 
+    fn describe_field_examples<E: Serialize>(
+        &mut self,
+        key: &'static str,
+        examples: impl IntoIterator<Item = E>,
+    ) -> Result<(), Self::Error> {
+        self.schema_builder.describe_field_examples(key, examples)
+    }
+
+    fn deny_unknown_fields(&mut self) -> Result<(), Self::Error> {
+        self.schema_builder.deny_unknown_fields()
+    }
+
+    fn describe_translations(
+        &mut self,
+        translations: &[(&'static str, &'static str)],
+    ) -> Result<(), Self::Error> {
+        self.schema_builder.describe_translations(translations)
+    }
+
+    fn describe_example_names(&mut self, names: &[&'static str]) -> Result<(), Self::Error> {
+        self.schema_builder.describe_example_names(names)
+    }
+
+    fn describe_modifier(&mut self, modifier: FieldMod) -> Result<(), Self::Error> {
+        self.schema_builder.describe_modifier(modifier)
+    }
+
     fn end(self) -> Result<Self::Ok, Self::Error> {
         (self.transform).transform(self.schema_builder.end()?)
     }
@@ -235,6 +262,33 @@ where
             .describe_additional_elements(describe_key, description, deprecated)
     }
 
+    fn describe_element_examples<K: Schema + Serialize, E: Serialize>(
+        &mut self,
+        key: K,
+        examples: impl IntoIterator<Item = E>,
+    ) -> Result<(), Self::Error> {
+        self.schema_builder.describe_element_examples(key, examples)
+    }
+
+    fn deny_additional_elements(&mut self) -> Result<(), Self::Error> {
+        self.schema_builder.deny_additional_elements()
+    }
+
+    fn describe_translations(
+        &mut self,
+        translations: &[(&'static str, &'static str)],
+    ) -> Result<(), Self::Error> {
+        self.schema_builder.describe_translations(translations)
+    }
+
+    fn describe_example_names(&mut self, names: &[&'static str]) -> Result<(), Self::Error> {
+        self.schema_builder.describe_example_names(names)
+    }
+
+    fn describe_modifier(&mut self, modifier: FieldMod) -> Result<(), Self::Error> {
+        self.schema_builder.describe_modifier(modifier)
+    }
+
     fn end(self) -> Result<Self::Ok, Self::Error> {
         (self.transform).transform(self.schema_builder.end()?)
     }
@@ -314,6 +368,21 @@ where
             .describe_struct_variant(index, id, len, description, deprecated)
     }
 
+    fn describe_translations(
+        &mut self,
+        translations: &[(&'static str, &'static str)],
+    ) -> Result<(), Self::Error> {
+        self.schema_builder.describe_translations(translations)
+    }
+
+    fn describe_example_names(&mut self, names: &[&'static str]) -> Result<(), Self::Error> {
+        self.schema_builder.describe_example_names(names)
+    }
+
+    fn describe_modifier(&mut self, modifier: FieldMod) -> Result<(), Self::Error> {
+        self.schema_builder.describe_modifier(modifier)
+    }
+
     fn end(self) -> Result<Self::Ok, Self::Error> {
         (self.transform).transform(self.schema_builder.end()?)
     }
@@ -733,6 +802,19 @@ where
         )?)
     }
 
+    fn describe_any<I: IntoIterator<IntoIter = E>>(
+        self,
+        description: Option<&'static str>,
+        examples: impl Fn() -> Result<I, Self::Error>,
+        deprecated: bool,
+    ) -> Result<Self::Ok, Self::Error> {
+        (self.transform).transform(self.schema_builder.describe_any(
+            description,
+            examples,
+            deprecated,
+        )?)
+    }
+
     fn describe_unit_struct<I: IntoIterator<IntoIter = E>>(
         self,
         id: Option<SchemaId>,
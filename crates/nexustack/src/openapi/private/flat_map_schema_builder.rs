@@ -95,6 +95,14 @@ impl<B: MapSchemaBuilder> StructSchemaBuilder for StructFlatMapSchemaBuilder<'_,
         )
     }
 
+    fn describe_field_examples<E: Serialize>(
+        &mut self,
+        key: &'static str,
+        examples: impl IntoIterator<Item = E>,
+    ) -> Result<(), Self::Error> {
+        MapSchemaBuilder::describe_element_examples(self.0, key, examples)
+    }
+
     fn end(self) -> Result<Self::Ok, Self::Error> {
         Ok(())
     }
@@ -165,6 +173,14 @@ impl<B: MapSchemaBuilder> MapSchemaBuilder for MapFlatMapSchemaBuilder<'_, B> {
         )
     }
 
+    fn describe_element_examples<K: Schema + Serialize, E: Serialize>(
+        &mut self,
+        key: K,
+        examples: impl IntoIterator<Item = E>,
+    ) -> Result<(), Self::Error> {
+        MapSchemaBuilder::describe_element_examples(self.0, key, examples)
+    }
+
     fn end(self) -> Result<Self::Ok, Self::Error> {
         Ok(())
     }
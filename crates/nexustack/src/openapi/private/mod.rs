@@ -6,5 +6,7 @@
  */
 
 mod flat_map_schema_builder;
+mod schema_with;
 
 pub use flat_map_schema_builder::*;
+pub use schema_with::*;
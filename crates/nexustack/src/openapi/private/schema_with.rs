@@ -0,0 +1,40 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use crate::openapi::{error, example::serialize_example};
+use serde::Serialize;
+
+pub use crate::openapi::example::ExampleExtractor;
+
+/// The example type of a field described by `#[api_property(schema_with = "...")]`.
+///
+/// The examples of the describe function cannot be named by the generated code, so they are captured in their
+/// serialized form.
+pub type SchemaWithExample = serde_json::Value;
+
+/// The examples type of a field described by `#[api_property(schema_with = "...")]`.
+pub type SchemaWithExamples = std::vec::IntoIter<SchemaWithExample>;
+
+/// Collects the examples of a field described by `#[api_property(schema_with = "...")]`.
+///
+/// # Errors
+///
+/// Returns an error if the describe function fails or an example cannot be serialized.
+pub fn schema_with_examples<D, E, Error>(
+    describe: D,
+    is_human_readable: bool,
+) -> Result<SchemaWithExamples, Error>
+where
+    D: FnOnce(ExampleExtractor<Error>) -> Result<E, Error>,
+    E: Iterator<Item: Serialize + 'static>,
+    Error: error::Error,
+{
+    describe(ExampleExtractor::new(is_human_readable))?
+        .map(serialize_example)
+        .collect::<Result<Vec<_>, _>>()
+        .map(Vec::into_iter)
+}
@@ -202,6 +202,96 @@ pub trait StructSchemaBuilder {
         Ok(())
     }
 
+    /// Attach example values to a previously described field.
+    ///
+    /// The examples are recorded on the field's property schema. If the field refers to a
+    /// shared schema, the reference is kept and the examples are added alongside it.
+    ///
+    /// # Arguments
+    /// * `key` - The name of the field the examples belong to.
+    /// * `examples` - The example values of the field.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if schema construction fails, for example due to:
+    /// - Serialization errors when generating example values.
+    /// - Builder-specific errors encountered during schema description.
+    fn describe_field_examples<E: Serialize>(
+        &mut self,
+        key: &'static str,
+        examples: impl IntoIterator<Item = E>,
+    ) -> Result<(), Self::Error> {
+        let _ = (key, examples);
+        Ok(())
+    }
+
+    /// Attach translations of the struct schema description, keyed by language tag.
+    ///
+    /// The description passed when describing the schema remains the description in the default
+    /// language.
+    ///
+    /// # Arguments
+    /// * `translations` - Pairs of a language tag (e.g. `de`) and the translated description.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if schema construction fails, for example due to:
+    /// - Builder-specific errors encountered during schema description.
+    fn describe_translations(
+        &mut self,
+        translations: &[(&'static str, &'static str)],
+    ) -> Result<(), Self::Error> {
+        let _ = translations;
+        Ok(())
+    }
+
+    /// Attach names to the examples of the struct schema.
+    ///
+    /// The names are matched with the examples by position and allow builders to emit the
+    /// examples as a keyed map instead of a list.
+    ///
+    /// # Arguments
+    /// * `names` - The names of the examples, in the order of the examples.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if schema construction fails, for example due to:
+    /// - A number of names that does not match the number of examples.
+    /// - Builder-specific errors encountered during schema description.
+    fn describe_example_names(&mut self, names: &[&'static str]) -> Result<(), Self::Error> {
+        let _ = names;
+        Ok(())
+    }
+
+    /// Mark the whole struct schema as read-only or write-only.
+    ///
+    /// This complements the field modifiers for types that only ever appear in responses or
+    /// requests. [`FieldMod::ReadWrite`] leaves the schema unchanged.
+    ///
+    /// # Arguments
+    /// * `modifier` - The access modifier of the schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if schema construction fails, for example due to:
+    /// - Builder-specific errors encountered during schema description.
+    fn describe_modifier(&mut self, modifier: FieldMod) -> Result<(), Self::Error> {
+        let _ = modifier;
+        Ok(())
+    }
+
+    /// Mark the struct schema as closed, so that fields other than the described ones are rejected.
+    ///
+    /// This mirrors serde's `#[serde(deny_unknown_fields)]` container attribute.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if schema construction fails, for example due to:
+    /// - Builder-specific errors encountered during schema description.
+    fn deny_unknown_fields(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
     /// Finalize the struct schema and return the result.
     ///
     /// For a usage example see the [`SchemaBuilder::describe_struct`] function.
@@ -756,6 +846,98 @@ pub trait MapSchemaBuilder {
         Ok(())
     }
 
+    /// Attach example values to a previously described element.
+    ///
+    /// The examples are recorded on the element's property schema. If the element refers to a
+    /// shared schema, the reference is kept and the examples are added alongside it.
+    ///
+    /// # Arguments
+    /// * `key` - The key of the element the examples belong to.
+    /// * `examples` - The example values of the element.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if schema construction fails, for example due to:
+    /// - Serialization errors when generating example values.
+    /// - Builder-specific errors encountered during schema description.
+    fn describe_element_examples<K: Schema + Serialize, E: Serialize>(
+        &mut self,
+        key: K,
+        examples: impl IntoIterator<Item = E>,
+    ) -> Result<(), Self::Error> {
+        let _ = (key, examples);
+        Ok(())
+    }
+
+    /// Attach translations of the map schema description, keyed by language tag.
+    ///
+    /// The description passed when describing the schema remains the description in the default
+    /// language.
+    ///
+    /// # Arguments
+    /// * `translations` - Pairs of a language tag (e.g. `de`) and the translated description.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if schema construction fails, for example due to:
+    /// - Builder-specific errors encountered during schema description.
+    fn describe_translations(
+        &mut self,
+        translations: &[(&'static str, &'static str)],
+    ) -> Result<(), Self::Error> {
+        let _ = translations;
+        Ok(())
+    }
+
+    /// Attach names to the examples of the map schema.
+    ///
+    /// The names are matched with the examples by position and allow builders to emit the
+    /// examples as a keyed map instead of a list.
+    ///
+    /// # Arguments
+    /// * `names` - The names of the examples, in the order of the examples.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if schema construction fails, for example due to:
+    /// - A number of names that does not match the number of examples.
+    /// - Builder-specific errors encountered during schema description.
+    fn describe_example_names(&mut self, names: &[&'static str]) -> Result<(), Self::Error> {
+        let _ = names;
+        Ok(())
+    }
+
+    /// Mark the whole map schema as read-only or write-only.
+    ///
+    /// This complements the field modifiers for types that only ever appear in responses or
+    /// requests. [`FieldMod::ReadWrite`] leaves the schema unchanged.
+    ///
+    /// # Arguments
+    /// * `modifier` - The access modifier of the schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if schema construction fails, for example due to:
+    /// - Builder-specific errors encountered during schema description.
+    fn describe_modifier(&mut self, modifier: FieldMod) -> Result<(), Self::Error> {
+        let _ = modifier;
+        Ok(())
+    }
+
+    /// Mark the map schema as closed, so that elements other than the described ones are rejected.
+    ///
+    /// Elements described via [`MapSchemaBuilder::describe_additional_elements`] remain allowed.
+    /// This mirrors serde's `#[serde(deny_unknown_fields)]` container attribute for structs with
+    /// flattened fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if schema construction fails, for example due to:
+    /// - Builder-specific errors encountered during schema description.
+    fn deny_additional_elements(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
     /// Finalize the map schema and return the result.
     ///
     /// For a usage example see the [`SchemaBuilder::describe_map`] function.
@@ -966,6 +1148,29 @@ pub trait StructVariantSchemaBuilder {
         Ok(())
     }
 
+    /// Attach example values to a previously described field.
+    ///
+    /// The examples are recorded on the field's property schema. If the field refers to a
+    /// shared schema, the reference is kept and the examples are added alongside it.
+    ///
+    /// # Arguments
+    /// * `key` - The name of the field the examples belong to.
+    /// * `examples` - The example values of the field.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if schema construction fails, for example due to:
+    /// - Serialization errors when generating example values.
+    /// - Builder-specific errors encountered during schema description.
+    fn describe_field_examples<E: Serialize>(
+        &mut self,
+        key: &'static str,
+        examples: impl IntoIterator<Item = E>,
+    ) -> Result<(), Self::Error> {
+        let _ = (key, examples);
+        Ok(())
+    }
+
     /// Finalize the struct variant schema and return the result.
     ///
     /// For a usage example see the [`SchemaBuilder::describe_enum`] function.
@@ -1222,6 +1427,61 @@ pub trait EnumSchemaBuilder {
         deprecated: bool,
     ) -> Result<Self::StructVariantSchemaBuilder<'a>, Self::Error>;
 
+    /// Attach translations of the enum schema description, keyed by language tag.
+    ///
+    /// The description passed when describing the schema remains the description in the default
+    /// language.
+    ///
+    /// # Arguments
+    /// * `translations` - Pairs of a language tag (e.g. `de`) and the translated description.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if schema construction fails, for example due to:
+    /// - Builder-specific errors encountered during schema description.
+    fn describe_translations(
+        &mut self,
+        translations: &[(&'static str, &'static str)],
+    ) -> Result<(), Self::Error> {
+        let _ = translations;
+        Ok(())
+    }
+
+    /// Attach names to the examples of the enum schema.
+    ///
+    /// The names are matched with the examples by position and allow builders to emit the
+    /// examples as a keyed map instead of a list.
+    ///
+    /// # Arguments
+    /// * `names` - The names of the examples, in the order of the examples.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if schema construction fails, for example due to:
+    /// - A number of names that does not match the number of examples.
+    /// - Builder-specific errors encountered during schema description.
+    fn describe_example_names(&mut self, names: &[&'static str]) -> Result<(), Self::Error> {
+        let _ = names;
+        Ok(())
+    }
+
+    /// Mark the whole enum schema as read-only or write-only.
+    ///
+    /// This complements the field modifiers for types that only ever appear in responses or
+    /// requests. [`FieldMod::ReadWrite`] leaves the schema unchanged.
+    ///
+    /// # Arguments
+    /// * `modifier` - The access modifier of the schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if schema construction fails, for example due to:
+    /// - Builder-specific errors encountered during schema description.
+    fn describe_modifier(&mut self, modifier: FieldMod) -> Result<(), Self::Error> {
+        let _ = modifier;
+        Ok(())
+    }
+
     /// Finalize the enum schema and return the result.
     ///
     /// For a usage example see the [`SchemaBuilder::describe_enum`] function.
@@ -3073,6 +3333,59 @@ pub trait SchemaBuilder<E: Iterator<Item: Serialize + 'static>>: Sized {
         self.describe_combinator(Combinator::OneOf, len, description, examples, deprecated)
     }
 
+    /// Describe a schema that accepts any value.
+    ///
+    /// This method is used for values whose shape is not known at compile time, like [`serde_json::Value`].
+    ///
+    /// The default implementation describes an "allOf" combinator without any subschemas, which is satisfied by
+    /// every value. Implementors may override this to emit an empty schema instead.
+    ///
+    /// # Arguments
+    /// * `description` - Optional description for the schema.
+    /// * `examples` - Function providing example values.
+    /// * `deprecated` - Whether the schema is deprecated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if schema construction fails, for example due to:
+    /// - Serialization errors when generating example values.
+    /// - Builder-specific errors encountered during schema description.
+    ///
+    /// # Example
+    /// ```rust
+    /// use nexustack::openapi::SchemaBuilder;
+    /// use nexustack::openapi::Schema;
+    ///
+    /// struct MyType;
+    ///
+    /// impl Schema for MyType {
+    ///     type Example = serde_json::Value;
+    ///     type Examples = <[Self::Example; 2] as IntoIterator>::IntoIter;
+    ///
+    ///     #[inline]
+    ///     fn describe<B>(schema_builder: B) -> Result<B::Ok, B::Error>
+    ///     where
+    ///         B: SchemaBuilder<Self::Examples>,
+    ///     {
+    ///         schema_builder.describe_any(
+    ///             None,
+    ///             || Ok([serde_json::json!(1), serde_json::json!("one")]),
+    ///             false
+    ///         )
+    ///     }
+    /// }
+    ///
+    /// ```
+    fn describe_any<I: IntoIterator<IntoIter = E>>(
+        self,
+        description: Option<&'static str>,
+        examples: impl Fn() -> Result<I, Self::Error>,
+        deprecated: bool,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.describe_all_of(0, description, examples, deprecated)?
+            .end()
+    }
+
     /// Determine whether schemas and examples should be produced in human-readable form.
     ///
     /// Some types have a human-readable form that may be somewhat expensive to construct,
@@ -12,6 +12,7 @@ mod core {
 pub use self::core::borrow::Cow;
 pub use self::core::default::Default;
 pub use self::core::iter::Chain;
+pub use self::core::iter::IntoIterator;
 pub use self::core::iter::Iterator;
 pub use self::core::iter::Map;
 pub use self::core::iter::Once;
@@ -20,14 +21,116 @@ pub use self::core::iter::once;
 pub use self::core::marker::PhantomData;
 pub use self::core::option::Option::{self, None, Some};
 pub use self::core::result::Result::{self, Err, Ok};
+pub use self::core::time::Duration;
+pub use self::core::vec::Vec;
 
 #[path = ""]
 pub mod utils {
     pub use crate::utils::AtomicOnceCell;
 }
 
+/// Expands to the specified tokens. Used for statements gated on an enabled nexustack feature.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __feature_enabled {
+    ($($tokens:tt)*) => {
+        $($tokens)*
+    };
+}
+
+/// Discards the specified tokens. Used for statements gated on a disabled nexustack feature.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __feature_disabled {
+    ($($tokens:tt)*) => {};
+}
+
+/// The gates of the statements marked with `#[optional(Feature)]` in a `#[module(optional_features = "...")]`.
+#[path = ""]
+pub mod features {
+    #[cfg(not(feature = "cli"))]
+    pub use crate::__feature_disabled as Cli;
+    #[cfg(feature = "cli")]
+    pub use crate::__feature_enabled as Cli;
+
+    #[cfg(not(feature = "cron"))]
+    pub use crate::__feature_disabled as Cron;
+    #[cfg(feature = "cron")]
+    pub use crate::__feature_enabled as Cron;
+
+    #[cfg(not(feature = "openapi"))]
+    pub use crate::__feature_disabled as Openapi;
+    #[cfg(feature = "openapi")]
+    pub use crate::__feature_enabled as Openapi;
+}
+
 #[cfg(feature = "cron")]
 #[path = ""]
 pub mod cron {
     pub use cron::Schedule;
+
+    use crate::inject::{ServiceProvider, ServiceScope};
+
+    /// Wraps the scoped service provider a cron job runs with into its [`ServiceScope`].
+    #[must_use]
+    pub const fn service_scope(service_provider: ServiceProvider) -> ServiceScope {
+        ServiceScope::new(service_provider)
+    }
+}
+
+#[path = ""]
+pub mod inject {
+    use crate::inject::Injectable;
+    use std::marker::PhantomData;
+
+    /// Checks at compile time that a dependency of an injectable service can be resolved from an injector,
+    /// i.e. that it is injectable itself, a cloneable value or a boxed trait object.
+    ///
+    /// The check is dispatched via autoref-based specialization. Invoke `assert_resolvable` on
+    /// `&&&DependencyCheck<T>` with all check traits in scope. If none of the check traits applies, the call falls
+    /// back to the inherent method, which reports the dependency via [`ResolvableDependency`].
+    pub struct DependencyCheck<T: ?Sized>(pub PhantomData<T>);
+
+    impl<T: ?Sized> Clone for DependencyCheck<T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<T: ?Sized> Copy for DependencyCheck<T> {}
+
+    impl<T: ?Sized> DependencyCheck<T> {
+        pub const fn assert_resolvable(self)
+        where
+            T: ResolvableDependency,
+        {
+        }
+    }
+
+    /// Marker trait that is intentionally not implemented for any type. It is only required by the fallback of
+    /// [`DependencyCheck`] to report a dependency that cannot be resolved.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` cannot be resolved as a dependency of an injectable service",
+        label = "not injectable, cloneable or a boxed trait object",
+        note = "annotate `{Self}` with `#[injectable]`, implement `Clone` for it or remove `check_dependencies` if it is registered via a factory"
+    )]
+    pub trait ResolvableDependency {}
+
+    pub trait InjectableDependency {
+        fn assert_resolvable(&self) {}
+    }
+
+    impl<T: Injectable> InjectableDependency for DependencyCheck<T> {}
+
+    pub trait CloneDependency {
+        fn assert_resolvable(&self) {}
+    }
+
+    impl<T: Clone> CloneDependency for &DependencyCheck<T> {}
+
+    pub trait TraitObjectDependency {
+        fn assert_resolvable(&self) {}
+    }
+
+    impl<T: ?Sized> TraitObjectDependency for &&DependencyCheck<Box<T>> {}
 }
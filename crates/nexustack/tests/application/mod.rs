@@ -5,9 +5,6 @@
  * Licensed under the MIT license. See LICENSE file in the project root for details.
  */
 
-// TODO: Allow the closure to error?
-
-/// A trait for types that can be configured using a closure.
-pub(crate) trait Configurable<'a> {
-    fn has_item<I: 'static>() -> bool;
-}
+mod optional_part;
+mod shutdown_hooks;
+mod startup_order;
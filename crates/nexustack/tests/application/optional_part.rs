@@ -0,0 +1,115 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::{
+    Application as _, ApplicationBuilder as _, ApplicationPart, ApplicationPartBuilder, Here,
+    InHead, application_builder,
+    inject::{ConstructionResult, ServiceProvider},
+};
+use std::{
+    convert::Infallible,
+    sync::{Arc, Mutex},
+};
+use tokio_util::sync::CancellationToken;
+
+type HookLog = Arc<Mutex<Vec<&'static str>>>;
+
+struct HttpPart {
+    log: HookLog,
+}
+
+impl ApplicationPart for HttpPart {
+    type Error = Infallible;
+
+    async fn before_startup(
+        &mut self,
+        _cancellation_token: CancellationToken,
+    ) -> Result<(), Self::Error> {
+        self.log.lock().unwrap().push("before_startup");
+        Ok(())
+    }
+
+    async fn run(&mut self, _cancellation_token: CancellationToken) -> Result<(), Self::Error> {
+        self.log.lock().unwrap().push("run");
+        Ok(())
+    }
+
+    async fn before_shutdown(
+        &mut self,
+        _cancellation_token: CancellationToken,
+    ) -> Result<(), Self::Error> {
+        self.log.lock().unwrap().push("before_shutdown");
+        Ok(())
+    }
+}
+
+struct HttpPartBuilder {
+    log: HookLog,
+}
+
+impl ApplicationPartBuilder for HttpPartBuilder {
+    type ApplicationPart = HttpPart;
+
+    fn build(self, _service_provider: ServiceProvider) -> ConstructionResult<HttpPart> {
+        self.log.lock().unwrap().push("build");
+        Ok(HttpPart { log: self.log })
+    }
+}
+
+async fn run_with_http_part(enabled: bool) -> Vec<&'static str> {
+    let log = HookLog::default();
+
+    let app = application_builder()
+        .add_application_part_if(enabled, || HttpPartBuilder { log: log.clone() })
+        .build()
+        .unwrap();
+
+    app.run_with_cancellation_token(CancellationToken::new())
+        .await
+        .unwrap();
+
+    log.lock().unwrap().clone()
+}
+
+#[tokio::test]
+async fn test_disabled_part_hooks_never_run() {
+    pretty_assertions::assert_eq!(run_with_http_part(false).await, Vec::<&str>::new());
+}
+
+#[tokio::test]
+async fn test_enabled_part_hooks_run() {
+    pretty_assertions::assert_eq!(
+        run_with_http_part(true).await,
+        vec!["build", "before_startup", "run", "before_shutdown"]
+    );
+}
+
+#[tokio::test]
+async fn test_optional_part_is_configured_as_option() {
+    let log = HookLog::default();
+
+    let app = application_builder()
+        .add_application_part_if(true, || HttpPartBuilder {
+            log: HookLog::default(),
+        })
+        .configure_application_part::<Option<HttpPartBuilder>, _, InHead<Here>>(|builder| {
+            if let Some(builder) = builder {
+                builder.log = log.clone();
+            }
+        })
+        .build()
+        .unwrap();
+
+    app.run_with_cancellation_token(CancellationToken::new())
+        .await
+        .unwrap();
+
+    pretty_assertions::assert_eq!(
+        *log.lock().unwrap(),
+        vec!["build", "before_startup", "run", "before_shutdown"]
+    );
+}
@@ -0,0 +1,95 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::{
+    Application, ApplicationBuilder as _, application_builder,
+    inject::{ServiceProvider, ServiceScope, injectable},
+};
+use std::sync::{Arc, Mutex};
+use tokio_util::sync::CancellationToken;
+
+#[derive(Clone, Default)]
+struct Log(Arc<Mutex<Vec<&'static str>>>);
+
+#[derive(Clone)]
+struct Pool;
+
+#[injectable]
+impl Pool {
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(service_provider: ServiceProvider, log: Log) -> Self {
+        service_provider.on_shutdown(|| async move { log.0.lock().unwrap().push("pool") });
+        Self
+    }
+}
+
+#[derive(Clone)]
+struct Session;
+
+#[injectable]
+impl Session {
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(service_provider: ServiceProvider, log: Log) -> Self {
+        service_provider.on_shutdown(|| async move { log.0.lock().unwrap().push("session") });
+        Self
+    }
+}
+
+#[tokio::test]
+async fn test_shutdown_hooks_run_in_reverse_order() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    let app = application_builder().build().unwrap();
+
+    app.service_provider().on_shutdown({
+        let log = log.clone();
+        || async move { log.lock().unwrap().push("first") }
+    });
+
+    // Hooks registered via a scope are application-wide.
+    let service_scope = app.service_provider().resolve::<ServiceScope>().unwrap();
+    service_scope.service_provider().on_shutdown({
+        let log = log.clone();
+        || async move { log.lock().unwrap().push("second") }
+    });
+    drop(service_scope);
+
+    pretty_assertions::assert_eq!(log.lock().unwrap().len(), 0);
+
+    app.run_with_cancellation_token(CancellationToken::new())
+        .await
+        .unwrap();
+
+    pretty_assertions::assert_eq!(*log.lock().unwrap(), ["second", "first"]);
+}
+
+#[tokio::test]
+async fn test_shutdown_hooks_registered_during_construction_run() {
+    let log = Log::default();
+
+    let app = application_builder()
+        .configure_services({
+            let log = log.clone();
+            |services| {
+                services
+                    .add_value(log)
+                    .add_singleton::<Pool>()
+                    .add_scoped::<Session>();
+            }
+        })
+        .build()
+        .unwrap();
+
+    // Scoped services are constructed when the scope is created.
+    drop(app.service_provider().resolve::<ServiceScope>().unwrap());
+
+    app.run_with_cancellation_token(CancellationToken::new())
+        .await
+        .unwrap();
+
+    pretty_assertions::assert_eq!(*log.0.lock().unwrap(), ["session", "pool"]);
+}
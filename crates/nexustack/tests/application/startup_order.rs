@@ -0,0 +1,107 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::{
+    Application as _, ApplicationBuilder as _, ApplicationPart, ApplicationPartBuilder,
+    application_builder,
+    inject::{ConstructionResult, ServiceProvider},
+};
+use std::{
+    convert::Infallible,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio_util::sync::CancellationToken;
+
+type StartupLog = Arc<Mutex<Vec<&'static str>>>;
+
+struct MigrationPart {
+    log: StartupLog,
+}
+
+impl ApplicationPart for MigrationPart {
+    type Error = Infallible;
+
+    fn startup_order() -> i32 {
+        -1
+    }
+
+    async fn before_startup(
+        &mut self,
+        _cancellation_token: CancellationToken,
+    ) -> Result<(), Self::Error> {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        self.log.lock().unwrap().push("migration");
+        Ok(())
+    }
+
+    async fn run(&mut self, _cancellation_token: CancellationToken) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+struct MigrationPartBuilder {
+    log: StartupLog,
+}
+
+impl ApplicationPartBuilder for MigrationPartBuilder {
+    type ApplicationPart = MigrationPart;
+
+    fn build(self, _service_provider: ServiceProvider) -> ConstructionResult<MigrationPart> {
+        Ok(MigrationPart { log: self.log })
+    }
+}
+
+struct HttpPart {
+    log: StartupLog,
+}
+
+impl ApplicationPart for HttpPart {
+    type Error = Infallible;
+
+    async fn before_startup(
+        &mut self,
+        _cancellation_token: CancellationToken,
+    ) -> Result<(), Self::Error> {
+        self.log.lock().unwrap().push("http");
+        Ok(())
+    }
+
+    async fn run(&mut self, _cancellation_token: CancellationToken) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+struct HttpPartBuilder {
+    log: StartupLog,
+}
+
+impl ApplicationPartBuilder for HttpPartBuilder {
+    type ApplicationPart = HttpPart;
+
+    fn build(self, _service_provider: ServiceProvider) -> ConstructionResult<HttpPart> {
+        Ok(HttpPart { log: self.log })
+    }
+}
+
+#[tokio::test]
+async fn test_before_startup_honors_startup_order() {
+    let log = StartupLog::default();
+
+    // The HTTP part is added last and therefore is the head of the chain.
+    let app = application_builder()
+        .add_application_part_with_factory(|| MigrationPartBuilder { log: log.clone() })
+        .add_application_part_with_factory(|| HttpPartBuilder { log: log.clone() })
+        .build()
+        .unwrap();
+
+    app.run_with_cancellation_token(CancellationToken::new())
+        .await
+        .unwrap();
+
+    pretty_assertions::assert_eq!(*log.lock().unwrap(), ["migration", "http"]);
+}
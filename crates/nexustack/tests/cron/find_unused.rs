@@ -0,0 +1,77 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::{
+    ApplicationBuilder as _, application_builder,
+    cron::{Cron, CronApplicationBuilder as _, CronResult, cron, cron_jobs},
+    inject::{ServiceToken, injectable},
+};
+use std::any::type_name;
+
+#[derive(Clone)]
+struct Config;
+
+#[derive(Clone)]
+struct Repository;
+
+#[injectable]
+impl Repository {
+    pub const fn new(_config: Config) -> Self {
+        Self
+    }
+}
+
+#[derive(Clone)]
+#[injectable]
+struct Unused;
+
+#[cron(schedule = "0 0 * * * *")]
+#[allow(clippy::unused_async)]
+async fn cleanup(#[cron::service] _repository: Repository) -> CronResult {
+    Ok(())
+}
+
+#[test]
+fn test_cron_job_reports_nothing_unused() {
+    let application_builder = application_builder()
+        .configure_services(|services| {
+            services.add_value(Config).add_transient::<Repository>();
+        })
+        .add_cron_with_default_clock()
+        .configure_cron(cron_jobs![cleanup]);
+
+    pretty_assertions::assert_eq!(
+        application_builder
+            .find_unused_services()
+            .iter()
+            .map(ServiceToken::type_name)
+            .collect::<Vec<_>>(),
+        Vec::<&str>::new()
+    );
+}
+
+#[test]
+fn test_cron_job_reports_services_not_used_by_any_job() {
+    let application_builder = application_builder()
+        .configure_services(|services| {
+            services
+                .add_value(Config)
+                .add_transient::<Repository>()
+                .add_singleton::<Unused>();
+        })
+        .add_cron_with_default_clock()
+        .configure_cron(cron_jobs![cleanup]);
+
+    pretty_assertions::assert_eq!(
+        application_builder
+            .find_unused_services()
+            .iter()
+            .map(ServiceToken::type_name)
+            .collect::<Vec<_>>(),
+        vec![type_name::<Unused>()]
+    );
+}
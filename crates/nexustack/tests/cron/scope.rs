@@ -0,0 +1,66 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::{
+    cron::{CronError, CronJob as _, CronResult, cron},
+    inject::{ServiceCollection, ServiceScope, injectable},
+};
+use std::sync::{Arc, Mutex};
+
+struct RequestContext;
+
+#[injectable]
+impl RequestContext {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// Records whether the context of the child scope was the context of the job.
+#[derive(Default)]
+struct Observation {
+    same_context: Mutex<Option<bool>>,
+}
+
+#[cron(schedule = "0 0 * * * *")]
+#[allow(clippy::unused_async)]
+async fn process_items(
+    #[cron::service] context: Arc<RequestContext>,
+    #[cron::service] observation: Arc<Observation>,
+    #[cron::scope] scope: ServiceScope,
+) -> CronResult {
+    let child_scope = scope
+        .service_provider()
+        .resolve::<ServiceScope>()
+        .map_err(|err| CronError::RunError(err.into()))?;
+    let child_context = child_scope
+        .service_provider()
+        .resolve_shared::<RequestContext>()
+        .map_err(|err| CronError::RunError(err.into()))?;
+
+    *observation.same_context.lock().unwrap() = Some(Arc::ptr_eq(&context, &child_context));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_child_scope_has_fresh_scoped_services() {
+    let observation = Arc::new(Observation::default());
+
+    let mut services = ServiceCollection::new();
+    services
+        .add_scoped_shared::<RequestContext>()
+        .add_value(observation.clone());
+    let service_provider = services.build();
+    let service_scope = service_provider.resolve::<ServiceScope>().unwrap();
+
+    process_items::run(service_scope.service_provider().clone())
+        .await
+        .unwrap();
+
+    pretty_assertions::assert_eq!(*observation.same_context.lock().unwrap(), Some(false));
+}
@@ -0,0 +1,65 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::inject::{ServiceCollection, injectable};
+
+trait Greeter: Send + Sync {
+    fn greet(&self) -> String;
+}
+
+struct Clock;
+
+#[injectable]
+impl Clock {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Greeter for Clock {
+    fn greet(&self) -> String {
+        "hello".to_string()
+    }
+}
+
+#[derive(Clone)]
+struct Name(String);
+
+struct Service {
+    _clock: Clock,
+    name: Name,
+    greeter: Box<dyn Greeter>,
+}
+
+#[injectable(check_dependencies)]
+impl Service {
+    pub fn new(clock: Clock, name: Name, greeter: Box<dyn Greeter>) -> Self {
+        Self {
+            _clock: clock,
+            name,
+            greeter,
+        }
+    }
+}
+
+#[test]
+fn test_check_dependencies_accepts_resolvable_dependencies() {
+    let mut services = ServiceCollection::new();
+    services
+        .add_transient::<Clock>()
+        .add_transient_factory::<Box<dyn Greeter>>(|_| Ok(Box::new(Clock)))
+        .add_value(Name("world".to_string()))
+        .add_transient::<Service>();
+    let service_provider = services.build();
+
+    let service = service_provider.resolve::<Service>().unwrap();
+
+    pretty_assertions::assert_eq!(
+        format!("{} {}", service.greeter.greet(), service.name.0),
+        "hello world"
+    );
+}
@@ -0,0 +1,104 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::inject::{InjectionError, ServiceCollection, injectable};
+use std::sync::Arc;
+
+struct Settings {
+    _name: String,
+}
+
+struct RequestContext {}
+
+#[injectable]
+impl RequestContext {
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+fn build_services() -> nexustack::inject::ServiceProvider {
+    let mut services = ServiceCollection::new();
+    services.add_value(Arc::new(Settings {
+        _name: "settings".to_owned(),
+    }));
+    services.add_scoped_shared::<RequestContext>();
+    services.build()
+}
+
+#[test]
+fn test_value_is_shared_and_scoped_service_differs() {
+    let service_provider = build_services();
+
+    let service_scope = service_provider.create_scope().unwrap();
+    let other_service_scope = service_provider.create_scope().unwrap();
+
+    let settings = service_provider.resolve::<Arc<Settings>>().unwrap();
+    let scope_settings = service_scope
+        .service_provider()
+        .resolve::<Arc<Settings>>()
+        .unwrap();
+
+    assert!(Arc::ptr_eq(&settings, &scope_settings));
+
+    let context = service_scope
+        .service_provider()
+        .resolve_shared::<RequestContext>()
+        .unwrap();
+    let other_context = other_service_scope
+        .service_provider()
+        .resolve_shared::<RequestContext>()
+        .unwrap();
+
+    assert!(!Arc::ptr_eq(&context, &other_context));
+}
+
+#[test]
+fn test_nested_scope_has_fresh_scoped_services() {
+    let service_provider = build_services();
+
+    let service_scope = service_provider.create_scope().unwrap();
+    let nested_service_scope = service_scope.service_provider().create_scope().unwrap();
+
+    let context = service_scope
+        .service_provider()
+        .resolve_shared::<RequestContext>()
+        .unwrap();
+    let nested_context = nested_service_scope
+        .service_provider()
+        .resolve_shared::<RequestContext>()
+        .unwrap();
+
+    assert!(!Arc::ptr_eq(&context, &nested_context));
+
+    let settings = service_provider.resolve::<Arc<Settings>>().unwrap();
+    let nested_settings = nested_service_scope
+        .service_provider()
+        .resolve::<Arc<Settings>>()
+        .unwrap();
+
+    assert!(Arc::ptr_eq(&settings, &nested_settings));
+}
+
+#[test]
+fn test_scope_outliving_parent() {
+    let service_provider = build_services();
+    let service_scope = service_provider.create_scope().unwrap();
+
+    drop(service_provider);
+
+    assert!(
+        service_scope
+            .service_provider()
+            .resolve_shared::<RequestContext>()
+            .is_ok()
+    );
+    assert!(matches!(
+        service_scope.service_provider().resolve::<Arc<Settings>>(),
+        Err(InjectionError::DroppedServiceProvider { .. })
+    ));
+}
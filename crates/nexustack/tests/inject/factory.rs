@@ -0,0 +1,98 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::inject::{Factory, ServiceCollection, ServiceScope, injectable};
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+#[derive(Clone, Default)]
+struct SequenceNumbers(Arc<AtomicUsize>);
+
+impl SequenceNumbers {
+    fn next(self) -> usize {
+        self.0.fetch_add(1, Ordering::Relaxed) + 1
+    }
+}
+
+struct Command {
+    seq_num: usize,
+}
+
+#[injectable]
+impl Command {
+    pub fn new(sequence_numbers: SequenceNumbers) -> Self {
+        Self {
+            seq_num: sequence_numbers.next(),
+        }
+    }
+}
+
+struct CommandDispatcher {
+    command_factory: Factory<Command>,
+}
+
+#[injectable]
+impl CommandDispatcher {
+    pub const fn new(command_factory: Factory<Command>) -> Self {
+        Self { command_factory }
+    }
+}
+
+#[test]
+fn test_factory_creates_distinct_instances() {
+    let mut services = ServiceCollection::new();
+    services
+        .add_value(SequenceNumbers::default())
+        .add_factory::<Command>()
+        .add_transient::<CommandDispatcher>();
+    let service_provider = services.build();
+
+    let dispatcher = service_provider.resolve::<CommandDispatcher>().unwrap();
+
+    let command_1 = dispatcher.command_factory.create().unwrap();
+    let command_2 = dispatcher.command_factory.create().unwrap();
+
+    pretty_assertions::assert_eq!(command_1.seq_num, 1);
+    pretty_assertions::assert_eq!(command_2.seq_num, 2);
+}
+
+#[derive(Clone, Copy)]
+struct RequestId(usize);
+
+struct Handler {
+    request_id: usize,
+}
+
+#[injectable]
+impl Handler {
+    pub const fn new(request_id: RequestId) -> Self {
+        Self {
+            request_id: request_id.0,
+        }
+    }
+}
+
+#[test]
+fn test_factory_resolves_dependencies_from_scope() {
+    let request_ids = SequenceNumbers::default();
+    let mut services = ServiceCollection::new();
+    services
+        .add_scoped_factory(move |_| Ok(RequestId(request_ids.clone().next())))
+        .add_factory::<Handler>();
+    let service_provider = services.build();
+
+    let service_scope = service_provider.resolve::<ServiceScope>().unwrap();
+    let handler_factory = service_scope
+        .service_provider()
+        .resolve::<Factory<Handler>>()
+        .unwrap();
+
+    pretty_assertions::assert_eq!(handler_factory.create().unwrap().request_id, 1);
+    pretty_assertions::assert_eq!(handler_factory.create().unwrap().request_id, 1);
+}
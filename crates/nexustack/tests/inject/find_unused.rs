@@ -0,0 +1,95 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::inject::{Lazy, ServiceCollection, injectable};
+use std::{any::type_name, sync::Arc};
+
+#[derive(Clone)]
+struct Config;
+
+#[derive(Clone)]
+struct Database;
+
+#[injectable]
+impl Database {
+    pub const fn new(_config: Config) -> Self {
+        Self
+    }
+}
+
+#[derive(Clone)]
+struct Repository {
+    _database: Arc<Lazy<Database>>,
+}
+
+#[injectable]
+impl Repository {
+    pub fn new(database: Lazy<Database>) -> Self {
+        Self {
+            _database: Arc::new(database),
+        }
+    }
+}
+
+#[derive(Clone)]
+#[injectable]
+struct Handler {
+    _repository: Repository,
+}
+
+#[derive(Clone)]
+#[injectable]
+struct Unused;
+
+fn build_services() -> ServiceCollection {
+    let mut services = ServiceCollection::new();
+    services
+        .add_value(Config)
+        .add_singleton::<Database>()
+        .add_lazy::<Database>()
+        .add_scoped::<Repository>()
+        .add_transient::<Handler>()
+        .add_singleton::<Unused>();
+    services
+}
+
+#[test]
+fn test_find_unused_reports_unreachable_registrations() {
+    let mut services = build_services();
+    services.mark_used::<Handler>();
+
+    let unused = services
+        .find_unused()
+        .iter()
+        .map(|service| service.type_name().to_owned())
+        .collect::<Vec<_>>();
+
+    pretty_assertions::assert_eq!(unused, vec![type_name::<Unused>().to_owned()]);
+}
+
+#[test]
+fn test_find_unused_without_used_services_reports_all_registrations() {
+    let services = build_services();
+
+    let unused = services
+        .find_unused()
+        .iter()
+        .map(|service| service.type_name().to_owned())
+        .collect::<Vec<_>>();
+
+    pretty_assertions::assert_eq!(
+        unused,
+        vec![
+            type_name::<Config>().to_owned(),
+            type_name::<Database>().to_owned(),
+            type_name::<Lazy<Database>>().to_owned(),
+            type_name::<Repository>().to_owned(),
+            type_name::<Handler>().to_owned(),
+            type_name::<Unused>().to_owned(),
+        ]
+    );
+}
@@ -0,0 +1,97 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::inject::{ConstructionError, InjectionError, ServiceCollection};
+use std::{any::type_name, error::Error as _};
+
+struct Database;
+struct Repository;
+struct Handler;
+
+fn add_repository_and_handler(services: &mut ServiceCollection) {
+    services
+        .add_transient_factory(|injector| {
+            injector.resolve::<Database>()?;
+            Ok(Repository)
+        })
+        .add_transient_factory(|injector| {
+            injector.resolve::<Repository>()?;
+            Ok(Handler)
+        });
+}
+
+#[test]
+fn test_construction_error_renders_chain_and_links_source() {
+    let mut services = ServiceCollection::new();
+    services.add_transient_factory::<Database>(|_| {
+        Err(ConstructionError::Custom(Box::new(std::io::Error::other(
+            "connection refused",
+        ))))
+    });
+    add_repository_and_handler(&mut services);
+    let service_provider = services.build();
+
+    let Err(err) = service_provider.resolve::<Handler>() else {
+        panic!("expected the resolution of `Handler` to fail");
+    };
+
+    assert!(matches!(err, InjectionError::Custom { .. }));
+    pretty_assertions::assert_eq!(
+        err.to_string(),
+        format!(
+            "service cannot be constructed due to an error while resolving {} required by {} \
+             required by {}",
+            type_name::<Database>(),
+            type_name::<Repository>(),
+            type_name::<Handler>(),
+        )
+    );
+    pretty_assertions::assert_eq!(
+        err.source().map(ToString::to_string).as_deref(),
+        Some("connection refused")
+    );
+}
+
+#[test]
+fn test_missing_service_renders_chain_without_source() {
+    let mut services = ServiceCollection::new();
+    add_repository_and_handler(&mut services);
+    let service_provider = services.build();
+
+    let Err(err) = service_provider.resolve::<Handler>() else {
+        panic!("expected the resolution of `Handler` to fail");
+    };
+
+    assert!(matches!(err, InjectionError::ServiceNotFound { .. }));
+    pretty_assertions::assert_eq!(
+        err.to_string(),
+        format!(
+            "service not found while resolving {} required by {} required by {}",
+            type_name::<Database>(),
+            type_name::<Repository>(),
+            type_name::<Handler>(),
+        )
+    );
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn test_root_service_renders_without_chain() {
+    let service_provider = ServiceCollection::new().build();
+
+    let Err(err) = service_provider.resolve::<Database>() else {
+        panic!("expected the resolution of `Database` to fail");
+    };
+
+    pretty_assertions::assert_eq!(
+        err.to_string(),
+        format!(
+            "service not found while resolving {}",
+            type_name::<Database>()
+        )
+    );
+}
@@ -0,0 +1,83 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::inject::{Lazy, ServiceCollection, ServiceScope, injectable};
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+#[derive(Clone, Default)]
+struct ConstructionCounter(Arc<AtomicUsize>);
+
+impl ConstructionCounter {
+    fn next_id(self) -> usize {
+        self.0.fetch_add(1, Ordering::Relaxed) + 1
+    }
+}
+
+struct ExpensiveService {
+    id: usize,
+}
+
+#[injectable]
+impl ExpensiveService {
+    pub fn new(counter: ConstructionCounter) -> Self {
+        Self {
+            id: counter.next_id(),
+        }
+    }
+}
+
+struct Consumer {
+    expensive_service: Lazy<ExpensiveService>,
+}
+
+#[injectable]
+impl Consumer {
+    pub const fn new(expensive_service: Lazy<ExpensiveService>) -> Self {
+        Self { expensive_service }
+    }
+}
+
+fn build_services(counter: &ConstructionCounter) -> ServiceCollection {
+    let mut services = ServiceCollection::new();
+    services
+        .add_value(counter.clone())
+        .add_transient::<ExpensiveService>()
+        .add_lazy::<ExpensiveService>()
+        .add_transient::<Consumer>();
+    services
+}
+
+#[test]
+fn test_lazy_service_is_constructed_on_first_get() {
+    let counter = ConstructionCounter::default();
+    let service_provider = build_services(&counter).build();
+
+    let consumer = service_provider.resolve::<Consumer>().unwrap();
+
+    pretty_assertions::assert_eq!(counter.0.load(Ordering::Relaxed), 0);
+
+    pretty_assertions::assert_eq!(consumer.expensive_service.get().unwrap().id, 1);
+    pretty_assertions::assert_eq!(consumer.expensive_service.get().unwrap().id, 1);
+    pretty_assertions::assert_eq!(counter.0.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn test_lazy_service_is_resolved_within_scope() {
+    let counter = ConstructionCounter::default();
+    let service_provider = build_services(&counter).build();
+
+    let service_scope = service_provider.resolve::<ServiceScope>().unwrap();
+    let consumer = service_scope
+        .service_provider()
+        .resolve::<Consumer>()
+        .unwrap();
+
+    pretty_assertions::assert_eq!(consumer.expensive_service.get().unwrap().id, 1);
+}
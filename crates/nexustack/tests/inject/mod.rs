@@ -0,0 +1,18 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+mod check_dependencies;
+mod create_scope;
+mod factory;
+mod find_unused;
+mod injection_error;
+mod lazy;
+mod optional_dependency;
+mod resolve_or_default;
+mod scoped_concurrency;
+mod shared;
+mod trait_object;
@@ -0,0 +1,88 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::inject::{InjectionError, ServiceCollection, injectable};
+
+#[derive(Clone)]
+struct Metrics {
+    prefix: &'static str,
+}
+
+#[derive(Clone)]
+struct Database;
+
+struct Repository {
+    metrics: Option<Metrics>,
+}
+
+#[injectable]
+impl Repository {
+    pub const fn new(#[inject(optional)] metrics: Option<Metrics>) -> Self {
+        Self { metrics }
+    }
+}
+
+struct Handler;
+
+#[injectable]
+impl Handler {
+    pub const fn new(#[inject(optional)] _repository: Option<Repository>) -> Self {
+        Self
+    }
+}
+
+#[test]
+fn test_optional_dependency_registered() {
+    let mut services = ServiceCollection::new();
+    services
+        .add_value(Metrics { prefix: "app" })
+        .add_transient::<Repository>();
+    let service_provider = services.build();
+
+    let repository = service_provider.resolve::<Repository>().unwrap();
+
+    pretty_assertions::assert_eq!(
+        repository.metrics.map(|metrics| metrics.prefix),
+        Some("app")
+    );
+}
+
+#[test]
+fn test_optional_dependency_not_registered() {
+    let mut services = ServiceCollection::new();
+    services.add_transient::<Repository>();
+    let service_provider = services.build();
+
+    let repository = service_provider.resolve::<Repository>().unwrap();
+
+    assert!(repository.metrics.is_none());
+}
+
+#[test]
+fn test_optional_dependency_with_missing_dependency_fails() {
+    #[derive(Clone)]
+    struct Connection;
+
+    let mut services = ServiceCollection::new();
+    services
+        .add_transient_factory(|injector| {
+            injector.resolve::<Database>()?;
+            Ok(Connection)
+        })
+        .add_transient_factory(|injector| {
+            injector.resolve::<Connection>()?;
+            Ok(Metrics { prefix: "app" })
+        })
+        .add_transient::<Repository>()
+        .add_transient::<Handler>();
+    let service_provider = services.build();
+
+    assert!(matches!(
+        service_provider.resolve::<Handler>(),
+        Err(InjectionError::ServiceNotFound { .. })
+    ));
+}
@@ -0,0 +1,65 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::inject::{InjectionError, ServiceCollection};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct RetryOptions {
+    attempts: u32,
+}
+
+impl Default for RetryOptions {
+    fn default() -> Self {
+        Self { attempts: 3 }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct Client;
+
+#[test]
+fn test_unregistered_service_resolves_to_default() {
+    let service_provider = ServiceCollection::new().build();
+
+    pretty_assertions::assert_eq!(
+        service_provider
+            .resolve_or_default::<RetryOptions>()
+            .unwrap(),
+        RetryOptions { attempts: 3 }
+    );
+}
+
+#[test]
+fn test_registered_service_is_resolved() {
+    let mut services = ServiceCollection::new();
+    services.add_value(RetryOptions { attempts: 7 });
+    let service_provider = services.build();
+
+    pretty_assertions::assert_eq!(
+        service_provider
+            .resolve_or_default::<RetryOptions>()
+            .unwrap(),
+        RetryOptions { attempts: 7 }
+    );
+}
+
+#[test]
+fn test_missing_dependency_is_not_defaulted() {
+    let mut services = ServiceCollection::new();
+    services.add_transient_factory(|injector| {
+        injector.resolve::<RetryOptions>()?;
+        Ok(Client)
+    });
+    let service_provider = services.build();
+
+    let result = service_provider.resolve_or_default::<Client>();
+
+    assert!(matches!(
+        result,
+        Err(InjectionError::ServiceNotFound { .. })
+    ));
+}
@@ -0,0 +1,65 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::inject::{ServiceCollection, ServiceScope, injectable};
+use std::sync::{
+    Arc, Barrier,
+    atomic::{AtomicUsize, Ordering},
+};
+
+const THREADS: usize = 64;
+
+static CONSTRUCTIONS: AtomicUsize = AtomicUsize::new(0);
+
+struct Session {
+    _id: usize,
+}
+
+#[injectable]
+impl Session {
+    pub fn new() -> Self {
+        Self {
+            _id: CONSTRUCTIONS.fetch_add(1, Ordering::SeqCst),
+        }
+    }
+}
+
+#[test]
+fn test_concurrent_scoped_resolution_constructs_once() {
+    let mut services = ServiceCollection::new();
+    services.add_scoped_shared::<Session>();
+    let service_provider = services.build();
+
+    let service_scope = service_provider.resolve::<ServiceScope>().unwrap();
+    let barrier = Barrier::new(THREADS);
+
+    let sessions = std::thread::scope(|scope| {
+        let handles = (0..THREADS)
+            .map(|_| {
+                scope.spawn(|| {
+                    barrier.wait();
+                    service_scope
+                        .service_provider()
+                        .resolve_shared::<Session>()
+                        .unwrap()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+
+    assert!(
+        sessions
+            .iter()
+            .all(|session| Arc::ptr_eq(session, &sessions[0]))
+    );
+    pretty_assertions::assert_eq!(1, CONSTRUCTIONS.load(Ordering::SeqCst));
+}
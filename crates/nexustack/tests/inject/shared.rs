@@ -0,0 +1,66 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::inject::{ServiceCollection, ServiceScope, injectable};
+use std::sync::Arc;
+
+// Intentionally not `Clone`.
+struct Cache {
+    _entries: Vec<u64>,
+}
+
+#[injectable]
+impl Cache {
+    pub fn new() -> Self {
+        Self {
+            _entries: vec![0; 4096],
+        }
+    }
+}
+
+#[test]
+fn test_shared_scoped_service_is_same_allocation_within_scope() {
+    let mut services = ServiceCollection::new();
+    services.add_scoped_shared::<Cache>();
+    let service_provider = services.build();
+
+    let service_scope = service_provider.resolve::<ServiceScope>().unwrap();
+    let cache_1 = service_scope
+        .service_provider()
+        .resolve_shared::<Cache>()
+        .unwrap();
+    let cache_2 = service_scope
+        .service_provider()
+        .resolve_shared::<Cache>()
+        .unwrap();
+
+    assert!(Arc::ptr_eq(&cache_1, &cache_2));
+
+    let other_service_scope = service_provider.resolve::<ServiceScope>().unwrap();
+    let cache_3 = other_service_scope
+        .service_provider()
+        .resolve_shared::<Cache>()
+        .unwrap();
+
+    assert!(!Arc::ptr_eq(&cache_1, &cache_3));
+}
+
+#[test]
+fn test_shared_singleton_service_is_same_allocation() {
+    let mut services = ServiceCollection::new();
+    services.add_singleton_shared::<Cache>();
+    let service_provider = services.build();
+
+    let cache_1 = service_provider.resolve_shared::<Cache>().unwrap();
+    let service_scope = service_provider.resolve::<ServiceScope>().unwrap();
+    let cache_2 = service_scope
+        .service_provider()
+        .resolve_shared::<Cache>()
+        .unwrap();
+
+    assert!(Arc::ptr_eq(&cache_1, &cache_2));
+}
@@ -0,0 +1,87 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::inject::{ServiceCollection, ServiceScope, injectable};
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+trait Counter: Send + Sync {
+    fn increment(&self) -> usize;
+}
+
+#[derive(Clone)]
+struct SharedCounter {
+    count: Arc<AtomicUsize>,
+}
+
+#[injectable]
+impl SharedCounter {
+    pub fn new() -> Self {
+        Self {
+            count: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+}
+
+impl Counter for SharedCounter {
+    fn increment(&self) -> usize {
+        self.count.fetch_add(1, Ordering::Relaxed) + 1
+    }
+}
+
+#[test]
+fn test_resolve_trait_object() {
+    let mut services = ServiceCollection::new();
+    services.add_scoped_as::<dyn Counter, SharedCounter>(|service| service);
+    let service_provider = services.build();
+
+    let service_scope = service_provider.resolve::<ServiceScope>().unwrap();
+    let counter = service_scope
+        .service_provider()
+        .resolve::<Box<dyn Counter>>()
+        .unwrap();
+
+    pretty_assertions::assert_eq!(counter.increment(), 1);
+}
+
+#[test]
+fn test_trait_object_shares_scoped_instance() {
+    let mut services = ServiceCollection::new();
+    services.add_scoped_as::<dyn Counter, SharedCounter>(|service| service);
+    let service_provider = services.build();
+
+    let service_scope = service_provider.resolve::<ServiceScope>().unwrap();
+    let service_provider = service_scope.service_provider();
+
+    service_provider
+        .resolve::<Box<dyn Counter>>()
+        .unwrap()
+        .increment();
+    service_provider
+        .resolve::<SharedCounter>()
+        .unwrap()
+        .increment();
+
+    pretty_assertions::assert_eq!(
+        service_provider
+            .resolve::<Box<dyn Counter>>()
+            .unwrap()
+            .increment(),
+        3
+    );
+}
+
+#[test]
+fn test_trait_object_is_not_resolvable_outside_of_scope() {
+    let mut services = ServiceCollection::new();
+    services.add_scoped_as::<dyn Counter, SharedCounter>(|service| service);
+    let service_provider = services.build();
+
+    assert!(service_provider.resolve::<Box<dyn Counter>>().is_err());
+}
@@ -0,0 +1,48 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::{Application as _, ApplicationBuilder, application_builder, module};
+
+/// The notes of the application
+#[derive(Clone)]
+struct Notes;
+
+/// The `OpenAPI` specification the notes are documented with
+#[derive(Clone)]
+struct NotesDocumentation(String);
+
+#[module(optional_features = "Openapi")]
+pub trait NotesModule {
+    fn add_notes(self) -> impl ApplicationBuilder {
+        let builder = self.configure_services(|services| {
+            services.add_value(Notes);
+        });
+
+        #[optional(Openapi)]
+        let builder = builder.configure_services(|services| {
+            services.add_value(NotesDocumentation(
+                nexustack::openapi::json::Specification::OpenAPI3_1.to_string(),
+            ));
+        });
+
+        builder
+    }
+}
+
+#[test]
+fn test_optional_feature_is_configured_if_enabled() {
+    let app = application_builder().add_notes().build().unwrap();
+
+    assert!(app.service_provider().resolve::<Notes>().is_ok());
+    pretty_assertions::assert_eq!(
+        app.service_provider()
+            .resolve::<NotesDocumentation>()
+            .ok()
+            .map(|documentation| documentation.0),
+        cfg!(feature = "openapi").then(|| "Open API 3.1".to_owned())
+    );
+}
@@ -0,0 +1,88 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::openapi::{BoundedString, NonEmptyString, api_schema};
+
+/// A test struct
+#[api_schema]
+pub struct User {
+    /// The name of the user
+    name: NonEmptyString,
+    /// The country code of the user
+    country: BoundedString<2, 3>,
+}
+
+#[test]
+fn test_openapi_3_0() {
+    use nexustack::openapi::json::{Specification, build_schema};
+    let schema = build_schema::<User>(Specification::OpenAPI3_0).unwrap();
+
+    pretty_assertions::assert_eq!(
+        serde_json::to_value(schema).unwrap(),
+        serde_json::json!({
+            "description": "A test struct",
+            "example": { "name": "h", "country": "aa" },
+            "type": "object",
+            "required": ["country", "name"],
+            "properties": {
+                "name": {
+                    "description": "The name of the user",
+                    "type": "string",
+                    "minLength": 1,
+                    "example": "h"
+                },
+                "country": {
+                    "description": "The country code of the user",
+                    "type": "string",
+                    "minLength": 2,
+                    "maxLength": 3,
+                    "example": "aa"
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_deserialize_within_bounds() {
+    let user =
+        serde_json::from_value::<User>(serde_json::json!({ "name": "Ferris", "country": "DEU" }))
+            .unwrap();
+
+    pretty_assertions::assert_eq!(&*user.name, "Ferris");
+    pretty_assertions::assert_eq!(&*user.country, "DEU");
+}
+
+#[test]
+fn test_deserialize_empty() {
+    let error = serde_json::from_value::<User>(serde_json::json!({ "name": "", "country": "DE" }))
+        .err()
+        .unwrap();
+
+    assert!(
+        error
+            .to_string()
+            .contains("expected at least 1 characters, found 0"),
+        "unexpected error: {error}"
+    );
+}
+
+#[test]
+fn test_deserialize_oversized() {
+    let error = serde_json::from_value::<User>(
+        serde_json::json!({ "name": "Ferris", "country": "GERMANY" }),
+    )
+    .err()
+    .unwrap();
+
+    assert!(
+        error
+            .to_string()
+            .contains("expected between 2 and 3 characters, found 7"),
+        "unexpected error: {error}"
+    );
+}
@@ -0,0 +1,75 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::openapi::{BoundedVec, api_schema};
+
+/// A test struct
+#[api_schema]
+pub struct Article {
+    /// The tags of the article
+    tags: BoundedVec<u8, 1, 3>,
+}
+
+#[test]
+fn test_openapi_3_0() {
+    use nexustack::openapi::json::{Specification, build_schema};
+    let schema = build_schema::<Article>(Specification::OpenAPI3_0).unwrap();
+
+    pretty_assertions::assert_eq!(
+        serde_json::to_value(schema).unwrap(),
+        serde_json::json!({
+            "description": "A test struct",
+            "example": { "tags": [0] },
+            "type": "object",
+            "required": ["tags"],
+            "properties": {
+                "tags": {
+                    "description": "The tags of the article",
+                    "type": "array",
+                    "minItems": 1,
+                    "maxItems": 3,
+                    "example": [0],
+                    "items": {
+                        "example": 0,
+                        "maximum": 255,
+                        "minimum": 0,
+                        "type": "integer"
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_deserialize_within_bounds() {
+    let article =
+        serde_json::from_value::<Article>(serde_json::json!({ "tags": [1, 2, 3] })).unwrap();
+
+    pretty_assertions::assert_eq!(&*article.tags, &[1, 2, 3]);
+}
+
+#[test]
+fn test_deserialize_out_of_bounds() {
+    for tags in [serde_json::json!([]), serde_json::json!([1, 2, 3, 4])] {
+        let error = serde_json::from_value::<Article>(serde_json::json!({ "tags": tags }))
+            .err()
+            .unwrap();
+
+        assert!(
+            error.to_string().contains("expected between 1 and 3 items"),
+            "unexpected error: {error}"
+        );
+    }
+}
+
+#[test]
+fn test_try_from_out_of_bounds() {
+    let error = BoundedVec::<u8, 1, 3>::try_from(vec![1, 2, 3, 4]).unwrap_err();
+
+    pretty_assertions::assert_eq!(error.to_string(), "expected between 1 and 3 items, found 4");
+}
@@ -0,0 +1,115 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use crate::openapi::validation::is_valid;
+use nexustack::openapi::{api_schema, json::build_bundled_schema};
+
+/// A test struct
+#[api_schema]
+pub struct Point {
+    /// Field x
+    x: i32,
+    /// Field y
+    y: i32,
+}
+
+/// A test enum
+#[api_schema]
+pub enum Shape {
+    /// Variant Circle
+    Circle {
+        /// Field center
+        center: Point,
+        /// Field radius
+        radius: u32,
+    },
+    /// Variant Polygon
+    Polygon(
+        /// The corners of the polygon
+        Vec<Point>,
+    ),
+}
+
+/// A test struct
+#[api_schema]
+pub struct Drawing {
+    /// Field origin
+    origin: Point,
+    /// Field shapes
+    shapes: Vec<Shape>,
+}
+
+fn collect_refs<'a>(value: &'a serde_json::Value, refs: &mut Vec<&'a str>) {
+    match value {
+        serde_json::Value::Object(object) => {
+            for (key, value) in object {
+                match (key.as_str(), value) {
+                    ("$ref", serde_json::Value::String(reference)) => refs.push(reference),
+                    _ => collect_refs(value, refs),
+                }
+            }
+        }
+        serde_json::Value::Array(values) => {
+            for value in values {
+                collect_refs(value, refs);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[test]
+fn test_bundled_schema_is_self_contained() {
+    let document = serde_json::to_value(build_bundled_schema::<Drawing>().unwrap()).unwrap();
+
+    pretty_assertions::assert_eq!(Some("object"), document["type"].as_str());
+    assert!(document["$defs"]["Point"].is_object());
+    assert!(document["$defs"]["Shape"].is_object());
+
+    let mut refs = Vec::new();
+    collect_refs(&document, &mut refs);
+
+    assert!(!refs.is_empty());
+
+    for reference in refs {
+        let pointer = reference.strip_prefix('#').unwrap();
+        assert!(
+            document.pointer(pointer).is_some(),
+            "Reference {reference} does not resolve within the document"
+        );
+    }
+}
+
+#[test]
+fn test_bundled_schema_validates_instance() {
+    let document = serde_json::to_value(build_bundled_schema::<Drawing>().unwrap()).unwrap();
+
+    assert!(is_valid(
+        &document,
+        &serde_json::json!({
+            "origin": { "x": 0, "y": 0 },
+            "shapes": [
+                { "Circle": { "center": { "x": 1, "y": 2 }, "radius": 3 } },
+                { "Polygon": [{ "x": 0, "y": 0 }, { "x": 1, "y": 1 }] }
+            ]
+        })
+    ));
+    assert!(!is_valid(
+        &document,
+        &serde_json::json!({
+            "origin": { "x": 0, "y": "0" },
+            "shapes": []
+        })
+    ));
+    assert!(!is_valid(
+        &document,
+        &serde_json::json!({
+            "origin": { "x": 0, "y": 0 },
+            "shapes": [{ "Circle": { "center": { "x": 1 }, "radius": 3 } }]
+        })
+    ));
+}
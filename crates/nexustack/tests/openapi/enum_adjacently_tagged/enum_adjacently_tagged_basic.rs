@@ -70,6 +70,7 @@ fn test_openapi_3_0() {
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
                             "nullable": true,
+                            "format": "int32",
                             "type": "integer"
                         }
                     }
@@ -95,6 +96,7 @@ fn test_openapi_3_0() {
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
                                         "nullable": true,
+                                        "format": "int32",
                                         "type": "integer"
                                     },
                                     {
@@ -102,6 +104,7 @@ fn test_openapi_3_0() {
                                         "example": -2_147_483_648,
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": "integer"
                                     }
                                 ]
@@ -126,6 +129,7 @@ fn test_openapi_3_0() {
                                     "example": -2_147_483_648,
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": "integer"
                                 },
                                 "y": {
@@ -134,6 +138,7 @@ fn test_openapi_3_0() {
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
                                     "nullable": true,
+                                    "format": "int32",
                                     "type": "integer"
                                 }
                             },
@@ -203,6 +208,7 @@ fn test_openapi_3_0_with_collection() {
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
                                 "nullable": true,
+                                "format": "int32",
                                 "type": "integer"
                             }
                         }
@@ -228,6 +234,7 @@ fn test_openapi_3_0_with_collection() {
                                             "maximum": 2_147_483_647,
                                             "minimum": -2_147_483_648,
                                             "nullable": true,
+                                            "format": "int32",
                                             "type": "integer"
                                         },
                                         {
@@ -235,6 +242,7 @@ fn test_openapi_3_0_with_collection() {
                                             "example": -2_147_483_648,
                                             "maximum": 2_147_483_647,
                                             "minimum": -2_147_483_648,
+                                            "format": "int32",
                                             "type": "integer"
                                         }
                                     ]
@@ -259,6 +267,7 @@ fn test_openapi_3_0_with_collection() {
                                         "example": -2_147_483_648,
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": "integer"
                                     },
                                     "y": {
@@ -267,6 +276,7 @@ fn test_openapi_3_0_with_collection() {
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
                                         "nullable": true,
+                                        "format": "int32",
                                         "type": "integer"
                                     }
                                 },
@@ -400,6 +410,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": [
                                 "integer",
                                 "null"
@@ -433,6 +444,7 @@ fn test_openapi_3_1() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": [
                                         "integer",
                                         "null"
@@ -449,6 +461,7 @@ fn test_openapi_3_1() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": "integer"
                                 }
                             ]
@@ -478,6 +491,7 @@ fn test_openapi_3_1() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": "integer"
                                 },
                                 "y": {
@@ -492,6 +506,7 @@ fn test_openapi_3_1() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": [
                                         "integer",
                                         "null"
@@ -648,6 +663,7 @@ fn test_openapi_3_1_with_collection() {
                                 ],
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": [
                                     "integer",
                                     "null"
@@ -681,6 +697,7 @@ fn test_openapi_3_1_with_collection() {
                                         ],
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": [
                                             "integer",
                                             "null"
@@ -697,6 +714,7 @@ fn test_openapi_3_1_with_collection() {
                                         ],
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": "integer"
                                     }
                                 ]
@@ -726,6 +744,7 @@ fn test_openapi_3_1_with_collection() {
                                         ],
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": "integer"
                                     },
                                     "y": {
@@ -740,6 +759,7 @@ fn test_openapi_3_1_with_collection() {
                                         ],
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": [
                                             "integer",
                                             "null"
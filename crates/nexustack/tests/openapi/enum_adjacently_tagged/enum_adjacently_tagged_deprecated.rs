@@ -74,6 +74,7 @@ fn test_openapi_3_0() {
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
                             "nullable": true,
+                            "format": "int32",
                             "type": "integer"
                         }
                     }
@@ -99,6 +100,7 @@ fn test_openapi_3_0() {
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
                                         "nullable": true,
+                                        "format": "int32",
                                         "type": "integer"
                                     },
                                     {
@@ -106,6 +108,7 @@ fn test_openapi_3_0() {
                                         "example": -2_147_483_648,
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": "integer"
                                     }
                                 ]
@@ -130,6 +133,7 @@ fn test_openapi_3_0() {
                                     "example": -2_147_483_648,
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": "integer"
                                 },
                                 "y": {
@@ -138,6 +142,7 @@ fn test_openapi_3_0() {
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
                                     "nullable": true,
+                                    "format": "int32",
                                     "type": "integer"
                                 }
                             },
@@ -208,6 +213,7 @@ fn test_openapi_3_0_with_collection() {
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
                                 "nullable": true,
+                                "format": "int32",
                                 "type": "integer"
                             }
                         }
@@ -233,6 +239,7 @@ fn test_openapi_3_0_with_collection() {
                                             "maximum": 2_147_483_647,
                                             "minimum": -2_147_483_648,
                                             "nullable": true,
+                                            "format": "int32",
                                             "type": "integer"
                                         },
                                         {
@@ -240,6 +247,7 @@ fn test_openapi_3_0_with_collection() {
                                             "example": -2_147_483_648,
                                             "maximum": 2_147_483_647,
                                             "minimum": -2_147_483_648,
+                                            "format": "int32",
                                             "type": "integer"
                                         }
                                     ]
@@ -264,6 +272,7 @@ fn test_openapi_3_0_with_collection() {
                                         "example": -2_147_483_648,
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": "integer"
                                     },
                                     "y": {
@@ -272,6 +281,7 @@ fn test_openapi_3_0_with_collection() {
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
                                         "nullable": true,
+                                        "format": "int32",
                                         "type": "integer"
                                     }
                                 },
@@ -406,6 +416,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": [
                                 "integer",
                                 "null"
@@ -439,6 +450,7 @@ fn test_openapi_3_1() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": [
                                         "integer",
                                         "null"
@@ -455,6 +467,7 @@ fn test_openapi_3_1() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": "integer"
                                 }
                             ]
@@ -484,6 +497,7 @@ fn test_openapi_3_1() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": "integer"
                                 },
                                 "y": {
@@ -498,6 +512,7 @@ fn test_openapi_3_1() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": [
                                         "integer",
                                         "null"
@@ -655,6 +670,7 @@ fn test_openapi_3_1_with_collection() {
                                 ],
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": [
                                     "integer",
                                     "null"
@@ -688,6 +704,7 @@ fn test_openapi_3_1_with_collection() {
                                         ],
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": [
                                             "integer",
                                             "null"
@@ -704,6 +721,7 @@ fn test_openapi_3_1_with_collection() {
                                         ],
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": "integer"
                                     }
                                 ]
@@ -733,6 +751,7 @@ fn test_openapi_3_1_with_collection() {
                                         ],
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": "integer"
                                     },
                                     "y": {
@@ -747,6 +766,7 @@ fn test_openapi_3_1_with_collection() {
                                         ],
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": [
                                             "integer",
                                             "null"
@@ -75,6 +75,7 @@ fn test_openapi_3_0() {
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
                             "nullable": true,
+                            "format": "int32",
                             "type": "integer"
                         }
                     }
@@ -101,6 +102,7 @@ fn test_openapi_3_0() {
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
                                         "nullable": true,
+                                        "format": "int32",
                                         "type": "integer"
                                     },
                                     {
@@ -109,6 +111,7 @@ fn test_openapi_3_0() {
                                         "example": -2_147_483_648,
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": "integer"
                                     }
                                 ]
@@ -134,6 +137,7 @@ fn test_openapi_3_0() {
                                     "example": -2_147_483_648,
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": "integer"
                                 },
                                 "y": {
@@ -143,6 +147,7 @@ fn test_openapi_3_0() {
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
                                     "nullable": true,
+                                    "format": "int32",
                                     "type": "integer"
                                 }
                             },
@@ -213,6 +218,7 @@ fn test_openapi_3_0_with_collection() {
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
                                 "nullable": true,
+                                "format": "int32",
                                 "type": "integer"
                             }
                         }
@@ -239,6 +245,7 @@ fn test_openapi_3_0_with_collection() {
                                             "maximum": 2_147_483_647,
                                             "minimum": -2_147_483_648,
                                             "nullable": true,
+                                            "format": "int32",
                                             "type": "integer"
                                         },
                                         {
@@ -247,6 +254,7 @@ fn test_openapi_3_0_with_collection() {
                                             "example": -2_147_483_648,
                                             "maximum": 2_147_483_647,
                                             "minimum": -2_147_483_648,
+                                            "format": "int32",
                                             "type": "integer"
                                         }
                                     ]
@@ -272,6 +280,7 @@ fn test_openapi_3_0_with_collection() {
                                         "example": -2_147_483_648,
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": "integer"
                                     },
                                     "y": {
@@ -281,6 +290,7 @@ fn test_openapi_3_0_with_collection() {
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
                                         "nullable": true,
+                                        "format": "int32",
                                         "type": "integer"
                                     }
                                 },
@@ -415,6 +425,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": [
                                 "integer",
                                 "null"
@@ -449,6 +460,7 @@ fn test_openapi_3_1() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": [
                                         "integer",
                                         "null"
@@ -466,6 +478,7 @@ fn test_openapi_3_1() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": "integer"
                                 }
                             ]
@@ -496,6 +509,7 @@ fn test_openapi_3_1() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": "integer"
                                 },
                                 "y": {
@@ -511,6 +525,7 @@ fn test_openapi_3_1() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": [
                                         "integer",
                                         "null"
@@ -668,6 +683,7 @@ fn test_openapi_3_1_with_collection() {
                                 ],
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": [
                                     "integer",
                                     "null"
@@ -702,6 +718,7 @@ fn test_openapi_3_1_with_collection() {
                                         ],
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": [
                                             "integer",
                                             "null"
@@ -719,6 +736,7 @@ fn test_openapi_3_1_with_collection() {
                                         ],
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": "integer"
                                     }
                                 ]
@@ -749,6 +767,7 @@ fn test_openapi_3_1_with_collection() {
                                         ],
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": "integer"
                                     },
                                     "y": {
@@ -764,6 +783,7 @@ fn test_openapi_3_1_with_collection() {
                                         ],
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": [
                                             "integer",
                                             "null"
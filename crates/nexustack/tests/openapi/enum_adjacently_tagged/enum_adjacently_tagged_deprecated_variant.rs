@@ -79,6 +79,7 @@ fn test_openapi_3_0() {
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
                             "nullable": true,
+                            "format": "int32",
                             "type": "integer"
                         }
                     }
@@ -105,6 +106,7 @@ fn test_openapi_3_0() {
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
                                         "nullable": true,
+                                        "format": "int32",
                                         "type": "integer"
                                     },
                                     {
@@ -112,6 +114,7 @@ fn test_openapi_3_0() {
                                         "example": -2_147_483_648,
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": "integer"
                                     }
                                 ]
@@ -137,6 +140,7 @@ fn test_openapi_3_0() {
                                     "example": -2_147_483_648,
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": "integer"
                                 },
                                 "y": {
@@ -145,6 +149,7 @@ fn test_openapi_3_0() {
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
                                     "nullable": true,
+                                    "format": "int32",
                                     "type": "integer"
                                 }
                             },
@@ -216,6 +221,7 @@ fn test_openapi_3_0_with_collection() {
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
                                 "nullable": true,
+                                "format": "int32",
                                 "type": "integer"
                             }
                         }
@@ -242,6 +248,7 @@ fn test_openapi_3_0_with_collection() {
                                             "maximum": 2_147_483_647,
                                             "minimum": -2_147_483_648,
                                             "nullable": true,
+                                            "format": "int32",
                                             "type": "integer"
                                         },
                                         {
@@ -249,6 +256,7 @@ fn test_openapi_3_0_with_collection() {
                                             "example": -2_147_483_648,
                                             "maximum": 2_147_483_647,
                                             "minimum": -2_147_483_648,
+                                            "format": "int32",
                                             "type": "integer"
                                         }
                                     ]
@@ -274,6 +282,7 @@ fn test_openapi_3_0_with_collection() {
                                         "example": -2_147_483_648,
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": "integer"
                                     },
                                     "y": {
@@ -282,6 +291,7 @@ fn test_openapi_3_0_with_collection() {
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
                                         "nullable": true,
+                                        "format": "int32",
                                         "type": "integer"
                                     }
                                 },
@@ -417,6 +427,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": [
                                 "integer",
                                 "null"
@@ -451,6 +462,7 @@ fn test_openapi_3_1() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": [
                                         "integer",
                                         "null"
@@ -467,6 +479,7 @@ fn test_openapi_3_1() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": "integer"
                                 }
                             ]
@@ -497,6 +510,7 @@ fn test_openapi_3_1() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": "integer"
                                 },
                                 "y": {
@@ -511,6 +525,7 @@ fn test_openapi_3_1() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": [
                                         "integer",
                                         "null"
@@ -669,6 +684,7 @@ fn test_openapi_3_1_with_collection() {
                                 ],
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": [
                                     "integer",
                                     "null"
@@ -703,6 +719,7 @@ fn test_openapi_3_1_with_collection() {
                                         ],
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": [
                                             "integer",
                                             "null"
@@ -719,6 +736,7 @@ fn test_openapi_3_1_with_collection() {
                                         ],
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": "integer"
                                     }
                                 ]
@@ -749,6 +767,7 @@ fn test_openapi_3_1_with_collection() {
                                         ],
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": "integer"
                                     },
                                     "y": {
@@ -763,6 +782,7 @@ fn test_openapi_3_1_with_collection() {
                                         ],
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": [
                                             "integer",
                                             "null"
@@ -70,6 +70,7 @@ fn test_openapi_3_0() {
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
                             "nullable": true,
+                            "format": "int32",
                             "type": "integer"
                         }
                     }
@@ -95,6 +96,7 @@ fn test_openapi_3_0() {
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
                                         "nullable": true,
+                                        "format": "int32",
                                         "type": "integer"
                                     },
                                     {
@@ -102,6 +104,7 @@ fn test_openapi_3_0() {
                                         "example": -2_147_483_648,
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": "integer"
                                     }
                                 ]
@@ -126,6 +129,7 @@ fn test_openapi_3_0() {
                                     "example": -2_147_483_648,
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": "integer"
                                 },
                                 "y": {
@@ -134,6 +138,7 @@ fn test_openapi_3_0() {
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
                                     "nullable": true,
+                                    "format": "int32",
                                     "type": "integer"
                                 }
                             },
@@ -195,6 +200,7 @@ fn test_openapi_3_0_with_collection() {
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
                             "nullable": true,
+                            "format": "int32",
                             "type": "integer"
                         }
                     }
@@ -220,6 +226,7 @@ fn test_openapi_3_0_with_collection() {
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
                                         "nullable": true,
+                                        "format": "int32",
                                         "type": "integer"
                                     },
                                     {
@@ -227,6 +234,7 @@ fn test_openapi_3_0_with_collection() {
                                         "example": -2_147_483_648,
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": "integer"
                                     }
                                 ]
@@ -251,6 +259,7 @@ fn test_openapi_3_0_with_collection() {
                                     "example": -2_147_483_648,
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": "integer"
                                 },
                                 "y": {
@@ -259,6 +268,7 @@ fn test_openapi_3_0_with_collection() {
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
                                     "nullable": true,
+                                    "format": "int32",
                                     "type": "integer"
                                 }
                             },
@@ -396,6 +406,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": [
                                 "integer",
                                 "null"
@@ -429,6 +440,7 @@ fn test_openapi_3_1() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": [
                                         "integer",
                                         "null"
@@ -445,6 +457,7 @@ fn test_openapi_3_1() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": "integer"
                                 }
                             ]
@@ -474,6 +487,7 @@ fn test_openapi_3_1() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": "integer"
                                 },
                                 "y": {
@@ -488,6 +502,7 @@ fn test_openapi_3_1() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": [
                                         "integer",
                                         "null"
@@ -636,6 +651,7 @@ fn test_openapi_3_1_with_collection() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": [
                                 "integer",
                                 "null"
@@ -669,6 +685,7 @@ fn test_openapi_3_1_with_collection() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": [
                                         "integer",
                                         "null"
@@ -685,6 +702,7 @@ fn test_openapi_3_1_with_collection() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": "integer"
                                 }
                             ]
@@ -714,6 +732,7 @@ fn test_openapi_3_1_with_collection() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": "integer"
                                 },
                                 "y": {
@@ -728,6 +747,7 @@ fn test_openapi_3_1_with_collection() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": [
                                         "integer",
                                         "null"
@@ -71,6 +71,7 @@ fn test_openapi_3_0() {
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
                             "nullable": true,
+                            "format": "int32",
                             "type": "integer"
                         }
                     }
@@ -96,6 +97,7 @@ fn test_openapi_3_0() {
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
                                         "nullable": true,
+                                        "format": "int32",
                                         "type": "integer"
                                     },
                                     {
@@ -103,6 +105,7 @@ fn test_openapi_3_0() {
                                         "example": -2_147_483_648,
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": "integer"
                                     }
                                 ]
@@ -127,6 +130,7 @@ fn test_openapi_3_0() {
                                     "example": -2_147_483_648,
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": "integer"
                                 },
                                 "y": {
@@ -135,6 +139,7 @@ fn test_openapi_3_0() {
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
                                     "nullable": true,
+                                    "format": "int32",
                                     "type": "integer"
                                 }
                             },
@@ -144,7 +149,7 @@ fn test_openapi_3_0() {
                 },
                 {
                     "type": "object",
-                    "required": ["cont", "type"],
+                    "required": ["type"],
                     "properties": {
                         "type": {
                             "type": "string",
@@ -215,6 +220,7 @@ fn test_openapi_3_0_with_collection() {
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
                                 "nullable": true,
+                                "format": "int32",
                                 "type": "integer"
                             }
                         }
@@ -240,6 +246,7 @@ fn test_openapi_3_0_with_collection() {
                                             "maximum": 2_147_483_647,
                                             "minimum": -2_147_483_648,
                                             "nullable": true,
+                                            "format": "int32",
                                             "type": "integer"
                                         },
                                         {
@@ -247,6 +254,7 @@ fn test_openapi_3_0_with_collection() {
                                             "example": -2_147_483_648,
                                             "maximum": 2_147_483_647,
                                             "minimum": -2_147_483_648,
+                                            "format": "int32",
                                             "type": "integer"
                                         }
                                     ]
@@ -271,6 +279,7 @@ fn test_openapi_3_0_with_collection() {
                                         "example": -2_147_483_648,
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": "integer"
                                     },
                                     "y": {
@@ -279,6 +288,7 @@ fn test_openapi_3_0_with_collection() {
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
                                         "nullable": true,
+                                        "format": "int32",
                                         "type": "integer"
                                     }
                                 },
@@ -288,7 +298,7 @@ fn test_openapi_3_0_with_collection() {
                     },
                     {
                         "type": "object",
-                        "required": ["cont", "type"],
+                        "required": ["type"],
                         "properties": {
                             "type": {
                                 "type": "string",
@@ -423,6 +433,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": [
                                 "integer",
                                 "null"
@@ -456,6 +467,7 @@ fn test_openapi_3_1() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": [
                                         "integer",
                                         "null"
@@ -472,6 +484,7 @@ fn test_openapi_3_1() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": "integer"
                                 }
                             ]
@@ -501,6 +514,7 @@ fn test_openapi_3_1() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": "integer"
                                 },
                                 "y": {
@@ -515,6 +529,7 @@ fn test_openapi_3_1() {
                                     ],
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": [
                                         "integer",
                                         "null"
@@ -527,7 +542,7 @@ fn test_openapi_3_1() {
                 },
                 {
                     "type": "object",
-                    "required": ["cont", "type"],
+                    "required": ["type"],
                     "properties": {
                         "type": {
                             "type": "string",
@@ -682,6 +697,7 @@ fn test_openapi_3_1_with_collection() {
                                 ],
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": [
                                     "integer",
                                     "null"
@@ -715,6 +731,7 @@ fn test_openapi_3_1_with_collection() {
                                         ],
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": [
                                             "integer",
                                             "null"
@@ -731,6 +748,7 @@ fn test_openapi_3_1_with_collection() {
                                         ],
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": "integer"
                                     }
                                 ]
@@ -760,6 +778,7 @@ fn test_openapi_3_1_with_collection() {
                                         ],
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": "integer"
                                     },
                                     "y": {
@@ -774,6 +793,7 @@ fn test_openapi_3_1_with_collection() {
                                         ],
                                         "maximum": 2_147_483_647,
                                         "minimum": -2_147_483_648,
+                                        "format": "int32",
                                         "type": [
                                             "integer",
                                             "null"
@@ -786,7 +806,7 @@ fn test_openapi_3_1_with_collection() {
                     },
                     {
                         "type": "object",
-                        "required": ["cont", "type"],
+                        "required": ["type"],
                         "properties": {
                             "type": {
                                 "type": "string",
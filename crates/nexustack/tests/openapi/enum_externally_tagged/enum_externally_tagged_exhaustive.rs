@@ -0,0 +1,148 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::openapi::{
+    api_schema,
+    json::{Specification, build_schema},
+};
+
+/// A test enum
+#[api_schema(exhaustive)]
+#[non_exhaustive]
+pub enum ClosedMessage {
+    /// Variant A
+    VariantA,
+    /// Variant B
+    VariantB {
+        /// Field x of variant B
+        x: i32,
+    },
+}
+
+/// A test enum
+#[api_schema(non_exhaustive)]
+pub enum OpenMessage {
+    /// Variant A
+    VariantA,
+    /// Variant B
+    VariantB {
+        /// Field x of variant B
+        x: i32,
+    },
+}
+
+#[test]
+fn test_openapi_3_0_exhaustive() {
+    let schema = build_schema::<ClosedMessage>(Specification::OpenAPI3_0).unwrap();
+
+    pretty_assertions::assert_eq!(
+        serde_json::to_value(schema).unwrap(),
+        serde_json::json!({
+            "description": "A test enum",
+            "example": "VariantA",
+            "anyOf": [
+                {
+                    "description": "Variant A",
+                    "type": "string",
+                    "enum": ["VariantA"]
+                },
+                {
+                    "description": "Variant B",
+                    "type": "object",
+                    "required": ["VariantB"],
+                    "properties": {
+                        "VariantB": {
+                            "type": "object",
+                            "required": ["x"],
+                            "properties": {
+                                "x": {
+                                    "description": "Field x of variant B",
+                                    "example": -2_147_483_648,
+                                    "maximum": 2_147_483_647,
+                                    "minimum": -2_147_483_648,
+                                    "format": "int32",
+                                    "type": "integer"
+                                }
+                            }
+                        }
+                    }
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn test_openapi_3_0_non_exhaustive() {
+    let schema = build_schema::<OpenMessage>(Specification::OpenAPI3_0).unwrap();
+
+    pretty_assertions::assert_eq!(
+        serde_json::to_value(schema).unwrap(),
+        serde_json::json!({
+            "description": "A test enum",
+            "example": "VariantA",
+            "anyOf": [
+                {
+                    "description": "Variant A",
+                    "type": "string",
+                    "enum": ["VariantA"]
+                },
+                {
+                    "description": "Variant B",
+                    "type": "object",
+                    "required": ["VariantB"],
+                    "properties": {
+                        "VariantB": {
+                            "type": "object",
+                            "required": ["x"],
+                            "properties": {
+                                "x": {
+                                    "description": "Field x of variant B",
+                                    "example": -2_147_483_648,
+                                    "maximum": 2_147_483_647,
+                                    "minimum": -2_147_483_648,
+                                    "format": "int32",
+                                    "type": "integer"
+                                }
+                            }
+                        }
+                    }
+                },
+                {
+                    "type": "object",
+                    "additionalProperties": true
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn test_openapi_3_1_exhaustive() {
+    let schema =
+        serde_json::to_value(build_schema::<ClosedMessage>(Specification::OpenAPI3_1).unwrap())
+            .unwrap();
+    let subschemas = schema["anyOf"].as_array().unwrap();
+
+    pretty_assertions::assert_eq!(subschemas.len(), 2);
+    assert!(
+        subschemas
+            .iter()
+            .all(|subschema| subschema.get("patternProperties").is_none())
+    );
+}
+
+#[test]
+fn test_openapi_3_1_non_exhaustive() {
+    let schema =
+        serde_json::to_value(build_schema::<OpenMessage>(Specification::OpenAPI3_1).unwrap())
+            .unwrap();
+    let subschemas = schema["anyOf"].as_array().unwrap();
+
+    pretty_assertions::assert_eq!(subschemas.len(), 3);
+    assert!(subschemas[2].get("patternProperties").is_some());
+}
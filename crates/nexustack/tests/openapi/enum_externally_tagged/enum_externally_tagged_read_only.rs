@@ -0,0 +1,33 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::openapi::api_schema;
+
+/// A computed status
+#[api_schema(read_only)]
+pub enum Status {
+    /// Variant A
+    A,
+    /// Variant B
+    B(
+        /// The reason
+        String,
+    ),
+}
+
+#[test]
+fn test_read_only() {
+    use nexustack::openapi::json::{Specification, build_schema};
+
+    for specification in [Specification::OpenAPI3_0, Specification::OpenAPI3_1] {
+        let schema = build_schema::<Status>(specification).unwrap();
+        let schema = serde_json::to_value(schema).unwrap();
+
+        pretty_assertions::assert_eq!(schema["readOnly"], serde_json::json!(true));
+        assert!(schema["anyOf"].is_array());
+    }
+}
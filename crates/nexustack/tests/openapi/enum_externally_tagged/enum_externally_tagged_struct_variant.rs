@@ -0,0 +1,66 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use crate::openapi::validation::is_valid;
+use nexustack::openapi::{
+    api_schema,
+    json::{Specification, build_schema},
+};
+
+/// A test enum
+#[api_schema]
+pub enum Message {
+    /// Variant A
+    A {
+        /// Field x of variant A
+        x: i32,
+        /// Field y of variant A
+        y: Option<i32>,
+    },
+    /// Variant B
+    B {
+        /// Field z of variant B
+        z: i32,
+    },
+}
+
+fn assert_struct_variants_match_schema(specification: Specification) {
+    let schema = serde_json::to_value(build_schema::<Message>(specification).unwrap()).unwrap();
+
+    for message in [
+        Message::A { x: 1, y: Some(2) },
+        Message::A { x: 1, y: None },
+        Message::B { z: 3 },
+    ] {
+        let payload = serde_json::to_value(message).unwrap();
+        assert!(
+            is_valid(&schema, &payload),
+            "{payload} does not match the schema {schema}"
+        );
+    }
+
+    for payload in [
+        serde_json::json!({ "x": 1, "y": 2 }),
+        serde_json::json!({ "A": { "z": 3 } }),
+        serde_json::json!({ "B": { "x": 1, "y": 2 } }),
+    ] {
+        assert!(
+            !is_valid(&schema, &payload),
+            "{payload} unexpectedly matches the schema {schema}"
+        );
+    }
+}
+
+#[test]
+fn test_openapi_3_0() {
+    assert_struct_variants_match_schema(Specification::OpenAPI3_0);
+}
+
+#[test]
+fn test_openapi_3_1() {
+    assert_struct_variants_match_schema(Specification::OpenAPI3_1);
+}
@@ -9,10 +9,13 @@ mod enum_externally_tagged_basic;
 mod enum_externally_tagged_deprecated;
 mod enum_externally_tagged_deprecated_field;
 mod enum_externally_tagged_deprecated_variant;
+mod enum_externally_tagged_exhaustive;
 mod enum_externally_tagged_generic;
 mod enum_externally_tagged_non_exhaustive;
+mod enum_externally_tagged_read_only;
 mod enum_externally_tagged_rename;
 mod enum_externally_tagged_rename_field;
 mod enum_externally_tagged_rename_variant;
 mod enum_externally_tagged_skip_serializing_if;
 mod enum_externally_tagged_skip_variant;
+mod enum_externally_tagged_struct_variant;
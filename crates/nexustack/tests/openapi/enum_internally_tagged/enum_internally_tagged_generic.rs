@@ -97,6 +97,7 @@ fn test_openapi_3_0() {
                             "example": -2_147_483_648,
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": "integer"
                         },
                         "y": {
@@ -105,6 +106,7 @@ fn test_openapi_3_0() {
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
                             "nullable": true,
+                            "format": "int32",
                             "type": "integer"
                         }
                     },
@@ -185,6 +187,7 @@ fn test_openapi_3_0_with_collection() {
                             "example": -2_147_483_648,
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": "integer"
                         },
                         "y": {
@@ -193,6 +196,7 @@ fn test_openapi_3_0_with_collection() {
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
                             "nullable": true,
+                            "format": "int32",
                             "type": "integer"
                         }
                     },
@@ -416,6 +420,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": "integer"
                         },
                         "y": {
@@ -430,6 +435,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": [
                                 "integer",
                                 "null"
@@ -581,6 +587,7 @@ fn test_openapi_3_1_with_collection() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": "integer"
                         },
                         "y": {
@@ -595,6 +602,7 @@ fn test_openapi_3_1_with_collection() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": [
                                 "integer",
                                 "null"
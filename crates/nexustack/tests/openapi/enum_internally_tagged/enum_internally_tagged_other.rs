@@ -101,6 +101,7 @@ fn test_openapi_3_0() {
                             "example": -2_147_483_648,
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": "integer"
                         },
                         "y": {
@@ -109,6 +110,7 @@ fn test_openapi_3_0() {
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
                             "nullable": true,
+                            "format": "int32",
                             "type": "integer"
                         }
                     },
@@ -207,6 +209,7 @@ fn test_openapi_3_0_with_collection() {
                                 "example": -2_147_483_648,
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": "integer"
                             },
                             "y": {
@@ -215,6 +218,7 @@ fn test_openapi_3_0_with_collection() {
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
                                 "nullable": true,
+                                "format": "int32",
                                 "type": "integer",
                             }
                         },
@@ -443,6 +447,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": "integer"
                         },
                         "y": {
@@ -457,6 +462,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": [
                                 "integer",
                                 "null"
@@ -626,6 +632,7 @@ fn test_openapi_3_1_with_collection() {
                                 ],
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": "integer"
                             },
                             "y": {
@@ -640,6 +647,7 @@ fn test_openapi_3_1_with_collection() {
                                 ],
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": [
                                     "integer",
                                     "null"
@@ -735,3 +743,25 @@ fn test_openapi_3_1_with_collection() {
         })
     );
 }
+
+#[test]
+fn test_unknown_variant_matches_other() {
+    use crate::openapi::validation::is_valid;
+    use nexustack::openapi::json::{Specification, build_schema};
+
+    let payload = serde_json::json!({ "type": "VariantE", "z": 1 });
+
+    for specification in [Specification::OpenAPI3_0, Specification::OpenAPI3_1] {
+        let schema = serde_json::to_value(build_schema::<Message>(specification).unwrap()).unwrap();
+
+        assert!(
+            is_valid(&schema, &payload),
+            "{payload} does not match the schema {schema}"
+        );
+    }
+
+    assert!(matches!(
+        serde_json::from_value::<Message>(payload).unwrap(),
+        Message::Other
+    ));
+}
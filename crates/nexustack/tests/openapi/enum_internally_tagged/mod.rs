@@ -17,3 +17,4 @@ mod enum_internally_tagged_rename_field;
 mod enum_internally_tagged_rename_variant;
 mod enum_internally_tagged_skip_serializing_if;
 mod enum_internally_tagged_skip_variant;
+mod enum_internally_tagged_struct_variant;
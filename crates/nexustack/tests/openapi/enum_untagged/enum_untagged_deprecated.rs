@@ -62,6 +62,7 @@ fn test_openapi_3_0() {
                     "maximum": 2_147_483_647,
                     "minimum": -2_147_483_648,
                     "nullable": true,
+                    "format": "int32",
                     "type": "integer"
                 },
                 {
@@ -77,6 +78,7 @@ fn test_openapi_3_0() {
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
                                 "nullable": true,
+                                "format": "int32",
                                 "type": "integer"
                             },
                             {
@@ -84,6 +86,7 @@ fn test_openapi_3_0() {
                                 "example": -2_147_483_648,
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": "integer"
                             }
                         ]
@@ -98,6 +101,7 @@ fn test_openapi_3_0() {
                             "example": -2_147_483_648,
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": "integer"
                         },
                         "y": {
@@ -106,6 +110,7 @@ fn test_openapi_3_0() {
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
                             "nullable": true,
+                            "format": "int32",
                             "type": "integer"
                         }
                     },
@@ -162,6 +167,7 @@ fn test_openapi_3_0_with_collection() {
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
                         "nullable": true,
+                        "format": "int32",
                         "type": "integer"
                     },
                     {
@@ -177,6 +183,7 @@ fn test_openapi_3_0_with_collection() {
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
                                     "nullable": true,
+                                    "format": "int32",
                                     "type": "integer"
                                 },
                                 {
@@ -184,6 +191,7 @@ fn test_openapi_3_0_with_collection() {
                                     "example": -2_147_483_648,
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": "integer"
                                 }
                             ]
@@ -198,6 +206,7 @@ fn test_openapi_3_0_with_collection() {
                                 "example": -2_147_483_648,
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": "integer"
                             },
                             "y": {
@@ -206,6 +215,7 @@ fn test_openapi_3_0_with_collection() {
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
                                 "nullable": true,
+                                "format": "int32",
                                 "type": "integer"
                             }
                         },
@@ -294,6 +304,7 @@ fn test_openapi_3_1() {
                     ],
                     "maximum": 2_147_483_647,
                     "minimum": -2_147_483_648,
+                    "format": "int32",
                     "type": [
                         "integer",
                         "null"
@@ -317,6 +328,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": [
                                 "integer",
                                 "null"
@@ -333,6 +345,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": "integer"
                         }
                     ]
@@ -352,6 +365,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": "integer"
                         },
                         "y": {
@@ -366,6 +380,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": [
                                 "integer",
                                 "null"
@@ -477,6 +492,7 @@ fn test_openapi_3_1_with_collection() {
                         ],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": [
                             "integer",
                             "null"
@@ -500,6 +516,7 @@ fn test_openapi_3_1_with_collection() {
                                 ],
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": [
                                     "integer",
                                     "null"
@@ -516,6 +533,7 @@ fn test_openapi_3_1_with_collection() {
                                 ],
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": "integer"
                             }
                         ]
@@ -535,6 +553,7 @@ fn test_openapi_3_1_with_collection() {
                                 ],
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": "integer"
                             },
                             "y": {
@@ -549,6 +568,7 @@ fn test_openapi_3_1_with_collection() {
                                 ],
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": [
                                     "integer",
                                     "null"
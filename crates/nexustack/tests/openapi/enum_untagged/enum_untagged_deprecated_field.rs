@@ -62,6 +62,7 @@ fn test_openapi_3_0() {
                     "maximum": 2_147_483_647,
                     "minimum": -2_147_483_648,
                     "nullable": true,
+                    "format": "int32",
                     "type": "integer"
                 },
                 {
@@ -78,6 +79,7 @@ fn test_openapi_3_0() {
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
                                 "nullable": true,
+                                "format": "int32",
                                 "type": "integer"
                             },
                             {
@@ -86,6 +88,7 @@ fn test_openapi_3_0() {
                                 "example": -2_147_483_648,
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": "integer"
                             }
                         ]
@@ -101,6 +104,7 @@ fn test_openapi_3_0() {
                             "example": -2_147_483_648,
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": "integer"
                         },
                         "y": {
@@ -110,6 +114,7 @@ fn test_openapi_3_0() {
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
                             "nullable": true,
+                            "format": "int32",
                             "type": "integer"
                         }
                     },
@@ -165,6 +170,7 @@ fn test_openapi_3_0_with_collection() {
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
                         "nullable": true,
+                        "format": "int32",
                         "type": "integer"
                     },
                     {
@@ -181,6 +187,7 @@ fn test_openapi_3_0_with_collection() {
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
                                     "nullable": true,
+                                    "format": "int32",
                                     "type": "integer"
                                 },
                                 {
@@ -189,6 +196,7 @@ fn test_openapi_3_0_with_collection() {
                                     "example": -2_147_483_648,
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": "integer"
                                 }
                             ]
@@ -204,6 +212,7 @@ fn test_openapi_3_0_with_collection() {
                                 "example": -2_147_483_648,
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": "integer"
                             },
                             "y": {
@@ -213,6 +222,7 @@ fn test_openapi_3_0_with_collection() {
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
                                 "nullable": true,
+                                "format": "int32",
                                 "type": "integer"
                             }
                         },
@@ -300,6 +310,7 @@ fn test_openapi_3_1() {
                     ],
                     "maximum": 2_147_483_647,
                     "minimum": -2_147_483_648,
+                    "format": "int32",
                     "type": [
                         "integer",
                         "null"
@@ -324,6 +335,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": [
                                 "integer",
                                 "null"
@@ -341,6 +353,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": "integer"
                         }
                     ]
@@ -361,6 +374,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": "integer"
                         },
                         "y": {
@@ -376,6 +390,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": [
                                 "integer",
                                 "null"
@@ -486,6 +501,7 @@ fn test_openapi_3_1_with_collection() {
                         ],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": [
                             "integer",
                             "null"
@@ -510,6 +526,7 @@ fn test_openapi_3_1_with_collection() {
                                 ],
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": [
                                     "integer",
                                     "null"
@@ -527,6 +544,7 @@ fn test_openapi_3_1_with_collection() {
                                 ],
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": "integer"
                             }
                         ]
@@ -547,6 +565,7 @@ fn test_openapi_3_1_with_collection() {
                                 ],
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": "integer"
                             },
                             "y": {
@@ -562,6 +581,7 @@ fn test_openapi_3_1_with_collection() {
                                 ],
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": [
                                     "integer",
                                     "null"
@@ -65,6 +65,7 @@ fn test_openapi_3_0() {
                     "maximum": 2_147_483_647,
                     "minimum": -2_147_483_648,
                     "nullable": true,
+                    "format": "int32",
                     "type": "integer",
                      "deprecated": true,
                 },
@@ -81,6 +82,7 @@ fn test_openapi_3_0() {
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
                                 "nullable": true,
+                                "format": "int32",
                                 "type": "integer"
                             },
                             {
@@ -88,6 +90,7 @@ fn test_openapi_3_0() {
                                 "example": -2_147_483_648,
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": "integer"
                             }
                         ]
@@ -103,6 +106,7 @@ fn test_openapi_3_0() {
                             "example": -2_147_483_648,
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": "integer"
                         },
                         "y": {
@@ -111,6 +115,7 @@ fn test_openapi_3_0() {
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
                             "nullable": true,
+                            "format": "int32",
                             "type": "integer"
                         }
                     },
@@ -168,6 +173,7 @@ fn test_openapi_3_0_with_collection() {
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
                         "nullable": true,
+                        "format": "int32",
                         "type": "integer",
                         "deprecated": true,
                     },
@@ -184,6 +190,7 @@ fn test_openapi_3_0_with_collection() {
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
                                     "nullable": true,
+                                    "format": "int32",
                                     "type": "integer"
                                 },
                                 {
@@ -191,6 +198,7 @@ fn test_openapi_3_0_with_collection() {
                                     "example": -2_147_483_648,
                                     "maximum": 2_147_483_647,
                                     "minimum": -2_147_483_648,
+                                    "format": "int32",
                                     "type": "integer"
                                 }
                             ]
@@ -206,6 +214,7 @@ fn test_openapi_3_0_with_collection() {
                                 "example": -2_147_483_648,
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": "integer"
                             },
                             "y": {
@@ -214,6 +223,7 @@ fn test_openapi_3_0_with_collection() {
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
                                 "nullable": true,
+                                "format": "int32",
                                 "type": "integer"
                             }
                         },
@@ -303,6 +313,7 @@ fn test_openapi_3_1() {
                     ],
                     "maximum": 2_147_483_647,
                     "minimum": -2_147_483_648,
+                    "format": "int32",
                     "type": [
                         "integer",
                         "null"
@@ -327,6 +338,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": [
                                 "integer",
                                 "null"
@@ -343,6 +355,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": "integer"
                         }
                     ],
@@ -363,6 +376,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": "integer"
                         },
                         "y": {
@@ -377,6 +391,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": [
                                 "integer",
                                 "null"
@@ -489,6 +504,7 @@ fn test_openapi_3_1_with_collection() {
                         ],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": [
                             "integer",
                             "null"
@@ -513,6 +529,7 @@ fn test_openapi_3_1_with_collection() {
                                 ],
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": [
                                     "integer",
                                     "null"
@@ -529,6 +546,7 @@ fn test_openapi_3_1_with_collection() {
                                 ],
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": "integer"
                             }
                         ],
@@ -549,6 +567,7 @@ fn test_openapi_3_1_with_collection() {
                                 ],
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": "integer"
                             },
                             "y": {
@@ -563,6 +582,7 @@ fn test_openapi_3_1_with_collection() {
                                 ],
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": [
                                     "integer",
                                     "null"
@@ -58,6 +58,7 @@ fn test_openapi_3_0() {
                     "maximum": 2_147_483_647,
                     "minimum": -2_147_483_648,
                     "nullable": true,
+                    "format": "int32",
                     "type": "integer"
                 },
                 {
@@ -73,6 +74,7 @@ fn test_openapi_3_0() {
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
                                 "nullable": true,
+                                "format": "int32",
                                 "type": "integer"
                             },
                             {
@@ -80,6 +82,7 @@ fn test_openapi_3_0() {
                                 "example": -2_147_483_648,
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": "integer"
                             }
                         ]
@@ -94,6 +97,7 @@ fn test_openapi_3_0() {
                             "example": -2_147_483_648,
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": "integer"
                         },
                         "y": {
@@ -102,6 +106,7 @@ fn test_openapi_3_0() {
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
                             "nullable": true,
+                            "format": "int32",
                             "type": "integer"
                         }
                     },
@@ -149,6 +154,7 @@ fn test_openapi_3_0_with_collection() {
                     "maximum": 2_147_483_647,
                     "minimum": -2_147_483_648,
                     "nullable": true,
+                    "format": "int32",
                     "type": "integer"
                 },
                 {
@@ -164,6 +170,7 @@ fn test_openapi_3_0_with_collection() {
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
                                 "nullable": true,
+                                "format": "int32",
                                 "type": "integer"
                             },
                             {
@@ -171,6 +178,7 @@ fn test_openapi_3_0_with_collection() {
                                 "example": -2_147_483_648,
                                 "maximum": 2_147_483_647,
                                 "minimum": -2_147_483_648,
+                                "format": "int32",
                                 "type": "integer"
                             }
                         ]
@@ -185,6 +193,7 @@ fn test_openapi_3_0_with_collection() {
                             "example": -2_147_483_648,
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": "integer"
                         },
                         "y": {
@@ -193,6 +202,7 @@ fn test_openapi_3_0_with_collection() {
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
                             "nullable": true,
+                            "format": "int32",
                             "type": "integer"
                         }
                     },
@@ -284,6 +294,7 @@ fn test_openapi_3_1() {
                     ],
                     "maximum": 2_147_483_647,
                     "minimum": -2_147_483_648,
+                    "format": "int32",
                     "type": [
                         "integer",
                         "null"
@@ -307,6 +318,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": [
                                 "integer",
                                 "null"
@@ -323,6 +335,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": "integer"
                         }
                     ]
@@ -342,6 +355,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": "integer"
                         },
                         "y": {
@@ -356,6 +370,7 @@ fn test_openapi_3_1() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": [
                                 "integer",
                                 "null"
@@ -458,6 +473,7 @@ fn test_openapi_3_1_with_collection() {
                     ],
                     "maximum": 2_147_483_647,
                     "minimum": -2_147_483_648,
+                    "format": "int32",
                     "type": [
                         "integer",
                         "null"
@@ -481,6 +497,7 @@ fn test_openapi_3_1_with_collection() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": [
                                 "integer",
                                 "null"
@@ -497,6 +514,7 @@ fn test_openapi_3_1_with_collection() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": "integer"
                         }
                     ]
@@ -516,6 +534,7 @@ fn test_openapi_3_1_with_collection() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": "integer"
                         },
                         "y": {
@@ -530,6 +549,7 @@ fn test_openapi_3_1_with_collection() {
                             ],
                             "maximum": 2_147_483_647,
                             "minimum": -2_147_483_648,
+                            "format": "int32",
                             "type": [
                                 "integer",
                                 "null"
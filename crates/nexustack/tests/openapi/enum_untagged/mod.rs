@@ -16,3 +16,4 @@ mod enum_untagged_rename_field;
 mod enum_untagged_rename_variant;
 mod enum_untagged_skip_serializing_if;
 mod enum_untagged_skip_variant;
+mod enum_untagged_struct_variant;
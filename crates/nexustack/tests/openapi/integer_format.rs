@@ -0,0 +1,39 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::openapi::{
+    Schema,
+    json::{Specification, build_schema},
+};
+
+fn format<T: Schema>(specification: Specification) -> serde_json::Value {
+    serde_json::to_value(build_schema::<T>(specification).unwrap()).unwrap()["format"].clone()
+}
+
+#[test]
+fn test_32_bit_integers_default_to_int32() {
+    for specification in [Specification::OpenAPI3_0, Specification::OpenAPI3_1] {
+        pretty_assertions::assert_eq!(format::<i32>(specification), serde_json::json!("int32"));
+        pretty_assertions::assert_eq!(format::<u32>(specification), serde_json::json!("int32"));
+    }
+}
+
+#[test]
+fn test_64_bit_integers_default_to_int64() {
+    for specification in [Specification::OpenAPI3_0, Specification::OpenAPI3_1] {
+        pretty_assertions::assert_eq!(format::<i64>(specification), serde_json::json!("int64"));
+        pretty_assertions::assert_eq!(format::<u64>(specification), serde_json::json!("int64"));
+    }
+}
+
+#[test]
+fn test_smaller_integers_have_no_format() {
+    for specification in [Specification::OpenAPI3_0, Specification::OpenAPI3_1] {
+        pretty_assertions::assert_eq!(format::<i8>(specification), serde_json::Value::Null);
+        pretty_assertions::assert_eq!(format::<u16>(specification), serde_json::Value::Null);
+    }
+}
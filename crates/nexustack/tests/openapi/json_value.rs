@@ -0,0 +1,98 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use crate::openapi::validation::is_valid;
+use nexustack::openapi::api_schema;
+use serde_json::{Map, Value, json};
+
+/// A webhook event
+#[api_schema]
+pub struct Event {
+    /// The payload as received
+    payload: Value,
+    /// Arbitrary metadata
+    metadata: Map<String, Value>,
+}
+
+#[test]
+fn test_openapi_3_1() {
+    use nexustack::openapi::json::{Specification, build_schema};
+    let schema = build_schema::<Event>(Specification::OpenAPI3_1).unwrap();
+
+    pretty_assertions::assert_eq!(
+        serde_json::to_value(schema).unwrap(),
+        json!({
+            "description": "A webhook event",
+            "examples": [
+                { "metadata": {}, "payload": { "key": "value" } },
+                {
+                    "metadata": { "name": "Ferris", "tags": ["crab", "rust"] },
+                    "payload": [1, 2, 3]
+                }
+            ],
+            "type": "object",
+            "required": ["metadata", "payload"],
+            "properties": {
+                "payload": {
+                    "description": "The payload as received",
+                    "examples": [{ "key": "value" }, [1, 2, 3], "text", 42, true]
+                },
+                "metadata": {
+                    "description": "Arbitrary metadata",
+                    "type": "object",
+                    "examples": [{}, { "name": "Ferris", "tags": ["crab", "rust"] }],
+                    "additionalProperties": {
+                        "examples": [{ "key": "value" }, [1, 2, 3], "text", 42, true]
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_optional_value_is_nullable_in_openapi_3_0() {
+    use nexustack::openapi::json::{Specification, build_schema};
+    let schema = build_schema::<Option<Value>>(Specification::OpenAPI3_0).unwrap();
+
+    pretty_assertions::assert_eq!(
+        serde_json::to_value(schema).unwrap(),
+        json!({
+            "example": { "key": "value" },
+            "nullable": true
+        })
+    );
+}
+
+#[test]
+fn test_value_accepts_arbitrary_json() {
+    use nexustack::openapi::json::{Specification, build_schema};
+    let schema =
+        serde_json::to_value(build_schema::<Event>(Specification::OpenAPI3_1).unwrap()).unwrap();
+
+    for payload in [
+        json!(null),
+        json!(false),
+        json!(-1.5),
+        json!("text"),
+        json!([null, { "nested": [1, "two"] }]),
+        json!({ "deeply": { "nested": { "object": [] } } }),
+    ] {
+        assert!(
+            is_valid(
+                &schema,
+                &json!({ "payload": payload, "metadata": { "source": payload } })
+            ),
+            "expected {payload} to be accepted"
+        );
+    }
+
+    assert!(!is_valid(
+        &schema,
+        &json!({ "payload": 1, "metadata": [1, 2, 3] })
+    ));
+}
@@ -0,0 +1,253 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::openapi::{
+    api_schema,
+    json::{SchemaCollection, Specification, build_bundled_schema, build_schema_with_collection},
+};
+use serde_json::{Value, json};
+use std::{cell::RefCell, rc::Rc};
+
+/// The `OpenAPI` 3.1 meta-schema as published by the `OpenAPI` Initiative under the Apache 2.0 license, see
+/// <https://spec.openapis.org/oas/3.1/schema/2022-10-07>.
+const OAS_3_1_SCHEMA: &str = include_str!("schemas/oas-3.1.json");
+
+const OAS_3_1_SCHEMA_ID: &str = "https://spec.openapis.org/oas/3.1/schema/2022-10-07";
+
+/// An error found while validating a document against a meta-schema.
+#[derive(Debug)]
+pub struct MetaSchemaError {
+    /// The JSON pointer to the invalid value within the document.
+    pub instance_path: String,
+    /// The description of the violated constraint.
+    pub message: String,
+}
+
+/// Validates an `OpenAPI` 3.1 document against the bundled `OpenAPI` 3.1 meta-schema.
+///
+/// The meta-schema leaves Schema Objects unconstrained, so these are validated against the JSON Schema Draft 2020-12
+/// meta-schema in addition, like the `schema-base` variant of the meta-schema does for the `OpenAPI` dialect.
+pub fn validate_document(document: &Value) -> Result<(), Vec<MetaSchemaError>> {
+    let oas_schema = serde_json::from_str(OAS_3_1_SCHEMA).unwrap();
+    let schema = json!({
+        "$id": "https://github.com/1ean267/nexustack/tests/oas-3.1-schema-base",
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$ref": OAS_3_1_SCHEMA_ID,
+        "$defs": {
+            "schema": {
+                "$dynamicAnchor": "meta",
+                "$ref": "https://json-schema.org/draft/2020-12/schema"
+            }
+        }
+    });
+
+    let validator = jsonschema::options()
+        .with_resource(
+            OAS_3_1_SCHEMA_ID,
+            jsonschema::Resource::from_contents(oas_schema),
+        )
+        .build(&schema)
+        .unwrap();
+
+    collect_errors(validator.iter_errors(document))
+}
+
+/// Validates a JSON Schema document, e.g. a bundled schema, against the JSON Schema Draft 2020-12 meta-schema.
+pub fn validate_schema(schema: &Value) -> Result<(), Vec<MetaSchemaError>> {
+    let validator = jsonschema::draft202012::new(&json!({
+        "$ref": "https://json-schema.org/draft/2020-12/schema"
+    }))
+    .unwrap();
+
+    collect_errors(validator.iter_errors(schema))
+}
+
+fn collect_errors<'a>(
+    errors: impl Iterator<Item = jsonschema::ValidationError<'a>>,
+) -> Result<(), Vec<MetaSchemaError>> {
+    let errors = errors
+        .map(|error| MetaSchemaError {
+            instance_path: error.instance_path().to_string(),
+            message: error.to_string(),
+        })
+        .collect::<Vec<_>>();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// A test struct
+#[api_schema]
+pub struct Point {
+    /// Field x
+    x: i32,
+    /// Field y
+    y: i32,
+}
+
+/// A test tuple struct
+#[api_schema]
+pub struct Range(
+    /// The start
+    Option<Point>,
+    /// The end
+    Option<Point>,
+);
+
+/// A test enum
+#[api_schema]
+pub enum Direction {
+    /// Variant North
+    North,
+    /// Variant South
+    South,
+}
+
+/// A test enum
+#[api_schema(tag = "type")]
+pub enum Shape {
+    /// Variant Circle
+    Circle {
+        /// Field center
+        center: Point,
+        /// Field radius
+        radius: Option<u32>,
+    },
+    /// Variant Line
+    Line {
+        /// Field range
+        range: Range,
+    },
+}
+
+/// A test enum
+#[api_schema(tag = "type", content = "content")]
+pub enum Command {
+    /// Variant Move
+    Move(
+        /// The direction to move in
+        Option<Direction>,
+    ),
+    /// Variant Draw
+    Draw(
+        /// The shape to draw
+        Shape,
+    ),
+}
+
+/// A test enum
+#[api_schema(untagged)]
+pub enum Input {
+    /// Variant Command
+    Command(
+        /// The command
+        Command,
+    ),
+    /// Variant Commands
+    Commands(
+        /// The commands
+        Vec<Command>,
+    ),
+}
+
+/// A test struct
+#[api_schema]
+pub struct Drawing {
+    /// Field title
+    title: Option<String>,
+    /// Field origin
+    origin: (i32, i32),
+    /// Field inputs
+    inputs: Vec<Input>,
+    /// Field direction
+    direction: Option<Direction>,
+}
+
+fn build_document() -> Value {
+    let schema_collection = Rc::new(RefCell::new(SchemaCollection::new()));
+    let schema = build_schema_with_collection::<Drawing>(
+        Specification::OpenAPI3_1,
+        schema_collection.clone(),
+    )
+    .unwrap();
+    let schemas = schema_collection.take().to_schemas_object();
+
+    json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": "Drawings",
+            "version": "1.0.0"
+        },
+        "paths": {
+            "/drawings": {
+                "post": {
+                    "requestBody": {
+                        "content": {
+                            "application/json": {
+                                "schema": schema
+                            }
+                        }
+                    },
+                    "responses": {
+                        "204": {
+                            "description": "The drawing was saved"
+                        }
+                    }
+                }
+            }
+        },
+        "components": {
+            "schemas": schemas
+        }
+    })
+}
+
+#[test]
+fn test_generated_document_is_valid() {
+    let document = build_document();
+
+    if let Err(errors) = validate_document(&document) {
+        panic!("generated document is invalid: {errors:#?}");
+    }
+}
+
+#[test]
+fn test_bundled_schema_is_valid() {
+    let schema = serde_json::to_value(build_bundled_schema::<Drawing>().unwrap()).unwrap();
+
+    if let Err(errors) = validate_schema(&schema) {
+        panic!("bundled schema is invalid: {errors:#?}");
+    }
+}
+
+#[test]
+fn test_invalid_document_reports_paths() {
+    let mut document = build_document();
+    document["info"].as_object_mut().unwrap().remove("version");
+    document["components"]["schemas"]["Point"]["required"] = json!("x");
+
+    let errors = validate_document(&document).unwrap_err();
+    let mut instance_paths = errors
+        .iter()
+        .map(|error| error.instance_path.as_str())
+        .collect::<Vec<_>>();
+    instance_paths.sort_unstable();
+    instance_paths.dedup();
+
+    pretty_assertions::assert_eq!(
+        instance_paths,
+        ["/components/schemas/Point/required", "/info"]
+    );
+    assert!(
+        errors
+            .iter()
+            .any(|error| error.instance_path == "/info" && error.message.contains("version"))
+    );
+}
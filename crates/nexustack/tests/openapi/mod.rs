@@ -5,11 +5,21 @@
  * Licensed under the MIT license. See LICENSE file in the project root for details.
  */
 
+mod bounded_string;
+mod bounded_vec;
+mod bundle;
 mod enum_adjacently_tagged;
 mod enum_externally_tagged;
 mod enum_internally_tagged;
 mod enum_untagged;
+mod integer_format;
+mod json_value;
+mod meta_schema;
 mod newtype_struct;
+mod pointer;
+mod schema_collection;
 mod r#struct;
+mod transform;
 mod tuple_struct;
 mod unit_struct;
+mod validation;
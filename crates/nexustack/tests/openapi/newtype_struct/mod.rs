@@ -8,3 +8,4 @@
 mod newtype_struct_basic;
 mod newtype_struct_rename;
 mod newtype_struct_transparent;
+mod newtype_struct_transparent_inner;
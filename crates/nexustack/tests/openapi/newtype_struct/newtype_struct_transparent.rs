@@ -27,6 +27,7 @@ fn test_openapi_3_0() {
             "maximum": 2_147_483_647,
             "minimum": -2_147_483_648,
             "nullable": true,
+            "format": "int32",
             "type": "integer"
         })
     );
@@ -55,6 +56,7 @@ fn test_openapi_3_0_with_collection() {
             "maximum": 2_147_483_647,
             "minimum": -2_147_483_648,
             "nullable": true,
+            "format": "int32",
             "type": "integer"
         })
     );
@@ -77,6 +79,7 @@ fn test_openapi_3_1() {
             "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647, null],
             "maximum": 2_147_483_647,
             "minimum": -2_147_483_648,
+            "format": "int32",
             "type": ["integer", "null"]
         })
     );
@@ -104,6 +107,7 @@ fn test_openapi_3_1_with_collection() {
             "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647, null],
             "maximum": 2_147_483_647,
             "minimum": -2_147_483_648,
+            "format": "int32",
             "type": ["integer", "null"]
         })
     );
@@ -0,0 +1,145 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use crate::openapi::validation::is_valid;
+use nexustack::openapi::{
+    Schema, api_schema,
+    json::{SchemaCollection, Specification, build_schema, build_schema_with_collection},
+};
+use std::{cell::RefCell, rc::Rc};
+
+/// A point
+#[api_schema]
+pub struct Point {
+    /// The x coordinate
+    x: i32,
+    /// The y coordinate
+    y: i32,
+}
+
+/// A point that is serialized as the point itself
+#[api_schema(transparent)]
+pub struct Location(
+    /// The point
+    Point,
+);
+
+/// A percentage that is serialized as the number itself
+#[api_schema(transparent)]
+pub struct Percentage(
+    /// The value
+    u8,
+);
+
+fn build_with_collection<T: Schema>(
+    specification: Specification,
+) -> (serde_json::Value, serde_json::Value) {
+    let schema_collection = Rc::new(RefCell::new(SchemaCollection::new()));
+    let schema =
+        build_schema_with_collection::<T>(specification, schema_collection.clone()).unwrap();
+
+    let schemas_object = Rc::try_unwrap(schema_collection)
+        .map_err(|_| "Should be the only Rc strong reference")
+        .unwrap()
+        .into_inner()
+        .to_schemas_object();
+
+    (
+        serde_json::to_value(schema).unwrap(),
+        serde_json::to_value(schemas_object).unwrap(),
+    )
+}
+
+#[test]
+fn test_struct_schema_is_inner_schema() {
+    for specification in [Specification::OpenAPI3_0, Specification::OpenAPI3_1] {
+        pretty_assertions::assert_eq!(
+            serde_json::to_value(build_schema::<Location>(specification).unwrap()).unwrap(),
+            serde_json::to_value(build_schema::<Point>(specification).unwrap()).unwrap(),
+        );
+    }
+}
+
+#[test]
+fn test_primitive_schema_is_inner_schema() {
+    for specification in [Specification::OpenAPI3_0, Specification::OpenAPI3_1] {
+        pretty_assertions::assert_eq!(
+            serde_json::to_value(build_schema::<Percentage>(specification).unwrap()).unwrap(),
+            serde_json::to_value(build_schema::<u8>(specification).unwrap()).unwrap(),
+        );
+    }
+}
+
+#[test]
+fn test_struct_with_collection_references_inner_schema() {
+    for specification in [Specification::OpenAPI3_0, Specification::OpenAPI3_1] {
+        let (location_schema, location_schemas_object) =
+            build_with_collection::<Location>(specification);
+        let (point_schema, point_schemas_object) = build_with_collection::<Point>(specification);
+
+        pretty_assertions::assert_eq!(location_schema, point_schema);
+        pretty_assertions::assert_eq!(location_schemas_object, point_schemas_object);
+        pretty_assertions::assert_eq!(
+            location_schemas_object
+                .as_object()
+                .unwrap()
+                .keys()
+                .collect::<Vec<_>>(),
+            vec!["Point"]
+        );
+    }
+}
+
+#[test]
+fn test_primitive_with_collection_is_inlined() {
+    for specification in [Specification::OpenAPI3_0, Specification::OpenAPI3_1] {
+        let (schema, schemas_object) = build_with_collection::<Percentage>(specification);
+
+        pretty_assertions::assert_eq!(
+            schema,
+            serde_json::to_value(build_schema::<u8>(specification).unwrap()).unwrap()
+        );
+        pretty_assertions::assert_eq!(schemas_object, serde_json::json!({}));
+    }
+}
+
+#[test]
+fn test_payloads_match_schema() {
+    for specification in [Specification::OpenAPI3_0, Specification::OpenAPI3_1] {
+        let location_schema =
+            serde_json::to_value(build_schema::<Location>(specification).unwrap()).unwrap();
+        let percentage_schema =
+            serde_json::to_value(build_schema::<Percentage>(specification).unwrap()).unwrap();
+
+        let location = serde_json::to_value(Location(Point { x: 1, y: -2 })).unwrap();
+        let percentage = serde_json::to_value(Percentage(42)).unwrap();
+
+        pretty_assertions::assert_eq!(location, serde_json::json!({ "x": 1, "y": -2 }));
+        pretty_assertions::assert_eq!(percentage, serde_json::json!(42));
+
+        assert!(is_valid(&location_schema, &location));
+        assert!(is_valid(&percentage_schema, &percentage));
+
+        for payload in [
+            serde_json::json!({ "0": { "x": 1, "y": -2 } }),
+            serde_json::json!([{ "x": 1, "y": -2 }]),
+            serde_json::json!({ "x": 1 }),
+        ] {
+            assert!(
+                !is_valid(&location_schema, &payload),
+                "{payload} unexpectedly matches the schema {location_schema}"
+            );
+        }
+
+        for payload in [serde_json::json!([42]), serde_json::json!(256)] {
+            assert!(
+                !is_valid(&percentage_schema, &payload),
+                "{payload} unexpectedly matches the schema {percentage_schema}"
+            );
+        }
+    }
+}
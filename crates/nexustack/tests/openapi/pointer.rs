@@ -0,0 +1,90 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::openapi::{
+    Schema, api_schema,
+    json::{SchemaCollection, Specification, build_schema, build_schema_with_collection},
+};
+use std::{borrow::Cow, cell::RefCell, rc::Rc, sync::Arc};
+
+/// A test struct
+#[api_schema]
+#[derive(Clone)]
+pub struct Point {
+    /// Field x
+    x: i32,
+    /// Field y
+    y: Option<i32>,
+}
+
+fn schema<T: Schema>(specification: Specification) -> serde_json::Value {
+    serde_json::to_value(build_schema::<T>(specification).unwrap()).unwrap()
+}
+
+fn schema_with_collection<T: Schema>(
+    specification: Specification,
+) -> (serde_json::Value, serde_json::Value) {
+    let schema_collection = Rc::new(RefCell::new(SchemaCollection::new()));
+    let schema =
+        build_schema_with_collection::<T>(specification, schema_collection.clone()).unwrap();
+
+    let schemas_object = Rc::try_unwrap(schema_collection)
+        .map_err(|_| "Should be the only Rc strong reference")
+        .unwrap()
+        .into_inner()
+        .to_schemas_object();
+
+    (
+        serde_json::to_value(schema).unwrap(),
+        serde_json::to_value(schemas_object).unwrap(),
+    )
+}
+
+#[test]
+fn test_pointers_describe_inner_schema() {
+    for specification in [Specification::OpenAPI3_0, Specification::OpenAPI3_1] {
+        let expected = schema::<Point>(specification);
+
+        pretty_assertions::assert_eq!(schema::<Box<Point>>(specification), expected);
+        pretty_assertions::assert_eq!(schema::<Rc<Point>>(specification), expected);
+        pretty_assertions::assert_eq!(schema::<Arc<Point>>(specification), expected);
+        pretty_assertions::assert_eq!(schema::<Cow<'static, Point>>(specification), expected);
+    }
+}
+
+#[test]
+fn test_pointers_describe_inner_schema_with_collection() {
+    for specification in [Specification::OpenAPI3_0, Specification::OpenAPI3_1] {
+        let expected = schema_with_collection::<Point>(specification);
+
+        pretty_assertions::assert_eq!(
+            schema_with_collection::<Box<Point>>(specification),
+            expected
+        );
+        pretty_assertions::assert_eq!(schema_with_collection::<Rc<Point>>(specification), expected);
+        pretty_assertions::assert_eq!(
+            schema_with_collection::<Arc<Point>>(specification),
+            expected
+        );
+        pretty_assertions::assert_eq!(
+            schema_with_collection::<Cow<'static, Point>>(specification),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_pointers_describe_inner_unsized_schema() {
+    for specification in [Specification::OpenAPI3_0, Specification::OpenAPI3_1] {
+        let expected = schema::<String>(specification);
+
+        pretty_assertions::assert_eq!(schema::<Box<str>>(specification), expected);
+        pretty_assertions::assert_eq!(schema::<Rc<str>>(specification), expected);
+        pretty_assertions::assert_eq!(schema::<Arc<str>>(specification), expected);
+        pretty_assertions::assert_eq!(schema::<Cow<'static, str>>(specification), expected);
+    }
+}
@@ -0,0 +1,110 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::openapi::{
+    api_schema,
+    json::{SchemaCollection, Specification, build_schema_with_collection},
+};
+use std::{cell::RefCell, rc::Rc};
+
+/// A test struct
+#[api_schema]
+pub struct Inner {
+    /// Field x
+    x: i32,
+}
+
+/// A test struct
+#[api_schema]
+pub struct Outer {
+    /// Field inner
+    inner: Inner,
+}
+
+fn build_with_ref_base(
+    ref_base: impl Into<std::borrow::Cow<'static, str>>,
+) -> (serde_json::Value, serde_json::Value) {
+    let schema_collection = Rc::new(RefCell::new(
+        SchemaCollection::new().with_ref_base(ref_base),
+    ));
+    let schema =
+        build_schema_with_collection::<Outer>(Specification::OpenAPI3_1, schema_collection.clone())
+            .unwrap();
+
+    let schemas_object = Rc::try_unwrap(schema_collection)
+        .map_err(|_| "Should be the only Rc strong reference")
+        .unwrap()
+        .into_inner()
+        .to_schemas_object();
+
+    (
+        serde_json::to_value(schema).unwrap(),
+        serde_json::to_value(schemas_object).unwrap(),
+    )
+}
+
+#[test]
+fn test_with_ref_base() {
+    let (schema, schemas_object) = build_with_ref_base("#/$defs");
+
+    pretty_assertions::assert_eq!(schema, serde_json::json!({ "$ref": "#/$defs/Outer" }));
+    pretty_assertions::assert_eq!(
+        schemas_object["Outer"]["properties"]["inner"],
+        serde_json::json!({ "$ref": "#/$defs/Inner" })
+    );
+    assert!(schemas_object.get("Inner").is_some());
+}
+
+#[test]
+fn test_with_external_ref_base() {
+    let (schema, schemas_object) = build_with_ref_base("schemas.json#/definitions/".to_owned());
+
+    pretty_assertions::assert_eq!(
+        schema,
+        serde_json::json!({ "$ref": "schemas.json#/definitions/Outer" })
+    );
+    pretty_assertions::assert_eq!(
+        schemas_object["Outer"]["properties"]["inner"],
+        serde_json::json!({ "$ref": "schemas.json#/definitions/Inner" })
+    );
+}
+
+/// A test struct
+#[api_schema]
+pub struct Document {
+    /// Field outer
+    outer: Outer,
+}
+
+fn build_schemas_object_json() -> String {
+    let schema_collection = Rc::new(RefCell::new(SchemaCollection::new()));
+    build_schema_with_collection::<Document>(Specification::OpenAPI3_1, schema_collection.clone())
+        .unwrap();
+
+    let schemas_object = Rc::try_unwrap(schema_collection)
+        .map_err(|_| "Should be the only Rc strong reference")
+        .unwrap()
+        .into_inner()
+        .to_schemas_object();
+
+    serde_json::to_string(&schemas_object).unwrap()
+}
+
+#[test]
+fn test_schemas_object_is_deterministic() {
+    let json = build_schemas_object_json();
+
+    for _ in 0..16 {
+        pretty_assertions::assert_eq!(json, build_schemas_object_json());
+    }
+
+    let document = json.find("\"Document\"").unwrap();
+    let inner = json.find("\"Inner\"").unwrap();
+    let outer = json.find("\"Outer\"").unwrap();
+
+    assert!(document < inner && inner < outer, "{json}");
+}
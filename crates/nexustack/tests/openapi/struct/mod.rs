@@ -7,13 +7,27 @@
 
 mod struct_additional_properties;
 mod struct_basic;
+#[cfg(feature = "decimal")]
+mod struct_decimal;
+mod struct_deny_unknown_fields;
 mod struct_deprecated;
 mod struct_deprecated_field;
+mod struct_description_i18n;
+mod struct_example;
+mod struct_example_error;
+mod struct_example_named;
+mod struct_field_example;
 mod struct_flatten;
 mod struct_generic;
+mod struct_inline;
+mod struct_map;
 mod struct_pattern_properties;
+mod struct_property_order;
+mod struct_read_only;
 mod struct_rename;
+mod struct_schema_with;
 mod struct_skip;
+mod struct_skip_serializing;
 mod struct_skip_serializing_if;
 mod struct_transparent;
 mod struct_with_lifetime;
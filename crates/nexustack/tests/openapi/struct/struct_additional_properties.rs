@@ -47,6 +47,7 @@ fn test_openapi_3_0() {
                     "example": -2_147_483_648,
                     "maximum": 2_147_483_647,
                     "minimum": -2_147_483_648,
+                    "format": "int32",
                     "type": "integer"
                 }
             },
@@ -106,6 +107,7 @@ fn test_openapi_3_0_with_collection() {
                         "example":  -2_147_483_648,
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer"
                     }
                 },
@@ -167,6 +169,7 @@ fn test_openapi_3_1() {
                     ],
                     "maximum": 2_147_483_647,
                     "minimum": -2_147_483_648,
+                    "format": "int32",
                     "type": "integer"
                 }
             },
@@ -247,6 +250,7 @@ fn test_openapi_3_1_with_collection() {
                         ],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer"
                     }
                 },
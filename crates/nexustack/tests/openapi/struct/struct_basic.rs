@@ -38,6 +38,7 @@ fn test_openapi_3_0() {
                     "maximum": 2_147_483_647,
                     "minimum": -2_147_483_648,
                     "nullable": true,
+                    "format": "int32",
                     "type": "integer"
                 },
                 "y": {
@@ -45,6 +46,7 @@ fn test_openapi_3_0() {
                     "example":  -2_147_483_648,
                     "maximum": 2_147_483_647,
                     "minimum": -2_147_483_648,
+                    "format": "int32",
                     "type": "integer"
                 }
             },
@@ -96,6 +98,7 @@ fn test_openapi_3_0_with_collection() {
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
                         "nullable": true,
+                        "format": "int32",
                         "type": "integer"
                     },
                     "y": {
@@ -103,6 +106,7 @@ fn test_openapi_3_0_with_collection() {
                         "example":  -2_147_483_648,
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer"
                     }
                 },
@@ -161,6 +165,7 @@ fn test_openapi_3_1() {
                     ],
                     "maximum": 2_147_483_647,
                     "minimum": -2_147_483_648,
+                    "format": "int32",
                     "type": ["integer", "null"]
                 },
                 "y": {
@@ -174,6 +179,7 @@ fn test_openapi_3_1() {
                     ],
                     "maximum": 2_147_483_647,
                     "minimum": -2_147_483_648,
+                    "format": "int32",
                     "type": "integer"
                 }
             },
@@ -249,6 +255,7 @@ fn test_openapi_3_1_with_collection() {
                         ],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": ["integer", "null"]
                     },
                     "y": {
@@ -262,6 +269,7 @@ fn test_openapi_3_1_with_collection() {
                         ],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer"
                     }
                 },
@@ -0,0 +1,57 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::openapi::api_schema;
+use rust_decimal::Decimal;
+
+/// A monetary amount
+#[api_schema]
+pub struct Price {
+    /// The amount
+    amount: Decimal,
+
+    /// The currency code
+    currency: bool,
+}
+
+#[test]
+fn test_openapi_3_0() {
+    use nexustack::openapi::json::{Specification, build_schema};
+
+    let schema = build_schema::<Price>(Specification::OpenAPI3_0).unwrap();
+
+    pretty_assertions::assert_eq!(
+        serde_json::to_value(schema).unwrap(),
+        serde_json::json!({
+            "description": "A monetary amount",
+            "example": {
+                "amount": "0",
+                "currency": true
+            },
+            "properties": {
+                "amount": {
+                    "description": "The amount",
+                    "example": "0",
+                    "format": "decimal",
+                    "minLength": 1,
+                    "pattern": "^-?\\d+(\\.\\d+)?$",
+                    "type": "string"
+                },
+                "currency": {
+                    "description": "The currency code",
+                    "example": true,
+                    "type": "boolean"
+                }
+            },
+            "required": [
+                "amount",
+                "currency"
+            ],
+            "type": "object"
+        })
+    );
+}
@@ -0,0 +1,92 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::openapi::api_schema;
+use std::collections::HashMap;
+
+/// A closed point
+#[api_schema(deny_unknown_fields)]
+pub struct Point {
+    /// The x coordinate
+    x: u8,
+}
+
+/// A closed point with flattened extra elements
+#[api_schema(deny_unknown_fields)]
+pub struct Flatten {
+    /// The x coordinate
+    x: u8,
+
+    /// The extra elements
+    #[api_property(flatten)]
+    extra: HashMap<String, bool>,
+}
+
+#[test]
+fn test_openapi_3_0() {
+    use nexustack::openapi::json::{Specification, build_schema};
+
+    let schema = build_schema::<Point>(Specification::OpenAPI3_0).unwrap();
+
+    pretty_assertions::assert_eq!(
+        serde_json::to_value(schema).unwrap(),
+        serde_json::json!({
+            "additionalProperties": false,
+            "description": "A closed point",
+            "example": {
+                "x": 0
+            },
+            "properties": {
+                "x": {
+                    "description": "The x coordinate",
+                    "example": 0,
+                    "maximum": 255,
+                    "minimum": 0,
+                    "type": "integer"
+                }
+            },
+            "required": [
+                "x"
+            ],
+            "type": "object"
+        })
+    );
+}
+
+#[test]
+fn test_flatten_openapi_3_0() {
+    use nexustack::openapi::json::{Specification, build_schema};
+
+    let schema = build_schema::<Flatten>(Specification::OpenAPI3_0).unwrap();
+
+    pretty_assertions::assert_eq!(
+        serde_json::to_value(schema).unwrap(),
+        serde_json::json!({
+            "additionalProperties": {
+                "example": true,
+                "type": "boolean"
+            },
+            "description": "A closed point with flattened extra elements",
+            "example": {
+                "x": 0
+            },
+            "properties": {
+                "x": {
+                    "description": "The x coordinate",
+                    "example": 0,
+                    "maximum": 255,
+                    "minimum": 0,
+                    "type": "integer"
+                }
+            },
+            "required": [
+                "x"
+            ],
+            "type": "object"
+        })
+    );
+}
@@ -0,0 +1,56 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::openapi::api_schema;
+
+/// A city
+#[api_schema(description_i18n(de = "Eine Stadt", pt_br = "Uma cidade"))]
+pub struct City {
+    /// The number of inhabitants
+    population: u32,
+}
+
+/// The state of a traffic light
+#[api_schema(description_i18n(de = "Der Zustand einer Ampel"))]
+pub enum TrafficLight {
+    /// Stop
+    Red,
+    /// Go
+    Green,
+}
+
+#[test]
+fn test_struct_translations() {
+    use nexustack::openapi::json::{Specification, build_schema};
+
+    let schema = build_schema::<City>(Specification::OpenAPI3_1).unwrap();
+    let schema = serde_json::to_value(schema).unwrap();
+
+    pretty_assertions::assert_eq!(schema["description"], "A city");
+    pretty_assertions::assert_eq!(
+        schema["x-translations"],
+        serde_json::json!({
+            "de": { "description": "Eine Stadt" },
+            "pt-br": { "description": "Uma cidade" }
+        })
+    );
+}
+
+#[test]
+fn test_enum_translations() {
+    use nexustack::openapi::json::{Specification, build_schema};
+
+    let schema = build_schema::<TrafficLight>(Specification::OpenAPI3_0).unwrap();
+    let schema = serde_json::to_value(schema).unwrap();
+
+    pretty_assertions::assert_eq!(
+        schema["x-translations"],
+        serde_json::json!({
+            "de": { "description": "Der Zustand einer Ampel" }
+        })
+    );
+}
@@ -0,0 +1,82 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::openapi::{Schema, SchemaBuilder, api_schema};
+use serde::{Serialize, Serializer, ser::Error as _};
+
+/// A secret that refuses to be serialized
+pub struct Secret;
+
+impl Serialize for Secret {
+    fn serialize<S: Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+        Err(S::Error::custom("secrets cannot be serialized"))
+    }
+}
+
+impl Schema for Secret {
+    type Example = Self;
+    type Examples = std::iter::Empty<Self>;
+
+    fn describe<B>(schema_builder: B) -> Result<B::Ok, B::Error>
+    where
+        B: SchemaBuilder<Self::Examples>,
+    {
+        schema_builder.describe_str(
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some("A secret"),
+            || Ok(std::iter::empty()),
+            false,
+        )
+    }
+}
+
+/// Credentials of a user
+#[api_schema(write)]
+pub struct Credentials {
+    /// The name of the user
+    user: String,
+
+    /// The password of the user
+    password: Secret,
+}
+
+/// A configuration with an example that fails to serialize
+#[api_schema(write, example = "Config::example")]
+pub struct Config {
+    /// The credentials
+    credentials: Credentials,
+}
+
+impl Config {
+    fn example() -> Self {
+        Self {
+            credentials: Credentials {
+                user: "admin".to_owned(),
+                password: Secret,
+            },
+        }
+    }
+}
+
+#[test]
+fn test_example_error_names_field_path() {
+    use nexustack::openapi::json::{Specification, build_schema};
+
+    let Err(err) = build_schema::<Config>(Specification::OpenAPI3_1) else {
+        panic!("expected the example of `Config` to fail to serialize");
+    };
+
+    pretty_assertions::assert_eq!(
+        err.to_string(),
+        "schema cannot be constructed due to an error: failed to serialize example at \
+         `credentials.password`: secrets cannot be serialized"
+    );
+}
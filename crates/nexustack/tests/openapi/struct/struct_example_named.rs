@@ -0,0 +1,86 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::openapi::api_schema;
+
+/// A point with named examples
+#[api_schema(
+    example(name = "origin", value = "Point::origin"),
+    example(name = "unit", value = "Point::unit")
+)]
+pub struct Point {
+    /// The x coordinate
+    x: i32,
+
+    /// The y coordinate
+    y: i32,
+}
+
+impl Point {
+    const fn origin() -> Self {
+        Self { x: 0, y: 0 }
+    }
+
+    const fn unit() -> Self {
+        Self { x: 1, y: 1 }
+    }
+}
+
+#[test]
+fn test_openapi_3_0() {
+    use nexustack::openapi::json::{Specification, build_schema};
+
+    let schema = build_schema::<Point>(Specification::OpenAPI3_0).unwrap();
+    let schema = serde_json::to_value(schema).unwrap();
+
+    pretty_assertions::assert_eq!(schema["example"], serde_json::json!({ "x": 0, "y": 0 }));
+    pretty_assertions::assert_eq!(schema.get("examples"), None);
+}
+
+#[test]
+fn test_openapi_3_1() {
+    use nexustack::openapi::json::{Specification, build_schema};
+
+    let schema = build_schema::<Point>(Specification::OpenAPI3_1).unwrap();
+
+    pretty_assertions::assert_eq!(
+        serde_json::to_value(schema).unwrap(),
+        serde_json::json!({
+            "description": "A point with named examples",
+            "examples": {
+                "origin": {
+                    "x": 0,
+                    "y": 0
+                },
+                "unit": {
+                    "x": 1,
+                    "y": 1
+                }
+            },
+            "properties": {
+                "x": {
+                    "description": "The x coordinate",
+                    "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
+                    "maximum": 2_147_483_647,
+                    "minimum": -2_147_483_648,
+                    "format": "int32",
+                    "type": "integer"
+                },
+                "y": {
+                    "description": "The y coordinate",
+                    "examples": [-2_147_483_648, -1, 0, 1, 2_147_483_647],
+                    "maximum": 2_147_483_647,
+                    "minimum": -2_147_483_648,
+                    "format": "int32",
+                    "type": "integer"
+                }
+            },
+            "required": ["x", "y"],
+            "type": "object"
+        })
+    );
+}
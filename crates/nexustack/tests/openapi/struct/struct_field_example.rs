@@ -0,0 +1,105 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::openapi::api_schema;
+
+/// A geographic location
+#[api_schema]
+pub struct Location {
+    /// The name of the location
+    name: String,
+}
+
+/// A city
+#[api_schema]
+pub struct City {
+    /// The number of inhabitants
+    #[api_property(example = "example_population", example = "other_population")]
+    population: u32,
+
+    /// The location of the city
+    #[api_property(example = "example_location")]
+    location: Location,
+}
+
+const fn example_population() -> u32 {
+    1_500_000
+}
+
+const fn other_population() -> u32 {
+    42
+}
+
+fn example_location() -> Location {
+    Location {
+        name: "Munich".to_owned(),
+    }
+}
+
+#[test]
+fn test_openapi_3_0() {
+    use nexustack::openapi::json::{Specification, build_schema};
+
+    let schema = build_schema::<City>(Specification::OpenAPI3_0).unwrap();
+
+    pretty_assertions::assert_eq!(
+        serde_json::to_value(schema).unwrap()["properties"]["population"],
+        serde_json::json!({
+            "description": "The number of inhabitants",
+            "example": 1_500_000,
+            "maximum": 4_294_967_295_u32,
+            "minimum": 0,
+            "format": "int32",
+            "type": "integer"
+        })
+    );
+}
+
+#[test]
+fn test_openapi_3_1() {
+    use nexustack::openapi::json::{Specification, build_schema};
+
+    let schema = build_schema::<City>(Specification::OpenAPI3_1).unwrap();
+
+    pretty_assertions::assert_eq!(
+        serde_json::to_value(schema).unwrap()["properties"]["population"],
+        serde_json::json!({
+            "description": "The number of inhabitants",
+            "examples": [1_500_000, 42],
+            "maximum": 4_294_967_295_u32,
+            "minimum": 0,
+            "format": "int32",
+            "type": "integer"
+        })
+    );
+}
+
+#[test]
+fn test_referenced_field_keeps_reference() {
+    use nexustack::openapi::json::{SchemaCollection, Specification, build_schema_with_collection};
+    use std::{cell::RefCell, rc::Rc};
+
+    let schema_collection = Rc::new(RefCell::new(SchemaCollection::new()));
+    build_schema_with_collection::<City>(Specification::OpenAPI3_1, schema_collection.clone())
+        .unwrap();
+
+    let schemas_object = Rc::try_unwrap(schema_collection)
+        .map_err(|_| "Should be the only Rc strong reference")
+        .unwrap()
+        .into_inner()
+        .to_schemas_object();
+
+    pretty_assertions::assert_eq!(
+        serde_json::to_value(schemas_object).unwrap()["City"]["properties"]["location"],
+        serde_json::json!({
+            "allOf": [
+                { "$ref": "#/components/schemas/Location" },
+                { "examples": [{ "name": "Munich" }] }
+            ]
+        })
+    );
+}
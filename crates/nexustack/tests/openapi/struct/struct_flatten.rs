@@ -49,6 +49,7 @@ fn test_openapi_3_0() {
                     "example": -2_147_483_648,
                     "maximum": 2_147_483_647,
                     "minimum": -2_147_483_648,
+                    "format": "int32",
                     "type": "integer"
                 },
                 "x": {
@@ -57,6 +58,7 @@ fn test_openapi_3_0() {
                     "maximum": 2_147_483_647,
                     "minimum": -2_147_483_648,
                     "nullable": true,
+                    "format": "int32",
                     "type": "integer"
                 },
                 "y": {
@@ -64,6 +66,7 @@ fn test_openapi_3_0() {
                     "example":  -2_147_483_648,
                     "maximum": 2_147_483_647,
                     "minimum": -2_147_483_648,
+                    "format": "int32",
                     "type": "integer"
                 }
             },
@@ -118,6 +121,7 @@ fn test_openapi_3_0_with_collection() {
                         "example": -2_147_483_648,
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer"
                     },
                     "x": {
@@ -126,6 +130,7 @@ fn test_openapi_3_0_with_collection() {
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
                         "nullable": true,
+                        "format": "int32",
                         "type": "integer"
                     },
                     "y": {
@@ -133,6 +138,7 @@ fn test_openapi_3_0_with_collection() {
                         "example": -2_147_483_648,
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer"
                     }
                 },
@@ -196,6 +202,7 @@ fn test_openapi_3_1() {
                     ],
                     "maximum": 2_147_483_647,
                     "minimum": -2_147_483_648,
+                    "format": "int32",
                     "type": "integer"
                 },
                 "x": {
@@ -210,6 +217,7 @@ fn test_openapi_3_1() {
                     ],
                     "maximum": 2_147_483_647,
                     "minimum": -2_147_483_648,
+                    "format": "int32",
                     "type": ["integer", "null"]
                 },
                 "y": {
@@ -223,6 +231,7 @@ fn test_openapi_3_1() {
                     ],
                     "maximum": 2_147_483_647,
                     "minimum": -2_147_483_648,
+                    "format": "int32",
                     "type": "integer"
                 }
             },
@@ -305,6 +314,7 @@ fn test_openapi_3_1_with_collection() {
                         ],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer"
                     },
                     "x": {
@@ -319,6 +329,7 @@ fn test_openapi_3_1_with_collection() {
                         ],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": ["integer", "null"]
                     },
                     "y": {
@@ -332,6 +343,7 @@ fn test_openapi_3_1_with_collection() {
                         ],
                         "maximum": 2_147_483_647,
                         "minimum": -2_147_483_648,
+                        "format": "int32",
                         "type": "integer"
                     }
                 },
@@ -0,0 +1,121 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::openapi::api_schema;
+
+/// A small inlined value type
+#[api_schema(inline)]
+pub struct Meters {
+    /// The value in meters
+    value: u8,
+}
+
+/// A referenced value type
+#[api_schema]
+pub struct Label {
+    /// The label text
+    text: bool,
+}
+
+/// A marker with an inlined and a referenced field
+#[api_schema]
+pub struct Marker {
+    /// The distance
+    distance: Meters,
+
+    /// The label
+    label: Label,
+}
+
+#[test]
+fn test_openapi_3_0_with_collection() {
+    use nexustack::openapi::json::{SchemaCollection, Specification, build_schema_with_collection};
+    use std::{cell::RefCell, rc::Rc};
+
+    let schema_collection = Rc::new(RefCell::new(SchemaCollection::new()));
+    let schema = build_schema_with_collection::<Marker>(
+        Specification::OpenAPI3_0,
+        schema_collection.clone(),
+    )
+    .unwrap();
+
+    let schemas_object = Rc::try_unwrap(schema_collection)
+        .map_err(|_| "Should be the only Rc strong reference")
+        .unwrap()
+        .into_inner()
+        .to_schemas_object();
+
+    pretty_assertions::assert_eq!(
+        serde_json::to_value(schema).unwrap(),
+        serde_json::json!({
+            "$ref": "#/components/schemas/Marker"
+        })
+    );
+
+    pretty_assertions::assert_eq!(
+        serde_json::to_value(schemas_object).unwrap(),
+        serde_json::json!({
+            "Marker": {
+                "description": "A marker with an inlined and a referenced field",
+                "example": {
+                    "distance": {
+                        "value": 0
+                    },
+                    "label": {
+                        "text": true
+                    }
+                },
+                "properties": {
+                    "distance": {
+                        "description": "The distance",
+                        "example": {
+                            "value": 0
+                        },
+                        "properties": {
+                            "value": {
+                                "description": "The value in meters",
+                                "example": 0,
+                                "maximum": 255,
+                                "minimum": 0,
+                                "type": "integer"
+                            }
+                        },
+                        "required": [
+                            "value"
+                        ],
+                        "type": "object"
+                    },
+                    "label": {
+                        "$ref": "#/components/schemas/Label"
+                    }
+                },
+                "required": [
+                    "distance",
+                    "label"
+                ],
+                "type": "object"
+            },
+            "Label": {
+                "description": "A referenced value type",
+                "example": {
+                    "text": true
+                },
+                "properties": {
+                    "text": {
+                        "description": "The label text",
+                        "example": true,
+                        "type": "boolean"
+                    }
+                },
+                "required": [
+                    "text"
+                ],
+                "type": "object"
+            }
+        })
+    );
+}
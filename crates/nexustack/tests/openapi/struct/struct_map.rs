@@ -0,0 +1,128 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::openapi::{
+    IntoSchemaBuilder, Schema, SchemaBuilder, api_schema,
+    json::{Specification, build_schema},
+};
+use std::{collections::BTreeMap, collections::HashMap, net::Ipv4Addr};
+
+/// A struct with an ordered map
+#[api_schema]
+pub struct Scores {
+    /// The scores by name
+    scores: BTreeMap<String, i32>,
+}
+
+fn schema<T: Schema>(specification: Specification) -> serde_json::Value {
+    serde_json::to_value(build_schema::<T>(specification).unwrap()).unwrap()
+}
+
+#[test]
+fn test_openapi_3_0() {
+    pretty_assertions::assert_eq!(
+        schema::<Scores>(Specification::OpenAPI3_0),
+        serde_json::json!({
+            "description": "A struct with an ordered map",
+            "example": { "scores": {} },
+            "type": "object",
+            "required": ["scores"],
+            "properties": {
+                "scores": {
+                    "description": "The scores by name",
+                    "example": {},
+                    "type": "object",
+                    "additionalProperties": {
+                        "example": -2_147_483_648,
+                        "maximum": 2_147_483_647,
+                        "minimum": -2_147_483_648,
+                        "format": "int32",
+                        "type": "integer"
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn test_ordered_maps_describe_hash_map_schema() {
+    for specification in [Specification::OpenAPI3_0, Specification::OpenAPI3_1] {
+        pretty_assertions::assert_eq!(
+            schema::<BTreeMap<String, i32>>(specification),
+            schema::<HashMap<String, i32>>(specification)
+        );
+        pretty_assertions::assert_eq!(
+            schema::<BTreeMap<Ipv4Addr, i32>>(specification),
+            schema::<HashMap<Ipv4Addr, i32>>(specification)
+        );
+
+        #[cfg(feature = "indexmap")]
+        {
+            use indexmap::IndexMap;
+
+            pretty_assertions::assert_eq!(
+                schema::<IndexMap<String, i32>>(specification),
+                schema::<HashMap<String, i32>>(specification)
+            );
+            pretty_assertions::assert_eq!(
+                schema::<IndexMap<Ipv4Addr, i32>>(specification),
+                schema::<HashMap<Ipv4Addr, i32>>(specification)
+            );
+        }
+    }
+}
+
+/// A value that is anything but a string
+pub struct NotString {
+    _value: i32,
+}
+
+impl Schema for NotString {
+    type Example = i32;
+    type Examples = <[Self::Example; 1] as IntoIterator>::IntoIter;
+
+    fn describe<B>(schema_builder: B) -> Result<B::Ok, B::Error>
+    where
+        B: SchemaBuilder<Self::Examples>,
+    {
+        let not_schema_builder =
+            schema_builder.describe_not(Some("Anything but a string"), || Ok([1]), false)?;
+
+        <String as Schema>::describe(not_schema_builder.into_schema_builder())
+    }
+}
+
+#[test]
+fn test_map_value_not_schema() {
+    pretty_assertions::assert_eq!(
+        schema::<HashMap<String, NotString>>(Specification::OpenAPI3_0),
+        serde_json::json!({
+            "example": {},
+            "type": "object",
+            "additionalProperties": {
+                "description": "Anything but a string",
+                "example": 1,
+                "not": {
+                    "example": "",
+                    "type": "string"
+                }
+            }
+        })
+    );
+
+    let schema = schema::<HashMap<String, NotString>>(Specification::OpenAPI3_1);
+
+    pretty_assertions::assert_eq!(
+        schema["additionalProperties"]["not"]["type"],
+        serde_json::json!("string")
+    );
+    pretty_assertions::assert_eq!(
+        schema["additionalProperties"]["description"],
+        serde_json::json!("Anything but a string")
+    );
+}
@@ -0,0 +1,87 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::openapi::{
+    api_schema,
+    json::{
+        BoxSchemaOrReferenceObject, SchemaObject, SchemaOrReferenceObject, Specification,
+        build_schema,
+    },
+};
+
+/// A struct whose fields are not declared in alphabetical order
+#[api_schema]
+pub struct Unordered {
+    /// Field zeta
+    zeta: u8,
+    /// Field alpha
+    alpha: u8,
+    /// Field mu
+    mu: u8,
+    /// Field beta
+    beta: u8,
+}
+
+/// An internally tagged enum
+#[api_schema(tag = "type")]
+pub enum Tagged {
+    /// A variant whose fields are not declared in alphabetical order
+    Variant {
+        /// Field zeta
+        zeta: u8,
+        /// Field alpha
+        alpha: u8,
+    },
+}
+
+fn property_names(schema: &SchemaObject) -> Vec<&str> {
+    schema
+        .properties
+        .iter()
+        .flat_map(|properties| properties.keys())
+        .map(AsRef::as_ref)
+        .collect()
+}
+
+#[test]
+fn test_struct_properties_follow_declaration_order() {
+    for specification in [Specification::OpenAPI3_0, Specification::OpenAPI3_1] {
+        let SchemaOrReferenceObject::Schema(schema) =
+            build_schema::<Unordered>(specification).unwrap()
+        else {
+            panic!("Expected an inline schema");
+        };
+
+        pretty_assertions::assert_eq!(property_names(&schema), vec!["zeta", "alpha", "mu", "beta"]);
+
+        let json = serde_json::to_string(&schema.properties).unwrap();
+        let positions = ["\"zeta\":", "\"alpha\":", "\"mu\":", "\"beta\":"].map(|key| {
+            json.find(key)
+                .unwrap_or_else(|| panic!("{key} missing in {json}"))
+        });
+
+        assert!(positions.is_sorted(), "{json}");
+    }
+}
+
+#[test]
+fn test_variant_properties_follow_declaration_order() {
+    for specification in [Specification::OpenAPI3_0, Specification::OpenAPI3_1] {
+        let SchemaOrReferenceObject::Schema(schema) =
+            build_schema::<Tagged>(specification).unwrap()
+        else {
+            panic!("Expected an inline schema");
+        };
+
+        let variants = schema.one_of.or(schema.any_of).unwrap_or_default();
+        let Some(BoxSchemaOrReferenceObject::Schema(variant)) = variants.first() else {
+            panic!("Expected an inline variant schema");
+        };
+
+        pretty_assertions::assert_eq!(property_names(variant), vec!["type", "zeta", "alpha"]);
+    }
+}
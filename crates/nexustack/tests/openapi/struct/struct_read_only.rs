@@ -0,0 +1,73 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::openapi::api_schema;
+
+/// A computed report
+#[api_schema(read_only)]
+pub struct Report {
+    /// The number of entries
+    entries: u8,
+}
+
+/// A password change
+#[api_schema(write_only)]
+pub struct PasswordChange {
+    /// The new password
+    password: String,
+}
+
+#[test]
+fn test_openapi_3_0() {
+    use nexustack::openapi::json::{Specification, build_schema};
+
+    let schema = build_schema::<Report>(Specification::OpenAPI3_0).unwrap();
+
+    pretty_assertions::assert_eq!(
+        serde_json::to_value(schema).unwrap(),
+        serde_json::json!({
+            "description": "A computed report",
+            "example": { "entries": 0 },
+            "readOnly": true,
+            "properties": {
+                "entries": {
+                    "description": "The number of entries",
+                    "example": 0,
+                    "maximum": 255,
+                    "minimum": 0,
+                    "type": "integer"
+                }
+            },
+            "required": ["entries"],
+            "type": "object"
+        })
+    );
+}
+
+#[test]
+fn test_openapi_3_1() {
+    use nexustack::openapi::json::{Specification, build_schema};
+
+    let schema = build_schema::<Report>(Specification::OpenAPI3_1).unwrap();
+    let schema = serde_json::to_value(schema).unwrap();
+
+    pretty_assertions::assert_eq!(schema["readOnly"], serde_json::json!(true));
+    pretty_assertions::assert_eq!(schema.get("writeOnly"), None);
+}
+
+#[test]
+fn test_write_only() {
+    use nexustack::openapi::json::{Specification, build_schema};
+
+    for specification in [Specification::OpenAPI3_0, Specification::OpenAPI3_1] {
+        let schema = build_schema::<PasswordChange>(specification).unwrap();
+        let schema = serde_json::to_value(schema).unwrap();
+
+        pretty_assertions::assert_eq!(schema["writeOnly"], serde_json::json!(true));
+        pretty_assertions::assert_eq!(schema.get("readOnly"), None);
+    }
+}
@@ -0,0 +1,162 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::openapi::{SchemaBuilder, api_schema};
+
+/// An order
+#[api_schema]
+pub struct Order {
+    /// The order id, which is serialized as a string of digits
+    #[api_property(with = "id_as_string", schema_with = "describe_id")]
+    id: u64,
+    /// The quantity
+    quantity: u8,
+}
+
+mod id_as_string {
+    use serde::{Deserialize, Deserializer, Serializer, de::Error as _};
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub fn serialize<S: Serializer>(id: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(id)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+fn describe_id<B>(schema_builder: B) -> Result<B::Ok, B::Error>
+where
+    B: SchemaBuilder<std::iter::Once<&'static str>>,
+{
+    schema_builder.describe_str(
+        Some(1),
+        None,
+        Some("^[0-9]+$"),
+        None,
+        None,
+        None,
+        || Ok(std::iter::once("42")),
+        false,
+    )
+}
+
+#[test]
+fn test_serialize() {
+    let order = Order {
+        id: 42,
+        quantity: 3,
+    };
+
+    pretty_assertions::assert_eq!(
+        serde_json::to_value(&order).unwrap(),
+        serde_json::json!({ "id": "42", "quantity": 3 })
+    );
+}
+
+#[test]
+fn test_openapi_3_0() {
+    use nexustack::openapi::json::{Specification, build_schema};
+
+    let schema = build_schema::<Order>(Specification::OpenAPI3_0).unwrap();
+
+    pretty_assertions::assert_eq!(
+        serde_json::to_value(schema).unwrap(),
+        serde_json::json!({
+            "description": "An order",
+            "example": { "id": "42", "quantity": 0 },
+            "properties": {
+                "id": {
+                    "description": "The order id, which is serialized as a string of digits",
+                    "example": "42",
+                    "minLength": 1,
+                    "pattern": "^[0-9]+$",
+                    "type": "string"
+                },
+                "quantity": {
+                    "description": "The quantity",
+                    "example": 0,
+                    "maximum": 255,
+                    "minimum": 0,
+                    "type": "integer"
+                }
+            },
+            "required": ["id", "quantity"],
+            "type": "object"
+        })
+    );
+}
+
+/// A token whose example fails to serialize
+#[api_schema]
+pub struct Session {
+    /// The token of the session
+    #[api_property(with = "token_as_string", schema_with = "describe_token")]
+    token: u64,
+}
+
+mod token_as_string {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub fn serialize<S: Serializer>(token: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(token)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        u64::deserialize(deserializer)
+    }
+}
+
+/// A secret that refuses to be serialized
+pub struct Secret;
+
+impl serde::Serialize for Secret {
+    fn serialize<S: serde::Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+        Err(serde::ser::Error::custom("secrets cannot be serialized"))
+    }
+}
+
+/// The example of a token
+#[derive(serde::Serialize)]
+pub struct TokenExample {
+    secret: Secret,
+}
+
+fn describe_token<B>(schema_builder: B) -> Result<B::Ok, B::Error>
+where
+    B: SchemaBuilder<std::iter::Once<TokenExample>>,
+{
+    schema_builder.describe_str(
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        || Ok(std::iter::once(TokenExample { secret: Secret })),
+        false,
+    )
+}
+
+#[test]
+fn test_example_error_names_field_path() {
+    use nexustack::openapi::json::{Specification, build_schema};
+
+    let Err(err) = build_schema::<Session>(Specification::OpenAPI3_1) else {
+        panic!("expected the example of `Session` to fail to serialize");
+    };
+
+    pretty_assertions::assert_eq!(
+        err.to_string(),
+        "schema cannot be constructed due to an error: failed to serialize example at \
+         `secret`: secrets cannot be serialized"
+    );
+}
@@ -0,0 +1,110 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::openapi::api_schema;
+
+/// A user account
+#[api_schema]
+pub struct Account {
+    /// The id assigned by the server
+    #[api_property(skip_deserializing)]
+    id: u8,
+    /// The name
+    name: String,
+    /// The password
+    #[api_property(skip_serializing)]
+    password: String,
+}
+
+/// An account event
+#[api_schema]
+pub enum Event {
+    /// An account was created
+    Created {
+        /// The id assigned by the server
+        #[api_property(skip_deserializing)]
+        id: u8,
+        /// The password
+        #[api_property(skip_serializing)]
+        password: String,
+    },
+}
+
+#[test]
+fn test_serde() {
+    let account = Account {
+        id: 7,
+        name: "Ferris".to_owned(),
+        password: "secret".to_owned(),
+    };
+
+    pretty_assertions::assert_eq!(
+        serde_json::to_value(&account).unwrap(),
+        serde_json::json!({ "id": 7, "name": "Ferris" })
+    );
+
+    let account = serde_json::from_value::<Account>(
+        serde_json::json!({ "id": 7, "name": "Ferris", "password": "secret" }),
+    )
+    .unwrap();
+
+    pretty_assertions::assert_eq!(account.id, 0);
+    pretty_assertions::assert_eq!(account.password, "secret");
+}
+
+#[test]
+fn test_openapi_3_0() {
+    use nexustack::openapi::json::{Specification, build_schema};
+
+    let schema = build_schema::<Account>(Specification::OpenAPI3_0).unwrap();
+
+    pretty_assertions::assert_eq!(
+        serde_json::to_value(schema).unwrap(),
+        serde_json::json!({
+            "description": "A user account",
+            "example": { "id": 0, "name": "", "password": "" },
+            "properties": {
+                "id": {
+                    "description": "The id assigned by the server",
+                    "example": 0,
+                    "maximum": 255,
+                    "minimum": 0,
+                    "readOnly": true,
+                    "type": "integer"
+                },
+                "name": {
+                    "description": "The name",
+                    "example": "",
+                    "type": "string"
+                },
+                "password": {
+                    "description": "The password",
+                    "example": "",
+                    "type": "string",
+                    "writeOnly": true
+                }
+            },
+            "required": ["id", "name", "password"],
+            "type": "object"
+        })
+    );
+}
+
+#[test]
+fn test_struct_variant() {
+    use nexustack::openapi::json::{Specification, build_schema};
+
+    for specification in [Specification::OpenAPI3_0, Specification::OpenAPI3_1] {
+        let schema = serde_json::to_value(build_schema::<Event>(specification).unwrap()).unwrap();
+        let properties = &schema["anyOf"][0]["properties"]["Created"]["properties"];
+
+        pretty_assertions::assert_eq!(properties["id"]["readOnly"], serde_json::json!(true));
+        pretty_assertions::assert_eq!(properties["id"].get("writeOnly"), None);
+        pretty_assertions::assert_eq!(properties["password"]["writeOnly"], serde_json::json!(true));
+        pretty_assertions::assert_eq!(properties["password"].get("readOnly"), None);
+    }
+}
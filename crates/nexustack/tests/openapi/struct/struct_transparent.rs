@@ -27,6 +27,7 @@ fn test_openapi_3_0() {
             "maximum": 2_147_483_647,
             "minimum": -2_147_483_648,
             "nullable": true,
+            "format": "int32",
             "type": "integer"
         })
     );
@@ -55,6 +56,7 @@ fn test_openapi_3_0_with_collection() {
             "maximum": 2_147_483_647,
             "minimum": -2_147_483_648,
             "nullable": true,
+            "format": "int32",
             "type": "integer"
         })
     );
@@ -84,6 +86,7 @@ fn test_openapi_3_1() {
             ],
             "maximum": 2_147_483_647,
             "minimum": -2_147_483_648,
+            "format": "int32",
             "type": ["integer", "null"]
         })
     );
@@ -118,6 +121,7 @@ fn test_openapi_3_1_with_collection() {
             ],
             "maximum": 2_147_483_647,
             "minimum": -2_147_483_648,
+            "format": "int32",
             "type": ["integer", "null"]
         })
     );
@@ -0,0 +1,61 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::openapi::{
+    api_schema,
+    json::{Error, SchemaOrReferenceObject, Specification, build_schema_with_transform},
+};
+
+/// A test struct
+#[api_schema]
+pub struct Point {
+    /// Field x
+    x: i32,
+}
+
+#[test]
+fn test_transform_adds_vendor_extension() {
+    let schema = build_schema_with_transform::<Point>(Specification::OpenAPI3_0, |mut schema| {
+        if let SchemaOrReferenceObject::Schema(schema) = &mut schema {
+            schema
+                .extensions
+                .insert("x-internal".to_owned(), serde_json::json!(true));
+        }
+        Ok(schema)
+    })
+    .unwrap();
+
+    pretty_assertions::assert_eq!(
+        serde_json::to_value(schema).unwrap(),
+        serde_json::json!({
+            "description": "A test struct",
+            "example": { "x": -2_147_483_648 },
+            "type": "object",
+            "required": ["x"],
+            "properties": {
+                "x": {
+                    "description": "Field x",
+                    "example": -2_147_483_648,
+                    "maximum": 2_147_483_647,
+                    "minimum": -2_147_483_648,
+                    "format": "int32",
+                    "type": "integer"
+                }
+            },
+            "x-internal": true
+        })
+    );
+}
+
+#[test]
+fn test_transform_error() {
+    let result = build_schema_with_transform::<Point>(Specification::OpenAPI3_1, |_| {
+        Err(Error::custom("rejected"))
+    });
+
+    assert!(matches!(result, Err(Error::Custom(message)) if message == "rejected"));
+}
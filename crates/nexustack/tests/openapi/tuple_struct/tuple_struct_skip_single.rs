@@ -31,6 +31,7 @@ fn test_openapi_3_0() {
             "example": -2_147_483_648,
             "maximum": 2_147_483_647,
             "minimum": -2_147_483_648,
+            "format": "int32",
             "type": "integer"
         })
     );
@@ -67,6 +68,7 @@ fn test_openapi_3_0_with_collection() {
                 "example": -2_147_483_648,
                 "maximum": 2_147_483_647,
                 "minimum": -2_147_483_648,
+                "format": "int32",
                 "type": "integer"
             }
         })
@@ -92,6 +94,7 @@ fn test_openapi_3_1() {
             ],
             "maximum": 2_147_483_647,
             "minimum": -2_147_483_648,
+            "format": "int32",
             "type": "integer"
         })
     );
@@ -134,6 +137,7 @@ fn test_openapi_3_1_with_collection() {
                 ],
                 "maximum": 2_147_483_647,
                 "minimum": -2_147_483_648,
+                "format": "int32",
                 "type": "integer"
             }
         })
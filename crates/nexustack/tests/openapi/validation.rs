@@ -0,0 +1,137 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use serde_json::Value;
+
+/// Validates a JSON value against a generated schema.
+///
+/// Only the subset of keywords emitted for enums and structs is supported: `type`, `nullable`, `enum`, `properties`,
+/// `required`, `additionalProperties`, `items`, `minimum`, `maximum`, `anyOf`, `oneOf`, `allOf` and `$ref`. References are
+/// resolved as JSON pointers into the schema itself; a reference that cannot be resolved fails validation.
+pub fn is_valid(schema: &Value, value: &Value) -> bool {
+    is_valid_in(schema, schema, value)
+}
+
+fn is_valid_in(document: &Value, schema: &Value, value: &Value) -> bool {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return reference
+            .strip_prefix('#')
+            .and_then(|pointer| document.pointer(pointer))
+            .is_some_and(|schema| is_valid_in(document, schema, value));
+    }
+
+    if value.is_null() && schema.get("nullable") == Some(&Value::Bool(true)) {
+        return true;
+    }
+
+    if let Some(r#type) = schema.get("type") {
+        let matches_type = |r#type: &Value| match r#type.as_str() {
+            Some("null") => value.is_null(),
+            Some("boolean") => value.is_boolean(),
+            Some("integer") => value.is_i64() || value.is_u64(),
+            Some("number") => value.is_number(),
+            Some("string") => value.is_string(),
+            Some("array") => value.is_array(),
+            Some("object") => value.is_object(),
+            _ => false,
+        };
+
+        let type_matches = match r#type {
+            Value::Array(types) => types.iter().any(matches_type),
+            r#type => matches_type(r#type),
+        };
+
+        if !type_matches {
+            return false;
+        }
+    }
+
+    if let Some(Value::Array(variants)) = schema.get("enum")
+        && !variants.contains(value)
+    {
+        return false;
+    }
+
+    if let Some(minimum) = schema.get("minimum").and_then(Value::as_f64)
+        && value.as_f64().is_some_and(|value| value < minimum)
+    {
+        return false;
+    }
+
+    if let Some(maximum) = schema.get("maximum").and_then(Value::as_f64)
+        && value.as_f64().is_some_and(|value| value > maximum)
+    {
+        return false;
+    }
+
+    if let Value::Object(object) = value {
+        if let Some(Value::Array(required)) = schema.get("required")
+            && !required
+                .iter()
+                .filter_map(Value::as_str)
+                .all(|key| object.contains_key(key))
+        {
+            return false;
+        }
+
+        let properties = schema.get("properties").and_then(Value::as_object);
+
+        for (key, property_value) in object {
+            let property_schema = properties
+                .and_then(|properties| properties.get(key))
+                .or_else(|| schema.get("additionalProperties"));
+
+            let property_valid = match property_schema {
+                Some(Value::Bool(additional_properties)) => *additional_properties,
+                Some(property_schema) => is_valid_in(document, property_schema, property_value),
+                None => true,
+            };
+
+            if !property_valid {
+                return false;
+            }
+        }
+    }
+
+    if let (Value::Array(items), Some(items_schema)) = (value, schema.get("items"))
+        && !items
+            .iter()
+            .all(|item| is_valid_in(document, items_schema, item))
+    {
+        return false;
+    }
+
+    let subschemas = |keyword: &str| {
+        schema
+            .get(keyword)
+            .and_then(Value::as_array)
+            .map(|subschemas| {
+                subschemas
+                    .iter()
+                    .filter(|subschema| is_valid_in(document, subschema, value))
+                    .count()
+            })
+    };
+
+    if subschemas("anyOf").is_some_and(|matching| matching == 0) {
+        return false;
+    }
+
+    if subschemas("oneOf").is_some_and(|matching| matching != 1) {
+        return false;
+    }
+
+    if let Some(Value::Array(all_of)) = schema.get("allOf")
+        && !all_of
+            .iter()
+            .all(|subschema| is_valid_in(document, subschema, value))
+    {
+        return false;
+    }
+
+    true
+}
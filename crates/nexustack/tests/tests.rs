@@ -8,5 +8,10 @@
 #![allow(clippy::too_many_lines)]
 #![allow(missing_docs)]
 
+mod application;
+#[cfg(feature = "cron")]
+mod cron;
+mod inject;
+mod module;
 #[cfg(feature = "openapi")]
 mod openapi;
@@ -0,0 +1,29 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+#![allow(missing_docs)]
+
+#[test]
+fn application() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/application/*.rs");
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn inject() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/inject/*.rs");
+    t.pass("tests/ui/inject/pass/*.rs");
+}
+
+#[cfg(feature = "cron")]
+#[test]
+fn cron() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/cron/*.rs");
+}
@@ -0,0 +1,39 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::{
+    ApplicationBuilder as _, ApplicationPart, ApplicationPartBuilder, application_builder,
+    inject::{ConstructionResult, ServiceProvider},
+};
+use std::convert::Infallible;
+use tokio_util::sync::CancellationToken;
+
+#[derive(Default)]
+struct ServerPart;
+
+impl ApplicationPart for ServerPart {
+    type Error = Infallible;
+
+    async fn run(&mut self, _cancellation_token: CancellationToken) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl ApplicationPartBuilder for ServerPart {
+    type ApplicationPart = Self;
+
+    fn build(self, _service_provider: ServiceProvider) -> ConstructionResult<Self> {
+        Ok(self)
+    }
+}
+
+fn main() {
+    let _app = application_builder()
+        .add_application_part::<ServerPart>()
+        .add_application_part_if(true, ServerPart::default)
+        .build();
+}
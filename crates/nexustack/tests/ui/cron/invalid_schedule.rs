@@ -0,0 +1,8 @@
+use nexustack::cron::cron;
+
+#[cron(schedule = "0 0 * *")]
+async fn invalid_schedule_job() -> nexustack::cron::CronResult {
+    Ok(())
+}
+
+fn main() {}
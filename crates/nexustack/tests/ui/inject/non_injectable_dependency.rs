@@ -0,0 +1,26 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::inject::injectable;
+
+// Neither `#[injectable]` nor `Clone`.
+struct Database;
+
+struct Repository {
+    _database: Database,
+}
+
+#[injectable(check_dependencies)]
+impl Repository {
+    pub fn new(database: Database) -> Self {
+        Self {
+            _database: database,
+        }
+    }
+}
+
+fn main() {}
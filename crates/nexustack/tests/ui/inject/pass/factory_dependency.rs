@@ -0,0 +1,32 @@
+/*
+ * This file is part of the nexustack (https://github.com/1ean267/nexustack) distribution.
+ *
+ * Copyright (c) Cato Truetschel and contributors. All rights reserved.
+ * Licensed under the MIT license. See LICENSE file in the project root for details.
+ */
+
+use nexustack::inject::{ServiceCollection, injectable};
+
+// Neither `#[injectable]` nor `Clone`, but registered via a factory.
+struct Conn(u32);
+
+struct Repository {
+    conn: Conn,
+}
+
+#[injectable]
+impl Repository {
+    pub fn new(conn: Conn) -> Self {
+        Self { conn }
+    }
+}
+
+fn main() {
+    let mut services = ServiceCollection::new();
+    services
+        .add_transient_factory::<Conn>(|_| Ok(Conn(1)))
+        .add_transient::<Repository>();
+
+    let repository = services.build().resolve::<Repository>().unwrap();
+    assert_eq!(repository.conn.0, 1);
+}
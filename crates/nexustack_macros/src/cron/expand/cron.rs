@@ -11,6 +11,7 @@ use crate::{
 };
 use proc_macro2::TokenStream;
 use quote::{ToTokens as _, format_ident, quote, quote_spanned};
+use std::time::Duration;
 use syn::{parse::Parser as _, spanned::Spanned};
 
 pub fn expand_cron(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
@@ -51,6 +52,8 @@ fn expand_function_cron(ctxt: &Ctxt, attr: TokenStream, mut item_fn: syn::ItemFn
         ));
     }
 
+    let mut arg_kinds = Vec::with_capacity(item_fn.sig.inputs.len());
+
     for input in item_fn.sig.inputs.iter_mut() {
         match input {
             syn::FnArg::Receiver(_) => {
@@ -61,14 +64,29 @@ fn expand_function_cron(ctxt: &Ctxt, attr: TokenStream, mut item_fn: syn::ItemFn
                 ));
             }
             syn::FnArg::Typed(pat_type) => {
-                let len = pat_type.attrs.len();
-                pat_type.attrs.retain(|attr| !is_cron_service_attr(attr));
-
-                if len == pat_type.attrs.len() {
-                    ctxt.syn_error(syn::Error::new_spanned(
-                        &pat_type,
-                        "Unknown parameter. To inject a service decorate it with the #[cron::service] attribute.",
-                    ));
+                let is_service = pat_type.attrs.iter().any(is_cron_service_attr);
+                let is_scope = pat_type.attrs.iter().any(is_cron_scope_attr);
+                pat_type
+                    .attrs
+                    .retain(|attr| !is_cron_service_attr(attr) && !is_cron_scope_attr(attr));
+
+                match (is_service, is_scope) {
+                    (true, false) => arg_kinds.push(ArgKind::Service),
+                    (false, true) => arg_kinds.push(ArgKind::Scope),
+                    (true, true) => {
+                        ctxt.syn_error(syn::Error::new_spanned(
+                            &pat_type,
+                            "A parameter cannot be decorated with both #[cron::service] and #[cron::scope].",
+                        ));
+                        arg_kinds.push(ArgKind::Service);
+                    }
+                    (false, false) => {
+                        ctxt.syn_error(syn::Error::new_spanned(
+                            &pat_type,
+                            "Unknown parameter. To inject a service decorate it with the #[cron::service] attribute, to receive the service scope decorate it with the #[cron::scope] attribute.",
+                        ));
+                        arg_kinds.push(ArgKind::Service);
+                    }
                 }
             }
         }
@@ -77,6 +95,10 @@ fn expand_function_cron(ctxt: &Ctxt, attr: TokenStream, mut item_fn: syn::ItemFn
     let mut crate_path = Attr::none(ctxt, CRATE);
     let mut schedule = Attr::none(ctxt, SCHEDULE);
     let mut schedule_with = Attr::none(ctxt, SCHEDULE_WITH);
+    let mut jitter = Attr::none(ctxt, JITTER);
+    let mut missed = Attr::none(ctxt, MISSED);
+    let mut after = Attr::none(ctxt, AFTER);
+    let mut once = BoolAttr::none(ctxt, ONCE);
 
     let span = attr.span();
 
@@ -90,6 +112,13 @@ fn expand_function_cron(ctxt: &Ctxt, attr: TokenStream, mut item_fn: syn::ItemFn
             } else if meta.path == SCHEDULE {
                 // #[cron(schedule = "...")]
                 if let Some(lit_str) = get_lit_str(ctxt, SCHEDULE, &meta)? {
+                    if let Err(err) = lit_str.value().parse::<cron::Schedule>() {
+                        ctxt.error_spanned_by(
+                            &lit_str,
+                            invalid_schedule_message(&lit_str.value(), &err),
+                        );
+                    }
+
                     schedule.set(&meta.path, lit_str.value());
                 }
             } else if meta.path == SCHEDULE_WITH {
@@ -97,6 +126,44 @@ fn expand_function_cron(ctxt: &Ctxt, attr: TokenStream, mut item_fn: syn::ItemFn
                 if let Some(path) = parse_lit_into_expr_path(ctxt, SCHEDULE_WITH, &meta)? {
                     schedule_with.set(&meta.path, path);
                 }
+            } else if meta.path == JITTER {
+                // #[cron(jitter = "30s")]
+                if let Some(lit_str) = get_lit_str(ctxt, JITTER, &meta)? {
+                    match parse_duration(&lit_str.value()) {
+                        Some(duration) => jitter.set(&meta.path, duration),
+                        None => ctxt.error_spanned_by(
+                            lit_str,
+                            "Invalid jitter: Expected a duration like `500ms`, `30s`, `5m` or `1h30m`.",
+                        ),
+                    }
+                }
+            } else if meta.path == MISSED {
+                // #[cron(missed = "skip" | "run_once" | "run_all")]
+                if let Some(lit_str) = get_lit_str(ctxt, MISSED, &meta)? {
+                    match lit_str.value().as_str() {
+                        "skip" => missed.set(&meta.path, quote!(Skip)),
+                        "run_once" => missed.set(&meta.path, quote!(RunOnce)),
+                        "run_all" => missed.set(&meta.path, quote!(RunAll)),
+                        _ => ctxt.error_spanned_by(
+                            lit_str,
+                            "Invalid missed run policy: Expected one of `skip`, `run_once` or `run_all`.",
+                        ),
+                    }
+                }
+            } else if meta.path == AFTER {
+                // #[cron(after = "10s", once)]
+                if let Some(lit_str) = get_lit_str(ctxt, AFTER, &meta)? {
+                    match parse_duration(&lit_str.value()) {
+                        Some(duration) => after.set(&meta.path, duration),
+                        None => ctxt.error_spanned_by(
+                            lit_str,
+                            "Invalid delay: Expected a duration like `500ms`, `30s`, `5m` or `1h30m`.",
+                        ),
+                    }
+                }
+            } else if meta.path == ONCE {
+                // #[cron(after = "10s", once)]
+                once.set_true(&meta.path);
             } else {
                 let path = meta.path.to_token_stream().to_string().replace(' ', "");
                 return Err(meta.error(format_args!("unknown attribute `{path}`")));
@@ -112,19 +179,36 @@ fn expand_function_cron(ctxt: &Ctxt, attr: TokenStream, mut item_fn: syn::ItemFn
 
     let schedule = schedule.get();
     let schedule_with = schedule_with.get();
+    let after = after.get();
+    let once = once.get();
 
-    let schedule_with = if let Some(schedule) = &schedule {
-        if schedule_with.is_some() {
+    if after.is_some() != once {
+        ctxt.syn_error(syn::Error::new(
+            span,
+            "Incomplete attributes: `after` and `once` must be specified together.",
+        ));
+    }
+
+    let schedule_with = if after.is_some() {
+        if schedule.is_some() || schedule_with.is_some() {
             ctxt.syn_error(syn::Error::new(
                 span,
-                "Conflicting attributes: Only one of `schedule` or `schedule_with` can be specified.",
+                "Conflicting attributes: A job that runs once `after` a delay cannot have a `schedule` or `schedule_with`.",
             ));
         }
 
-        if let Err(err) = schedule.parse::<cron::Schedule>() {
+        quote! {
+            |_service_provider: _nexustack::inject::ServiceProvider| async {
+                _nexustack::__private::Err(_nexustack::cron::CronError::ScheduleError(
+                    "a job that runs once after a delay has no schedule".into(),
+                ))
+            }
+        }
+    } else if let Some(schedule) = &schedule {
+        if schedule_with.is_some() {
             ctxt.syn_error(syn::Error::new(
                 span,
-                format!("Invalid cron schedule expression: {}", err),
+                "Conflicting attributes: Only one of `schedule` or `schedule_with` can be specified.",
             ));
         }
 
@@ -150,12 +234,43 @@ fn expand_function_cron(ctxt: &Ctxt, attr: TokenStream, mut item_fn: syn::ItemFn
         quote!()
     };
 
+    let jitter = jitter.get().map(|jitter| {
+        let secs = jitter.as_secs();
+        let nanos = jitter.subsec_nanos();
+
+        quote! {
+            fn jitter() -> _nexustack::__private::Duration {
+                _nexustack::__private::Duration::new(#secs, #nanos)
+            }
+        }
+    });
+
+    let run_once_after = after.map(|after| {
+        let secs = after.as_secs();
+        let nanos = after.subsec_nanos();
+
+        quote! {
+            fn run_once_after() -> _nexustack::__private::Option<_nexustack::__private::Duration> {
+                _nexustack::__private::Option::Some(_nexustack::__private::Duration::new(#secs, #nanos))
+            }
+        }
+    });
+
+    let missed_run_policy = missed.get().map(|missed| {
+        quote! {
+            fn missed_run_policy() -> _nexustack::cron::MissedRunPolicy {
+                _nexustack::cron::MissedRunPolicy::#missed
+            }
+        }
+    });
+
     let service_inits = item_fn
         .sig
         .inputs
         .iter()
+        .zip(&arg_kinds)
         .enumerate()
-        .map(|(index, input)| match input {
+        .map(|(index, (input, arg_kind))| match input {
             syn::FnArg::Receiver(_) => unreachable!(),
             syn::FnArg::Typed(pat_type) => {
                 let ty = &pat_type.ty;
@@ -164,14 +279,29 @@ fn expand_function_cron(ctxt: &Ctxt, attr: TokenStream, mut item_fn: syn::ItemFn
                     _ => &format_ident!("arg_{}", index),
                 };
 
-                quote_spanned! { pat_type.span()=>
-                    let #pat_name = service_provider
-                        .resolve::<#ty>()
-                        .map_err(|err| _nexustack::cron::CronError::RunError(err.into()))?;
+                match arg_kind {
+                    ArgKind::Service => quote_spanned! { pat_type.span()=>
+                        let #pat_name = service_provider
+                            .resolve::<#ty>()
+                            .map_err(|err| _nexustack::cron::CronError::RunError(err.into()))?;
+                    },
+                    ArgKind::Scope => quote_spanned! { pat_type.span()=>
+                        let #pat_name: #ty = _nexustack::__private::cron::service_scope(service_provider.clone());
+                    },
                 }
             }
         });
 
+    let dependencies = item_fn
+        .sig
+        .inputs
+        .iter()
+        .zip(&arg_kinds)
+        .filter_map(|(input, arg_kind)| match (input, arg_kind) {
+            (syn::FnArg::Typed(pat_type), ArgKind::Service) => Some(&pat_type.ty),
+            _ => None,
+        });
+
     let cron_args = item_fn.sig.inputs.iter().map(|input| match input {
         syn::FnArg::Receiver(_) => unreachable!(),
         syn::FnArg::Typed(pat_type) => {
@@ -221,6 +351,18 @@ fn expand_function_cron(ctxt: &Ctxt, attr: TokenStream, mut item_fn: syn::ItemFn
             fn name() -> _nexustack::__private::Cow<'static, str> {
                 _nexustack::__private::Cow::Borrowed(#cron_item_name_str)
             }
+
+            fn dependencies() -> _nexustack::__private::Vec<_nexustack::inject::ServiceToken> {
+                _nexustack::__private::Vec::from([
+                    #(_nexustack::inject::ServiceToken::create::<#dependencies>()),*
+                ])
+            }
+
+            #jitter
+
+            #missed_run_policy
+
+            #run_once_after
         }
     };
     let crate_path = crate_path.get();
@@ -234,6 +376,57 @@ fn expand_function_cron(ctxt: &Ctxt, attr: TokenStream, mut item_fn: syn::ItemFn
     }
 }
 
+fn invalid_schedule_message(schedule: &str, err: &cron::error::Error) -> String {
+    let fields = schedule.split_whitespace().count();
+
+    if !schedule.trim_start().starts_with('@') && !(6..=7).contains(&fields) {
+        return format!(
+            "Invalid cron schedule expression `{schedule}`: Expected 6 or 7 fields (sec min hour day-of-month month day-of-week [year]), found {fields}."
+        );
+    }
+
+    format!("Invalid cron schedule expression `{schedule}`: {err}")
+}
+
+/// Parses a duration like `500ms`, `30s`, `5m`, `1h` or a combination such as `1h30m`.
+fn parse_duration(value: &str) -> Option<Duration> {
+    let mut rest = value.trim();
+    let mut duration = Duration::ZERO;
+
+    if rest.is_empty() {
+        return None;
+    }
+
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let amount = rest[..digits].parse::<u64>().ok()?;
+        rest = &rest[digits..];
+
+        let unit = rest
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let part = match &rest[..unit] {
+            "ms" => Duration::from_millis(amount),
+            "s" => Duration::from_secs(amount),
+            "m" => Duration::from_secs(amount.checked_mul(60)?),
+            "h" => Duration::from_secs(amount.checked_mul(60 * 60)?),
+            _ => return None,
+        };
+        rest = &rest[unit..];
+
+        duration = duration.checked_add(part)?;
+    }
+
+    Some(duration)
+}
+
+enum ArgKind {
+    Service,
+    Scope,
+}
+
 fn is_cron_service_attr(attr: &syn::Attribute) -> bool {
     match &attr.meta {
         syn::Meta::Path(attr_path) => {
@@ -245,6 +438,17 @@ fn is_cron_service_attr(attr: &syn::Attribute) -> bool {
     }
 }
 
+fn is_cron_scope_attr(attr: &syn::Attribute) -> bool {
+    match &attr.meta {
+        syn::Meta::Path(attr_path) => {
+            is_path(attr_path, &["cron", "scope"])
+                || is_path(attr_path, &["cron", "cron", "scope"])
+                || is_path(attr_path, &["nexustack", "cron", "cron", "scope"])
+        }
+        _ => false,
+    }
+}
+
 // TODO: Stolen from inject expand, refactor later
 fn is_path(path: &syn::Path, segments: &[&str]) -> bool {
     if path.leading_colon.is_some() {
@@ -10,8 +10,8 @@ use crate::{
     internals::{Ctxt, attr::*, symbol::*},
 };
 use proc_macro2::TokenStream;
-use quote::{ToTokens as _, format_ident, quote};
-use syn::parse::Parser as _;
+use quote::{ToTokens as _, format_ident, quote, quote_spanned};
+use syn::{parse::Parser as _, spanned::Spanned as _};
 
 pub fn expand_injectable(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
     // TODO: Replace receiver
@@ -97,18 +97,49 @@ fn process_item_impl(ctxt: &Ctxt, attr: TokenStream, item_impl: syn::ItemImpl) -
         })
         .collect::<Vec<&syn::PatType>>();
 
+    let injectable_attr = parse_injectable_attr(ctxt, attr);
+    let mut dependency_types = Vec::new();
+
     // let {#parameter_name} = injector.resolve::<{#parameter_type}>()?;
-    let arguments = input_types.iter().map(|input_type| {
-        let parameter_type = input_type.ty.as_ref();
-        let parameter_name = match input_type.pat.as_ref() {
-            syn::Pat::Ident(parameter_name) => &parameter_name.ident,
-            _ => panic!("TODO: When does this happen??"),
-        };
+    let arguments = input_types
+        .iter()
+        .map(|input_type| {
+            let parameter_type = input_type.ty.as_ref();
+            let parameter_name = match input_type.pat.as_ref() {
+                syn::Pat::Ident(parameter_name) => &parameter_name.ident,
+                _ => panic!("TODO: When does this happen??"),
+            };
+
+            if is_optional_parameter(ctxt, input_type) {
+                let Some(parameter_type) = option_inner_type(parameter_type) else {
+                    ctxt.error_spanned_by(
+                        parameter_type,
+                        "#[inject(optional)] requires the parameter to be of type `Option<T>`.",
+                    );
+                    return TokenStream::new();
+                };
+
+                let assert_resolvable =
+                    assert_resolvable(&injectable_attr, parameter_type, &item_impl.generics);
+                dependency_types.push(parameter_type);
+
+                // let {#parameter_name} = injector.try_resolve::<{#parameter_type}>()?;
+                return quote! {
+                    #assert_resolvable
+                    let #parameter_name = injector.try_resolve::<#parameter_type>()?;
+                };
+            }
 
-        quote! {
-            let #parameter_name = injector.resolve::<#parameter_type>()?;
-        }
-    });
+            let assert_resolvable =
+                assert_resolvable(&injectable_attr, parameter_type, &item_impl.generics);
+            dependency_types.push(parameter_type);
+
+            quote! {
+                #assert_resolvable
+                let #parameter_name = injector.resolve::<#parameter_type>()?;
+            }
+        })
+        .collect::<Vec<_>>();
 
     let ctor_name = &ctor_fn.sig.ident;
     let ctor_parameter_names = input_types
@@ -131,8 +162,18 @@ fn process_item_impl(ctxt: &Ctxt, attr: TokenStream, item_impl: syn::ItemImpl) -
         if let Some(index) = fn_item.attrs.iter().position(is_injectable_ctor_attr) {
             fn_item.attrs.swap_remove(index);
         }
+
+        if fn_item.sig.ident == *ctor_name {
+            for input in &mut fn_item.sig.inputs {
+                if let syn::FnArg::Typed(input_type) = input {
+                    input_type.attrs.retain(|attr| attr.path() != INJECT);
+                }
+            }
+        }
     }
 
+    let dependencies = dependencies(&dependency_types, &item_impl.generics);
+
     let ident = item_impl.self_ty.as_ref();
     let generics = item_impl.generics.params;
     let where_clause = item_impl.generics.where_clause;
@@ -150,7 +191,9 @@ fn process_item_impl(ctxt: &Ctxt, attr: TokenStream, item_impl: syn::ItemImpl) -
         }
 
         #[automatically_derived]
-        impl <#generics> _nexustack::inject::Injectable for #ident #where_clause { }
+        impl <#generics> _nexustack::inject::Injectable for #ident #where_clause {
+            #dependencies
+        }
 
         #[automatically_derived]
         impl <#generics> _nexustack::inject::IntoConstructionResult for #ident #where_clause {
@@ -162,8 +205,7 @@ fn process_item_impl(ctxt: &Ctxt, attr: TokenStream, item_impl: syn::ItemImpl) -
         }
     };
 
-    let crate_path = get_crate_path(ctxt, attr);
-    let impl_block = dummy::wrap_in_const(crate_path.as_ref(), impl_block);
+    let impl_block = dummy::wrap_in_const(injectable_attr.crate_path.as_ref(), impl_block);
 
     quote! {
         #transformed_item_impl
@@ -171,16 +213,28 @@ fn process_item_impl(ctxt: &Ctxt, attr: TokenStream, item_impl: syn::ItemImpl) -
     }
 }
 
-fn get_crate_path(ctxt: &Ctxt, attr: TokenStream) -> Option<syn::Path> {
+struct InjectableAttr {
+    crate_path: Option<syn::Path>,
+    check_dependencies: bool,
+}
+
+fn parse_injectable_attr(ctxt: &Ctxt, attr: TokenStream) -> InjectableAttr {
     let mut crate_path = None;
+    let mut check_dependencies = false;
 
     if !attr.is_empty() {
         let parser = syn::meta::parser(|meta| {
             if meta.path == CRATE {
-                // #[inject(crate = "foo")]
+                // #[injectable(crate = "foo")]
                 if let Some(path) = parse_lit_into_path(ctxt, CRATE, &meta)? {
                     crate_path = Some(path);
                 }
+            } else if meta.path == CHECK_DEPENDENCIES {
+                // #[injectable(check_dependencies)]
+                if check_dependencies {
+                    return Err(meta.error("duplicate attribute `check_dependencies`"));
+                }
+                check_dependencies = true;
             } else {
                 let path = meta.path.to_token_stream().to_string().replace(' ', "");
                 return Err(meta.error(format_args!("unknown attribute `{path}`")));
@@ -194,7 +248,66 @@ fn get_crate_path(ctxt: &Ctxt, attr: TokenStream) -> Option<syn::Path> {
         }
     }
 
-    crate_path
+    InjectableAttr {
+        crate_path,
+        check_dependencies,
+    }
+}
+
+fn is_optional_parameter(ctxt: &Ctxt, input_type: &syn::PatType) -> bool {
+    let mut optional = false;
+
+    for attr in &input_type.attrs {
+        if attr.path() != INJECT {
+            continue;
+        }
+
+        let parse_res = attr.parse_nested_meta(|meta| {
+            if meta.path == OPTIONAL {
+                // #[inject(optional)]
+                if optional {
+                    return Err(meta.error("duplicate inject attribute `optional`"));
+                }
+                optional = true;
+            } else {
+                let path = meta.path.to_token_stream().to_string().replace(' ', "");
+                return Err(meta.error(format_args!("unknown inject attribute `{path}`")));
+            }
+            Ok(())
+        });
+
+        if let Err(err) = parse_res {
+            ctxt.syn_error(err);
+        }
+    }
+
+    optional
+}
+
+fn option_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+
+    if type_path.qself.is_some() {
+        return None;
+    }
+
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+
+    match arguments.args.first() {
+        Some(syn::GenericArgument::Type(inner_type)) if arguments.args.len() == 1 => {
+            Some(inner_type)
+        }
+        _ => None,
+    }
 }
 
 fn is_injectable_ctor_attr(attr: &syn::Attribute) -> bool {
@@ -278,6 +391,8 @@ fn process_item_unit_struct(
         );
     }
 
+    let injectable_attr = parse_injectable_attr(ctxt, attr);
+
     let ident = &struct_impl.ident;
     let generics = &struct_impl.generics.params;
     let where_clause = &struct_impl.generics.where_clause;
@@ -305,8 +420,7 @@ fn process_item_unit_struct(
         }
     };
 
-    let crate_path = get_crate_path(ctxt, attr);
-    let impl_block = dummy::wrap_in_const(crate_path.as_ref(), impl_block);
+    let impl_block = dummy::wrap_in_const(injectable_attr.crate_path.as_ref(), impl_block);
 
     quote! {
         #struct_impl
@@ -326,18 +440,31 @@ fn process_item_tuple_struct(
         );
     }
 
+    let injectable_attr = parse_injectable_attr(ctxt, attr);
+
     // let {#parameter_name} = injector.resolve::<{#parameter_type}>()?;
     let arguments = struct_impl.fields.iter().enumerate().map(|(index, field)| {
         let field_type = &field.ty;
         let var_name = format_ident!("arg_{index}");
 
+        let assert_resolvable =
+            assert_resolvable(&injectable_attr, field_type, &struct_impl.generics);
+
         quote! {
+            #assert_resolvable
             let #var_name = injector.resolve::<#field_type>()?;
         }
     });
 
     let field_names = (0usize..struct_impl.fields.len()).map(|index| format_ident!("arg_{index}"));
 
+    let field_types = struct_impl
+        .fields
+        .iter()
+        .map(|field| &field.ty)
+        .collect::<Vec<_>>();
+    let dependencies = dependencies(&field_types, &struct_impl.generics);
+
     let ident = &struct_impl.ident;
     let generics = &struct_impl.generics.params;
     let where_clause = &struct_impl.generics.where_clause;
@@ -355,7 +482,9 @@ fn process_item_tuple_struct(
         }
 
         #[automatically_derived]
-        impl <#generics> _nexustack::inject::Injectable for #ident #where_clause { }
+        impl <#generics> _nexustack::inject::Injectable for #ident #where_clause {
+            #dependencies
+        }
 
         #[automatically_derived]
         impl <#generics> _nexustack::inject::IntoConstructionResult for #ident #where_clause {
@@ -367,8 +496,7 @@ fn process_item_tuple_struct(
         }
     };
 
-    let crate_path = get_crate_path(ctxt, attr);
-    let impl_block = dummy::wrap_in_const(crate_path.as_ref(), impl_block);
+    let impl_block = dummy::wrap_in_const(injectable_attr.crate_path.as_ref(), impl_block);
 
     quote! {
         #struct_impl
@@ -388,6 +516,8 @@ fn process_item_struct(
         );
     }
 
+    let injectable_attr = parse_injectable_attr(ctxt, attr);
+
     // let {#parameter_name} = injector.resolve::<{#parameter_type}>()?;
     let arguments = struct_impl.fields.iter().map(|field| {
         let field_type = &field.ty;
@@ -396,7 +526,11 @@ fn process_item_struct(
             _ => unreachable!("Fields of braced structs are always named"),
         };
 
+        let assert_resolvable =
+            assert_resolvable(&injectable_attr, field_type, &struct_impl.generics);
+
         quote! {
+            #assert_resolvable
             let #field_name = injector.resolve::<#field_type>()?;
         }
     });
@@ -406,6 +540,13 @@ fn process_item_struct(
         _ => unreachable!("Fields of braced structs are always named"),
     });
 
+    let field_types = struct_impl
+        .fields
+        .iter()
+        .map(|field| &field.ty)
+        .collect::<Vec<_>>();
+    let dependencies = dependencies(&field_types, &struct_impl.generics);
+
     let ident = &struct_impl.ident;
     let generics = &struct_impl.generics.params;
     let where_clause = &struct_impl.generics.where_clause;
@@ -423,7 +564,9 @@ fn process_item_struct(
         }
 
         #[automatically_derived]
-        impl <#generics> _nexustack::inject::Injectable for #ident #where_clause { }
+        impl <#generics> _nexustack::inject::Injectable for #ident #where_clause {
+            #dependencies
+        }
 
         #[automatically_derived]
         impl <#generics> _nexustack::inject::IntoConstructionResult for #ident #where_clause {
@@ -435,8 +578,7 @@ fn process_item_struct(
         }
     };
 
-    let crate_path = get_crate_path(ctxt, attr);
-    let impl_block = dummy::wrap_in_const(crate_path.as_ref(), impl_block);
+    let impl_block = dummy::wrap_in_const(injectable_attr.crate_path.as_ref(), impl_block);
 
     quote! {
         #struct_impl
@@ -444,6 +586,69 @@ fn process_item_struct(
     }
 }
 
+// Asserts at compile time that a dependency can be resolved, so that a dependency that is neither
+// injectable nor a value is reported at its type instead of failing at runtime. The check is opt-in via
+// `#[injectable(check_dependencies)]`, as a dependency registered via a factory needs neither. Dependencies that
+// mention a generic type parameter are skipped, as they can only be checked at the use site.
+fn assert_resolvable(
+    injectable_attr: &InjectableAttr,
+    ty: &syn::Type,
+    generics: &syn::Generics,
+) -> TokenStream {
+    if !injectable_attr.check_dependencies {
+        return TokenStream::new();
+    }
+
+    let type_params = generics
+        .type_params()
+        .map(|type_param| &type_param.ident)
+        .collect::<Vec<_>>();
+
+    if mentions_any_ident(ty.to_token_stream(), &type_params) {
+        return TokenStream::new();
+    }
+
+    quote_spanned! {ty.span()=>
+        {
+            #[allow(unused_imports)]
+            use _nexustack::__private::inject::{
+                CloneDependency as _, InjectableDependency as _, TraitObjectDependency as _,
+            };
+            (&&&_nexustack::__private::inject::DependencyCheck::<#ty>(_nexustack::__private::PhantomData)).assert_resolvable();
+        }
+    }
+}
+
+// Generates the `Injectable::dependencies` function listing the service tokens of the dependencies. Like in
+// `assert_resolvable`, dependencies that mention a generic type parameter are skipped, as these are not known to be
+// `'static`.
+fn dependencies(types: &[&syn::Type], generics: &syn::Generics) -> TokenStream {
+    let type_params = generics
+        .type_params()
+        .map(|type_param| &type_param.ident)
+        .collect::<Vec<_>>();
+
+    let types = types
+        .iter()
+        .filter(|ty| !mentions_any_ident(ty.to_token_stream(), &type_params));
+
+    quote! {
+        fn dependencies() -> _nexustack::__private::Vec<_nexustack::inject::ServiceToken> {
+            _nexustack::__private::Vec::from([
+                #(_nexustack::inject::ServiceToken::create::<#types>()),*
+            ])
+        }
+    }
+}
+
+fn mentions_any_ident(tokens: TokenStream, idents: &[&syn::Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(ident) => idents.contains(&&ident),
+        proc_macro2::TokenTree::Group(group) => mentions_any_ident(group.stream(), idents),
+        _ => false,
+    })
+}
+
 fn is_path(path: &syn::Path, segments: &[&str]) -> bool {
     if path.leading_colon.is_some() {
         return false;
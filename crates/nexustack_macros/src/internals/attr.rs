@@ -78,10 +78,10 @@ impl<'c, T> Attr<'c, T> {
     }
 }
 
-#[cfg(feature = "openapi")]
+#[cfg(any(feature = "openapi", feature = "cron"))]
 pub(crate) struct BoolAttr<'c>(pub(crate) Attr<'c, ()>);
 
-#[cfg(feature = "openapi")]
+#[cfg(any(feature = "openapi", feature = "cron"))]
 impl<'c> BoolAttr<'c> {
     pub(crate) fn none(cx: &'c Ctxt, name: Symbol) -> Self {
         BoolAttr(Attr::none(cx, name))
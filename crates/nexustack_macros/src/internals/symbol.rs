@@ -18,15 +18,39 @@ pub struct Symbol(&'static str);
 #[cfg(any(feature = "openapi", feature = "inject", feature = "cron"))]
 pub const CRATE: Symbol = Symbol("crate");
 
+#[cfg(feature = "cron")]
+pub const AFTER: Symbol = Symbol("after");
+
+#[cfg(feature = "cron")]
+pub const JITTER: Symbol = Symbol("jitter");
+
+#[cfg(feature = "cron")]
+pub const MISSED: Symbol = Symbol("missed");
+
+#[cfg(feature = "cron")]
+pub const ONCE: Symbol = Symbol("once");
+
 #[cfg(feature = "cron")]
 pub const SCHEDULE: Symbol = Symbol("schedule");
 
 #[cfg(feature = "cron")]
 pub const SCHEDULE_WITH: Symbol = Symbol("schedule_with");
 
+#[cfg(feature = "inject")]
+pub const CHECK_DEPENDENCIES: Symbol = Symbol("check_dependencies");
+
+#[cfg(feature = "inject")]
+pub const INJECT: Symbol = Symbol("inject");
+
+#[cfg(any(feature = "inject", feature = "module"))]
+pub const OPTIONAL: Symbol = Symbol("optional");
+
 #[cfg(feature = "module")]
 pub const FEATURES: Symbol = Symbol("features");
 
+#[cfg(feature = "module")]
+pub const OPTIONAL_FEATURES: Symbol = Symbol("optional_features");
+
 #[cfg(feature = "openapi")]
 #[path = ""]
 mod optionapi {
@@ -42,22 +66,29 @@ mod optionapi {
     pub const DENY_UNKNOWN_FIELDS: Symbol = Symbol("deny_unknown_fields");
     pub const DEPRECATED: Symbol = Symbol("deprecated");
     pub const DESCRIPTION: Symbol = Symbol("description");
+    pub const DESCRIPTION_I18N: Symbol = Symbol("description_i18n");
     pub const DESERIALIZE_WITH: Symbol = Symbol("deserialize_with");
     pub const DESERIALIZE: Symbol = Symbol("deserialize");
     pub const DOC: Symbol = Symbol("doc");
+    pub const EXAMPLE: Symbol = Symbol("example");
+    pub const EXHAUSTIVE: Symbol = Symbol("exhaustive");
     pub const EXPECTING: Symbol = Symbol("expecting");
     pub const FIELD_IDENTIFIER: Symbol = Symbol("field_identifier");
     pub const FLATTEN: Symbol = Symbol("flatten");
     pub const FROM: Symbol = Symbol("from");
     pub const GETTER: Symbol = Symbol("getter");
+    pub const INLINE: Symbol = Symbol("inline");
     pub const INTO: Symbol = Symbol("into");
+    pub const NAME: Symbol = Symbol("name");
     pub const NON_EXHAUSTIVE: Symbol = Symbol("non_exhaustive");
     pub const OTHER: Symbol = Symbol("other");
     pub const READ: Symbol = Symbol("read");
+    pub const READ_ONLY: Symbol = Symbol("read_only");
     pub const REMOTE: Symbol = Symbol("remote");
     pub const RENAME_ALL_FIELDS: Symbol = Symbol("rename_all_fields");
     pub const RENAME_ALL: Symbol = Symbol("rename_all");
     pub const RENAME: Symbol = Symbol("rename");
+    pub const SCHEMA_WITH: Symbol = Symbol("schema_with");
     pub const SERDE: Symbol = Symbol("serde");
     pub const SERIALIZE_WITH: Symbol = Symbol("serialize_with");
     pub const SERIALIZE: Symbol = Symbol("serialize");
@@ -69,9 +100,11 @@ mod optionapi {
     pub const TRANSPARENT: Symbol = Symbol("transparent");
     pub const TRY_FROM: Symbol = Symbol("try_from");
     pub const UNTAGGED: Symbol = Symbol("untagged");
+    pub const VALUE: Symbol = Symbol("value");
     pub const VARIANT_IDENTIFIER: Symbol = Symbol("variant_identifier");
     pub const WITH: Symbol = Symbol("with");
     pub const WRITE: Symbol = Symbol("write");
+    pub const WRITE_ONLY: Symbol = Symbol("write_only");
 }
 
 #[cfg(feature = "openapi")]
@@ -11,7 +11,7 @@ use crate::{
 };
 use proc_macro2::TokenStream;
 use quote::{ToTokens as _, format_ident, quote, quote_spanned};
-use syn::{parse::Parser as _, spanned::Spanned as _};
+use syn::{parse::Parser as _, spanned::Spanned as _, visit_mut::VisitMut};
 
 pub fn expand_module(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
     let ctxt = Ctxt::new();
@@ -45,6 +45,7 @@ fn process_item_trait(ctxt: &Ctxt, attr: TokenStream, item_trait: syn::ItemTrait
 
     let mut crate_path = Attr::none(ctxt, CRATE);
     let mut features = Attr::none(ctxt, FEATURES);
+    let mut optional_features = Attr::none(ctxt, OPTIONAL_FEATURES);
 
     if !attr.is_empty() {
         let parser = syn::meta::parser(|meta| {
@@ -57,6 +58,10 @@ fn process_item_trait(ctxt: &Ctxt, attr: TokenStream, item_trait: syn::ItemTrait
                 // #[module(features(...))]
                 let feats = parse_lit_into_ty_list(ctxt, FEATURES, &meta)?;
                 features.set(&meta.path, feats);
+            } else if meta.path == OPTIONAL_FEATURES {
+                // #[module(optional_features = "...")]
+                let feats = parse_lit_into_ty_list(ctxt, OPTIONAL_FEATURES, &meta)?;
+                optional_features.set(&meta.path, feats);
             } else {
                 let path = meta.path.to_token_stream().to_string().replace(' ', "");
                 return Err(meta.error(format_args!("unknown container attribute `{path}`")));
@@ -73,6 +78,20 @@ fn process_item_trait(ctxt: &Ctxt, attr: TokenStream, item_trait: syn::ItemTrait
 
     let crate_path = crate_path.get();
     let features = features.get().unwrap_or_default();
+    let optional_features = optional_features
+        .get()
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|feature| match feature {
+            syn::Type::Path(type_path) if type_path.qself.is_none() => {
+                Some(type_path.path.segments.last().unwrap().ident.clone())
+            }
+            _ => {
+                ctxt.error_spanned_by(feature, "Expected a type path for the optional feature.");
+                None
+            }
+        })
+        .collect::<Vec<_>>();
 
     let trait_vis = &item_trait.vis;
     let trait_ident = &item_trait.ident;
@@ -173,6 +192,13 @@ fn process_item_trait(ctxt: &Ctxt, attr: TokenStream, item_trait: syn::ItemTrait
                 let fn_impl = &trait_item_fn.default;
 
                 if let Some(fn_iml) = fn_impl {
+                    let mut fn_iml = fn_iml.clone();
+                    OptionalFeatureGate {
+                        ctxt,
+                        optional_features: &optional_features,
+                    }
+                    .visit_block_mut(&mut fn_iml);
+
                     let fn_ident = &trait_item_fn.sig.ident;
                     let fn_input = &trait_item_fn.sig.inputs;
                     let fn_output = &trait_item_fn.sig.output;
@@ -181,6 +207,8 @@ fn process_item_trait(ctxt: &Ctxt, attr: TokenStream, item_trait: syn::ItemTrait
                     };
 
                     quote! {
+                        // Statements gated on optional features usually shadow the builder that is returned.
+                        #[allow(clippy::let_and_return)]
                         fn #fn_ident(#fn_input) #fn_output<Chain = Self::Chain> {
                             const fn is_application_builder<T: _nexustack::ApplicationBuilder> (_t: &T) {}
                             let result = {
@@ -280,3 +308,65 @@ fn process_item_trait(ctxt: &Ctxt, attr: TokenStream, item_trait: syn::ItemTrait
         #impl_block
     }
 }
+
+/// Gates the statements marked with `#[optional(Feature)]` on the nexustack feature `Feature`.
+///
+/// A gated statement is wrapped into the macro `_nexustack::__private::features::Feature`, which expands to the
+/// statement if the feature is enabled and to nothing otherwise.
+struct OptionalFeatureGate<'a> {
+    ctxt: &'a Ctxt,
+    optional_features: &'a [syn::Ident],
+}
+
+impl OptionalFeatureGate<'_> {
+    fn take_feature(&self, attrs: &mut Vec<syn::Attribute>) -> Option<syn::Ident> {
+        let index = attrs.iter().position(|attr| attr.path() == OPTIONAL)?;
+        let attr = attrs.remove(index);
+
+        let feature = match attr.parse_args::<syn::Ident>() {
+            Ok(feature) => feature,
+            Err(_) => {
+                self.ctxt.error_spanned_by(
+                    &attr,
+                    "Expected a single optional feature, e.g. #[optional(Openapi)].",
+                );
+                return None;
+            }
+        };
+
+        if !self.optional_features.contains(&feature) {
+            self.ctxt.error_spanned_by(
+                &feature,
+                format_args!("The feature `{feature}` is not declared in `optional_features`."),
+            );
+            return None;
+        }
+
+        Some(feature)
+    }
+}
+
+impl VisitMut for OptionalFeatureGate<'_> {
+    fn visit_stmt_mut(&mut self, stmt: &mut syn::Stmt) {
+        syn::visit_mut::visit_stmt_mut(self, stmt);
+
+        let attrs = match stmt {
+            syn::Stmt::Local(local) => &mut local.attrs,
+            syn::Stmt::Macro(stmt_macro) => &mut stmt_macro.attrs,
+            syn::Stmt::Expr(expr, Some(_)) => match expr {
+                syn::Expr::Assign(expr) => &mut expr.attrs,
+                syn::Expr::Call(expr) => &mut expr.attrs,
+                syn::Expr::MethodCall(expr) => &mut expr.attrs,
+                syn::Expr::Macro(expr) => &mut expr.attrs,
+                _ => return,
+            },
+            _ => return,
+        };
+
+        if let Some(feature) = self.take_feature(attrs) {
+            *stmt = syn::parse_quote! {
+                _nexustack::__private::features::#feature! { #stmt }
+            };
+        }
+    }
+}
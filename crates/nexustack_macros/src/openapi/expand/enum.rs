@@ -14,8 +14,10 @@ use crate::{
     internals::callsite,
     openapi::{
         expand::{
-            ExampleContainerIdentifier, Parameters, StructTrait, TupleTrait,
-            describe_struct_visitor, describe_tuple_struct_visitor, effective_style, mut_if,
+            ExampleContainerIdentifier, Parameters, StructTrait, TupleTrait, custom_examples,
+            custom_examples_type, describe_example_names, describe_modifier,
+            describe_struct_visitor, describe_translations, describe_tuple_struct_visitor,
+            effective_style, field_example_type, field_examples, field_examples_type, mut_if,
         },
         generics::{field_contains_generic_params, make_lifetimes_static},
         internals::{
@@ -44,7 +46,8 @@ pub fn expand_enum<'a>(cont: &Container, variants: &[Variant<'a>]) -> TokenStrea
         &example_cont_id,
         &mut variant_callsites,
     ));
-    let examples = examples_type(variants, &example_cont_id);
+    let examples =
+        custom_examples_type(cont).unwrap_or_else(|| examples_type(variants, &example_cont_id));
 
     quote! {
         static __callsite: _nexustack::__private::utils::AtomicOnceCell<_nexustack::Callsite> =
@@ -159,10 +162,11 @@ fn example_container(
                             .ident
                             .as_ref()
                             .expect("Brace structs have named fields");
+                        let example_ty = field_example_type(field, &ty);
 
                         g_fields.push(quote! {
                             #serde_field_attr
-                            #ident: <#ty as _nexustack::openapi::Schema>::Example
+                            #ident: #example_ty
                         });
                     }
                 }
@@ -324,28 +328,31 @@ fn struct_variant_examples_type(
     }
 
     if fields.len() == 1 {
-        let ty = fields[0].ty;
+        let examples_ty = field_examples_type(fields[0]);
+        let example_ty = field_example_type(fields[0], fields[0].ty);
 
         return quote! {
-            _nexustack::__private::Map<<#ty as _nexustack::openapi::Schema>::Examples, fn(<#ty as _nexustack::openapi::Schema>::Example) -> #example_cont>
+            _nexustack::__private::Map<#examples_ty, fn(#example_ty) -> #example_cont>
         };
     }
 
-    let field_ty_0 = fields[0].ty;
-    let field_ty_1 = fields[1].ty;
+    let examples_ty_0 = field_examples_type(fields[0]);
+    let examples_ty_1 = field_examples_type(fields[1]);
 
-    let mut examples_iter_ty = quote! { _nexustack::__private::Zip<<#field_ty_0 as _nexustack::openapi::Schema>::Examples, <#field_ty_1 as _nexustack::openapi::Schema>::Examples> };
+    let mut examples_iter_ty =
+        quote! { _nexustack::__private::Zip<#examples_ty_0, #examples_ty_1> };
     let mut example_ty_list = vec![
-        quote! { <#field_ty_0 as _nexustack::openapi::Schema>::Example },
-        quote! { <#field_ty_1 as _nexustack::openapi::Schema>::Example },
+        field_example_type(fields[0], fields[0].ty),
+        field_example_type(fields[1], fields[1].ty),
     ];
 
     for field in fields.iter().skip(2) {
-        let field_ty = field.ty;
+        let examples_ty = field_examples_type(field);
+        let example_ty = field_example_type(field, field.ty);
 
-        examples_iter_ty = quote! { _nexustack::__private::Zip<#examples_iter_ty, <#field_ty as _nexustack::openapi::Schema>::Examples> };
-        examples_iter_ty = quote! { _nexustack::__private::Map<#examples_iter_ty, fn(((#(#example_ty_list),*), <#field_ty as _nexustack::openapi::Schema>::Example)) -> (#(#example_ty_list),*, <#field_ty as _nexustack::openapi::Schema>::Example)> };
-        example_ty_list.push(quote! { <#field_ty as _nexustack::openapi::Schema>::Example });
+        examples_iter_ty = quote! { _nexustack::__private::Zip<#examples_iter_ty, #examples_ty> };
+        examples_iter_ty = quote! { _nexustack::__private::Map<#examples_iter_ty, fn(((#(#example_ty_list),*), #example_ty)) -> (#(#example_ty_list),*, #example_ty)> };
+        example_ty_list.push(example_ty);
     }
 
     quote! {
@@ -483,7 +490,8 @@ fn struct_variant_example(
     }
 
     if fields.len() == 1 {
-        let ty = fields[0].ty;
+        let field_examples = field_examples(fields[0]);
+        let example_ty = field_example_type(fields[0], fields[0].ty);
         let ident = fields[0]
             .original
             .ident
@@ -492,34 +500,35 @@ fn struct_variant_example(
 
         return quote! {
             _nexustack::__private::Iterator::map(
-                <#ty as _nexustack::openapi::SchemaExamples>::examples(is_human_readable)?,
-                (|e: <#ty as _nexustack::openapi::Schema>::Example| #example_cont_instantiation :: #variant_ident { #ident: e }) as _,
+                #field_examples,
+                (|e: #example_ty| #example_cont_instantiation :: #variant_ident { #ident: e }) as _,
             )
         };
     }
 
-    let field_ty_0 = fields[0].ty;
-    let field_ty_1 = fields[1].ty;
+    let field_examples_0 = field_examples(fields[0]);
+    let field_examples_1 = field_examples(fields[1]);
 
     let mut result = quote! {
         _nexustack::__private::Iterator::zip(
-            <#field_ty_0 as _nexustack::openapi::SchemaExamples>::examples(is_human_readable)?,
-            <#field_ty_1 as _nexustack::openapi::SchemaExamples>::examples(is_human_readable)?,
+            #field_examples_0,
+            #field_examples_1,
         )
     };
 
     let mut example_ty_list = vec![
-        quote! { <#field_ty_0 as _nexustack::openapi::Schema>::Example },
-        quote! { <#field_ty_1 as _nexustack::openapi::Schema>::Example },
+        field_example_type(fields[0], fields[0].ty),
+        field_example_type(fields[1], fields[1].ty),
     ];
 
     for (index, field) in fields.iter().enumerate().skip(2) {
-        let field_ty = field.ty;
+        let field_examples = field_examples(field);
+        let example_ty = field_example_type(field, field.ty);
 
         result = quote! {
             _nexustack::__private::Iterator::zip(
                 #result,
-                <#field_ty as _nexustack::openapi::SchemaExamples>::examples(is_human_readable)?,
+                #field_examples,
             )
         };
 
@@ -528,11 +537,11 @@ fn struct_variant_example(
         result = quote! {
             _nexustack::__private::Iterator::map(
                 #result,
-                (|f:((#(#example_ty_list),*), <#field_ty as _nexustack::openapi::Schema>::Example)| (#(#f_existing_entries),*, f.1)) as _,
+                (|f:((#(#example_ty_list),*), #example_ty)| (#(#f_existing_entries),*, f.1)) as _,
             )
         };
 
-        example_ty_list.push(quote! { <#field_ty as _nexustack::openapi::Schema>::Example });
+        example_ty_list.push(example_ty);
     }
 
     let f_entries = fields
@@ -583,7 +592,31 @@ fn describe(
         .filter(|variant| !variant.attrs.skip() && !variant.attrs.other())
         .peekable();
 
-    let let_mut = mut_if(serialized_variants.peek().is_some());
+    let translations = describe_translations(
+        cattrs,
+        &quote!(_nexustack::openapi::EnumSchemaBuilder),
+        &format_ident!("__enum_builder"),
+    );
+
+    let example_names = describe_example_names(
+        cattrs,
+        &quote!(_nexustack::openapi::EnumSchemaBuilder),
+        &format_ident!("__enum_builder"),
+        true,
+    );
+
+    let modifier = describe_modifier(
+        cattrs,
+        &quote!(_nexustack::openapi::EnumSchemaBuilder),
+        &format_ident!("__enum_builder"),
+    );
+
+    let let_mut = mut_if(
+        serialized_variants.peek().is_some()
+            || !translations.is_empty()
+            || !example_names.is_empty()
+            || !modifier.is_empty(),
+    );
     let type_name = cattrs.name().serialize_name();
 
     let len = serialized_variants
@@ -605,19 +638,20 @@ fn describe(
 
     let description = cattrs.description();
     let deprecated = cattrs.deprecated();
-    let examples = examples(variants, example_cont);
+    let examples = custom_examples(cont).unwrap_or_else(|| examples(variants, example_cont));
 
     // If any of the fields contains one of the containers generic parameters,
     // we cannot provide a unique type name.
-    let id = if variants
-        .iter()
-        .filter(|variant| !variant.attrs.skip() && !variant.attrs.other())
-        .any(|variant| {
-            variant
-                .fields
-                .iter()
-                .any(|field| !field.attrs.skip() && field_contains_generic_params(field, cont))
-        }) {
+    let id = if cattrs.inline()
+        || variants
+            .iter()
+            .filter(|variant| !variant.attrs.skip() && !variant.attrs.other())
+            .any(|variant| {
+                variant
+                    .fields
+                    .iter()
+                    .any(|field| !field.attrs.skip() && field_contains_generic_params(field, cont))
+            }) {
         quote! { _nexustack::__private::Option::None }
     } else {
         let cont_span = cont.original.span();
@@ -641,6 +675,9 @@ fn describe(
             #deprecated
         )?;
         #(#describe_variants)*
+        #translations
+        #example_names
+        #modifier
         _nexustack::openapi::EnumSchemaBuilder::end(__enum_builder)
     }
 }
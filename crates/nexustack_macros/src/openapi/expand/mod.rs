@@ -140,6 +140,36 @@ impl ExampleContainerIdentifier {
     }
 }
 
+// The type of the examples provided via `#[api_schema(example = "...")]`, which replace the
+// derived examples of the container.
+fn custom_examples_type(cont: &Container) -> Option<TokenStream> {
+    let len = cont.attrs.examples().len();
+
+    if len == 0 {
+        return None;
+    }
+
+    Some(quote!(<[Self; #len] as _nexustack::__private::IntoIterator>::IntoIter))
+}
+
+// The expression producing the examples provided via `#[api_schema(example = "...")]`. The
+// expression is placed where the derived examples would be built, so it consumes the
+// `is_human_readable` binding the derived examples would otherwise use.
+fn custom_examples(cont: &Container) -> Option<TokenStream> {
+    let examples = cont.attrs.examples();
+
+    if examples.is_empty() {
+        return None;
+    }
+
+    Some(quote! {
+        {
+            let _ = is_human_readable;
+            _nexustack::__private::IntoIterator::into_iter([#(#examples()),*])
+        }
+    })
+}
+
 fn precondition(cx: &Ctxt, cont: &Container) {
     match cont.attrs.identifier() {
         attr::Identifier::No => {}
@@ -215,11 +245,12 @@ fn build_generics(cont: &Container) -> syn::Generics {
 // Fields with a `skip` attribute, or which
 // belong to a variant with a `skip` attribute,
 // are not handled by us so we do not generate a bound. Fields with a `bound`
-// attribute specify their own bound so we do not generate one. All other fields
-// may need a `T: Schema` bound where T is the type of the field.
+// or `schema_with` attribute specify their own bound so we do not generate one.
+// All other fields may need a `T: Schema` bound where T is the type of the field.
 fn needs_describe_bound(field: &attr::Field, variant: Option<&attr::Variant>) -> bool {
     !field.skip()
         && field.ser_bound().is_none()
+        && field.schema_with().is_none()
         && variant.is_none_or(|variant| !variant.skip() && variant.ser_bound().is_none())
 }
 
@@ -287,23 +318,46 @@ fn describe_struct_visitor<'a>(
             let description = field.attrs.description();
             let deprecated = field.attrs.deprecated();
 
+            // The default of a field with a custom schema is serialized differently than its Rust value,
+            // so the field is only marked as optional.
             let default = match field.attrs.default().or(cattrs.default()) {
                 attr::Default::None => None,
-                attr::Default::Default => Some(quote!(<#ty as _nexustack::__private::Default>::default())),
-                attr::Default::Path(expr_path) => Some(quote!(#expr_path())),
+                _ if field.attrs.schema_with().is_some() => Some(quote!(_nexustack::__private::Option::None::<()>)),
+                attr::Default::Default => Some(quote!(_nexustack::__private::Option::Some(<#ty as _nexustack::__private::Default>::default()))),
+                attr::Default::Path(expr_path) => Some(quote!(_nexustack::__private::Option::Some(#expr_path()))),
             };
+            let describe = field_describe(field);
 
-            if let Some(default) = default {
+            // A field that is never deserialized is only sent by the server and vice versa.
+            let modifier = if field.attrs.skip_deserializing() {
+                quote!(_nexustack::openapi::FieldMod::Read)
+            } else if field.attrs.skip_serializing() {
+                quote!(_nexustack::openapi::FieldMod::Write)
+            } else {
+                quote!(_nexustack::openapi::FieldMod::ReadWrite)
+            };
+
+            let examples = field.attrs.examples();
+            let describe_examples = if examples.is_empty() {
+                TokenStream::new()
+            } else {
+                let func = struct_trait.describe_field_examples(span);
+                quote! {
+                    #func(&mut __builder, #key_expr, [#(#examples()),*])?;
+                }
+            };
+
+            let describe_field = if let Some(default) = default {
                 let func = struct_trait.describe_field_optional(span);
                 quote! {
                     #func(
                         &mut __builder,
                         #key_expr,
-                        _nexustack::openapi::FieldMod::ReadWrite,
-                        _nexustack::__private::Option::Some(#default),
+                        #modifier,
+                        #default,
                         _nexustack::__private::Option::Some(#description),
                         #deprecated,
-                        <#ty as _nexustack::openapi::Schema>::describe,
+                        #describe,
                     )?;
                 }
             } else {
@@ -312,22 +366,128 @@ fn describe_struct_visitor<'a>(
                     #func(
                         &mut __builder,
                         #key_expr,
-                        _nexustack::openapi::FieldMod::ReadWrite,
+                        #modifier,
                         _nexustack::__private::Option::Some(#description),
                         #deprecated,
-                        <#ty as _nexustack::openapi::Schema>::describe,
+                        #describe,
                     )?;
                 }
+            };
+
+            quote! {
+                #describe_field
+                #describe_examples
             }
         })
         .collect()
 }
 
+// The function describing the schema of a field, which is either the `describe` function of the field type
+// or the one given by `#[api_property(schema_with = "...")]`.
+fn field_describe(field: &Field) -> TokenStream {
+    let ty = field.ty;
+
+    match field.attrs.schema_with() {
+        Some(schema_with) => quote!(#schema_with),
+        None => quote!(<#ty as _nexustack::openapi::Schema>::describe),
+    }
+}
+
+// The type of a single example of a field. The examples of a custom schema cannot be named, so they are captured
+// in their serialized form.
+fn field_example_type(field: &Field, ty: &syn::Type) -> TokenStream {
+    match field.attrs.schema_with() {
+        Some(_) => quote!(_nexustack::openapi::__private::SchemaWithExample),
+        None => quote!(<#ty as _nexustack::openapi::Schema>::Example),
+    }
+}
+
+// The type of the examples iterator of a field.
+fn field_examples_type(field: &Field) -> TokenStream {
+    let ty = field.ty;
+
+    match field.attrs.schema_with() {
+        Some(_) => quote!(_nexustack::openapi::__private::SchemaWithExamples),
+        None => quote!(<#ty as _nexustack::openapi::Schema>::Examples),
+    }
+}
+
+// Collects the examples of a field, assuming `is_human_readable` to be in scope.
+fn field_examples(field: &Field) -> TokenStream {
+    let ty = field.ty;
+
+    match field.attrs.schema_with() {
+        Some(schema_with) => quote! {
+            _nexustack::openapi::__private::schema_with_examples(#schema_with, is_human_readable)?
+        },
+        None => quote!(<#ty as _nexustack::openapi::SchemaExamples>::examples(is_human_readable)?),
+    }
+}
+
 // where we want to omit the `mut` to avoid a warning.
 fn mut_if(is_mut: bool) -> Option<TokenStream> {
     if is_mut { Some(quote!(mut)) } else { None }
 }
 
+// Emits the `describe_translations` call for `#[api_schema(description_i18n(...))]`,
+// or nothing if the container carries no translations.
+fn describe_translations(
+    cattrs: &attr::Container,
+    builder_trait: &TokenStream,
+    builder: &Ident,
+) -> TokenStream {
+    let translations = cattrs.translations();
+    if translations.is_empty() {
+        return quote! {};
+    }
+
+    let translations = translations
+        .iter()
+        .map(|(language, description)| quote!((#language, #description)));
+
+    quote! {
+        #builder_trait::describe_translations(&mut #builder, &[#(#translations),*])?;
+    }
+}
+
+// Emits the `describe_example_names` call for `#[api_schema(example(name = "...", value = "..."))]`,
+// or nothing if the examples are unnamed or the custom examples are not in use.
+fn describe_example_names(
+    cattrs: &attr::Container,
+    builder_trait: &TokenStream,
+    builder: &Ident,
+    with_custom_examples: bool,
+) -> TokenStream {
+    let example_names = cattrs.example_names();
+    if example_names.is_empty() || !with_custom_examples {
+        return quote! {};
+    }
+
+    quote! {
+        #builder_trait::describe_example_names(&mut #builder, &[#(#example_names),*])?;
+    }
+}
+
+// Emits the `describe_modifier` call for `#[api_schema(read_only)]` and `#[api_schema(write_only)]`,
+// or nothing if the container is readable and writable.
+fn describe_modifier(
+    cattrs: &attr::Container,
+    builder_trait: &TokenStream,
+    builder: &Ident,
+) -> TokenStream {
+    let modifier = if cattrs.read_only() {
+        quote!(_nexustack::openapi::FieldMod::Read)
+    } else if cattrs.write_only() {
+        quote!(_nexustack::openapi::FieldMod::Write)
+    } else {
+        return quote! {};
+    };
+
+    quote! {
+        #builder_trait::describe_modifier(&mut #builder, #modifier)?;
+    }
+}
+
 fn effective_style(variant: &Variant) -> Style {
     match variant.style {
         Style::Newtype if variant.fields[0].attrs.skip() => Style::Unit,
@@ -369,6 +529,20 @@ impl StructTrait {
             }
         }
     }
+
+    fn describe_field_examples(&self, span: Span) -> TokenStream {
+        match *self {
+            StructTrait::Map => {
+                quote_spanned!(span => _nexustack::openapi::MapSchemaBuilder::describe_element_examples)
+            }
+            StructTrait::Struct => {
+                quote_spanned!(span => _nexustack::openapi::StructSchemaBuilder::describe_field_examples)
+            }
+            StructTrait::StructVariant => {
+                quote_spanned!(span => _nexustack::openapi::StructVariantSchemaBuilder::describe_field_examples)
+            }
+        }
+    }
 }
 
 enum TupleTrait {
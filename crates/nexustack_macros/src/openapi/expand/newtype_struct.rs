@@ -13,7 +13,7 @@ use crate::{
     fragment::{Fragment, Stmts},
     internals::callsite,
     openapi::{
-        expand::{ExampleContainerIdentifier, Parameters},
+        expand::{ExampleContainerIdentifier, Parameters, custom_examples, custom_examples_type},
         generics::{field_contains_generic_params, make_lifetimes_static},
         internals::ast::{Container, Field},
         serde::{build_example_field_attribute, build_example_struct_attribute},
@@ -29,7 +29,8 @@ pub fn expand_newtype_struct(cont: &Container, field: &Field<'_>) -> TokenStream
     let (impl_generics, ty_generics, where_clause) = params.generics.split_for_impl();
     let (example_cont, example_cont_id) = example_container(cont, field);
     let body = Stmts(describe(field, cont, &example_cont_id));
-    let examples = examples_type(field, &example_cont_id);
+    let examples =
+        custom_examples_type(cont).unwrap_or_else(|| examples_type(field, &example_cont_id));
 
     quote! {
         static __callsite: _nexustack::__private::utils::AtomicOnceCell<_nexustack::Callsite> =
@@ -131,14 +132,14 @@ fn describe(
     let span = field.original.span();
 
     let description = cattrs.description();
-    let examples = examples(field, example_cont);
+    let examples = custom_examples(cont).unwrap_or_else(|| examples(field, example_cont));
     let deprecated = cattrs.deprecated();
     let func = quote_spanned!(span => _nexustack::openapi::SchemaBuilder::collect_newtype_struct);
     let ty = field.ty;
 
     // If the field contains one of the containers generic parameters,
     // we cannot provide a unique type name.
-    let id = if field_contains_generic_params(field, cont) {
+    let id = if cattrs.inline() || field_contains_generic_params(field, cont) {
         quote! { _nexustack::__private::Option::None }
     } else {
         let cont_span = cont.original.span();
@@ -14,7 +14,10 @@ use crate::{
     internals::callsite,
     openapi::{
         expand::{
-            ExampleContainerIdentifier, Parameters, StructTrait, describe_struct_visitor, mut_if,
+            ExampleContainerIdentifier, Parameters, StructTrait, custom_examples,
+            custom_examples_type, describe_example_names, describe_modifier,
+            describe_struct_visitor, describe_translations, field_example_type, field_examples,
+            field_examples_type, mut_if,
         },
         generics::{field_contains_generic_params, make_lifetimes_static},
         internals::{
@@ -57,9 +60,10 @@ pub fn expand_struct(cont: &Container, fields: &[Field]) -> TokenStream {
             cont,
             non_generic_fields.as_slice(),
             Some(type_name),
+            true,
         )
     } else if non_generic_fields.is_empty() {
-        base_case(ident, &params, cont, generic_fields.as_slice(), None)
+        base_case(ident, &params, cont, generic_fields.as_slice(), None, true)
     } else {
         combined_case(
             ident,
@@ -96,10 +100,12 @@ fn combined_case(
 
     let (impl_generics, ty_generics, where_clause) = params.generics.split_for_impl();
     let (example_cont, example_cont_id) = example_container(ident, cont, all_fields.as_slice());
-    let examples_types = examples_type(all_fields.as_slice(), &example_cont_id);
+    let examples_types = custom_examples_type(cont)
+        .unwrap_or_else(|| examples_type(all_fields.as_slice(), &example_cont_id));
     let description = cattrs.description();
     let deprecated = cattrs.deprecated();
-    let examples = examples(all_fields.as_slice(), &example_cont_id);
+    let examples =
+        custom_examples(cont).unwrap_or_else(|| examples(all_fields.as_slice(), &example_cont_id));
 
     let ty_inner_non_generic = format_ident!("{}__Non_Generic", ident);
     let ty_inner_generic = format_ident!("{}__Generic", ident);
@@ -110,8 +116,10 @@ fn combined_case(
         cont,
         non_generic_fields,
         Some(type_name),
+        false,
     );
-    let ty_inner_generic_impl = base_case(&ty_inner_generic, params, cont, generic_fields, None);
+    let ty_inner_generic_impl =
+        base_case(&ty_inner_generic, params, cont, generic_fields, None, false);
 
     let type_params_phantom_fields = cont
         .generics
@@ -184,11 +192,20 @@ fn base_case(
     cont: &Container,
     fields: &[&Field],
     name: Option<&Name>,
+    with_custom_examples: bool,
 ) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = params.generics.split_for_impl();
     let (example_cont, example_cont_id) = example_container(ident, cont, fields);
-    let body = Stmts(describe(fields, cont, &example_cont_id, name));
-    let examples = examples_type(fields, &example_cont_id);
+    let body = Stmts(describe(
+        fields,
+        cont,
+        &example_cont_id,
+        name,
+        with_custom_examples,
+    ));
+    let examples = custom_examples_type(cont)
+        .filter(|_| with_custom_examples)
+        .unwrap_or_else(|| examples_type(fields, &example_cont_id));
 
     quote! {
         #[automatically_derived]
@@ -239,9 +256,8 @@ fn example_container(
             g_fields.push(quote! { #serde_field_attr #ident: #generic_param });
             generic_params.push(generic_param);
         } else {
-            g_fields.push(
-                quote! { #serde_field_attr #ident: <#ty as _nexustack::openapi::Schema>::Example },
-            );
+            let example_ty = field_example_type(field, &ty);
+            g_fields.push(quote! { #serde_field_attr #ident: #example_ty });
         }
     }
 
@@ -276,28 +292,31 @@ fn examples_type(fields: &[&Field], example_cont: &ExampleContainerIdentifier) -
     }
 
     if fields.len() == 1 {
-        let ty = fields[0].ty;
+        let examples_ty = field_examples_type(fields[0]);
+        let example_ty = field_example_type(fields[0], fields[0].ty);
 
         return quote! {
-            _nexustack::__private::Map<<#ty as _nexustack::openapi::Schema>::Examples, fn(<#ty as _nexustack::openapi::Schema>::Example) -> #example_cont>
+            _nexustack::__private::Map<#examples_ty, fn(#example_ty) -> #example_cont>
         };
     }
 
-    let field_ty_0 = fields[0].ty;
-    let field_ty_1 = fields[1].ty;
+    let examples_ty_0 = field_examples_type(fields[0]);
+    let examples_ty_1 = field_examples_type(fields[1]);
 
-    let mut examples_iter_ty = quote! { _nexustack::__private::Zip<<#field_ty_0 as _nexustack::openapi::Schema>::Examples, <#field_ty_1 as _nexustack::openapi::Schema>::Examples> };
+    let mut examples_iter_ty =
+        quote! { _nexustack::__private::Zip<#examples_ty_0, #examples_ty_1> };
     let mut example_ty_list = vec![
-        quote! { <#field_ty_0 as _nexustack::openapi::Schema>::Example },
-        quote! { <#field_ty_1 as _nexustack::openapi::Schema>::Example },
+        field_example_type(fields[0], fields[0].ty),
+        field_example_type(fields[1], fields[1].ty),
     ];
 
     for field in fields.iter().skip(2) {
-        let field_ty = field.ty;
+        let examples_ty = field_examples_type(field);
+        let example_ty = field_example_type(field, field.ty);
 
-        examples_iter_ty = quote! { _nexustack::__private::Zip<#examples_iter_ty, <#field_ty as _nexustack::openapi::Schema>::Examples> };
-        examples_iter_ty = quote! { _nexustack::__private::Map<#examples_iter_ty, fn(((#(#example_ty_list),*), <#field_ty as _nexustack::openapi::Schema>::Example)) -> (#(#example_ty_list),*, <#field_ty as _nexustack::openapi::Schema>::Example)> };
-        example_ty_list.push(quote! { <#field_ty as _nexustack::openapi::Schema>::Example });
+        examples_iter_ty = quote! { _nexustack::__private::Zip<#examples_iter_ty, #examples_ty> };
+        examples_iter_ty = quote! { _nexustack::__private::Map<#examples_iter_ty, fn(((#(#example_ty_list),*), #example_ty)) -> (#(#example_ty_list),*, #example_ty)> };
+        example_ty_list.push(example_ty);
     }
 
     quote! {
@@ -313,7 +332,8 @@ fn examples(fields: &[&Field], example_cont: &ExampleContainerIdentifier) -> Tok
     }
 
     if fields.len() == 1 {
-        let ty = fields[0].ty;
+        let field_examples = field_examples(fields[0]);
+        let example_ty = field_example_type(fields[0], fields[0].ty);
         let ident = fields[0]
             .original
             .ident
@@ -322,34 +342,35 @@ fn examples(fields: &[&Field], example_cont: &ExampleContainerIdentifier) -> Tok
 
         return quote! {
             _nexustack::__private::Iterator::map(
-                <#ty as _nexustack::openapi::SchemaExamples>::examples(is_human_readable)?,
-                (|e: <#ty as _nexustack::openapi::Schema>::Example| #example_cont_instantiation { #ident: e }) as _,
+                #field_examples,
+                (|e: #example_ty| #example_cont_instantiation { #ident: e }) as _,
             )
         };
     }
 
-    let field_ty_0 = fields[0].ty;
-    let field_ty_1 = fields[1].ty;
+    let field_examples_0 = field_examples(fields[0]);
+    let field_examples_1 = field_examples(fields[1]);
 
     let mut result = quote! {
         _nexustack::__private::Iterator::zip(
-            <#field_ty_0 as _nexustack::openapi::SchemaExamples>::examples(is_human_readable)?,
-            <#field_ty_1 as _nexustack::openapi::SchemaExamples>::examples(is_human_readable)?,
+            #field_examples_0,
+            #field_examples_1,
         )
     };
 
     let mut example_ty_list = vec![
-        quote! { <#field_ty_0 as _nexustack::openapi::Schema>::Example },
-        quote! { <#field_ty_1 as _nexustack::openapi::Schema>::Example },
+        field_example_type(fields[0], fields[0].ty),
+        field_example_type(fields[1], fields[1].ty),
     ];
 
     for (index, field) in fields.iter().enumerate().skip(2) {
-        let field_ty = field.ty;
+        let field_examples = field_examples(field);
+        let example_ty = field_example_type(field, field.ty);
 
         result = quote! {
             _nexustack::__private::Iterator::zip(
                 #result,
-                <#field_ty as _nexustack::openapi::SchemaExamples>::examples(is_human_readable)?,
+                #field_examples,
             )
         };
 
@@ -358,11 +379,11 @@ fn examples(fields: &[&Field], example_cont: &ExampleContainerIdentifier) -> Tok
         result = quote! {
             _nexustack::__private::Iterator::map(
                 #result,
-                (|f:((#(#example_ty_list),*), <#field_ty as _nexustack::openapi::Schema>::Example)| (#(#f_existing_entries),*, f.1)) as _,
+                (|f:((#(#example_ty_list),*), #example_ty)| (#(#f_existing_entries),*, f.1)) as _,
             )
         };
 
-        example_ty_list.push(quote! { <#field_ty as _nexustack::openapi::Schema>::Example });
+        example_ty_list.push(example_ty);
     }
 
     let f_entries = fields
@@ -391,6 +412,7 @@ fn describe(
     cont: &Container,
     example_cont: &ExampleContainerIdentifier,
     name: Option<&Name>,
+    with_custom_examples: bool,
 ) -> Fragment {
     let cattrs = &cont.attrs;
     assert!(
@@ -404,9 +426,9 @@ fn describe(
     let has_flatten = fields.iter().any(|field| field.attrs.flatten());
 
     if has_flatten {
-        describe_struct_as_map(fields, cont, example_cont, name)
+        describe_struct_as_map(fields, cont, example_cont, name, with_custom_examples)
     } else {
-        describe_struct_as_struct(fields, cont, example_cont, name)
+        describe_struct_as_struct(fields, cont, example_cont, name, with_custom_examples)
     }
 }
 
@@ -428,6 +450,7 @@ fn describe_struct_as_struct(
     cont: &Container,
     example_cont: &ExampleContainerIdentifier,
     name: Option<&Name>,
+    with_custom_examples: bool,
 ) -> Fragment {
     let cattrs = &cont.attrs;
     let describe_fields =
@@ -437,9 +460,43 @@ fn describe_struct_as_struct(
     let tag_field = describe_struct_tag_field(cattrs, &StructTrait::Struct);
     let tag_field_exists = !tag_field.is_empty();
 
+    let deny_unknown_fields = if cattrs.deny_unknown_fields() {
+        quote! {
+            _nexustack::openapi::StructSchemaBuilder::deny_unknown_fields(&mut __builder)?;
+        }
+    } else {
+        quote! {}
+    };
+
+    let translations = describe_translations(
+        cattrs,
+        &quote!(_nexustack::openapi::StructSchemaBuilder),
+        &format_ident!("__builder"),
+    );
+
+    let example_names = describe_example_names(
+        cattrs,
+        &quote!(_nexustack::openapi::StructSchemaBuilder),
+        &format_ident!("__builder"),
+        with_custom_examples,
+    );
+
+    let modifier = describe_modifier(
+        cattrs,
+        &quote!(_nexustack::openapi::StructSchemaBuilder),
+        &format_ident!("__builder"),
+    );
+
     let mut serialized_fields = fields.iter().peekable();
 
-    let let_mut = mut_if(serialized_fields.peek().is_some() || tag_field_exists);
+    let let_mut = mut_if(
+        serialized_fields.peek().is_some()
+            || tag_field_exists
+            || cattrs.deny_unknown_fields()
+            || !translations.is_empty()
+            || !example_names.is_empty()
+            || !modifier.is_empty(),
+    );
 
     let len = serialized_fields.map(|_| quote!(1)).fold(
         quote!(#tag_field_exists as usize),
@@ -447,10 +504,12 @@ fn describe_struct_as_struct(
     );
 
     let description = cattrs.description();
-    let examples = examples(fields, example_cont);
+    let examples = custom_examples(cont)
+        .filter(|_| with_custom_examples)
+        .unwrap_or_else(|| examples(fields, example_cont));
     let deprecated = cattrs.deprecated();
 
-    let id = if let Some(name) = name {
+    let id = if let Some(name) = name.filter(|_| !cattrs.inline()) {
         let cont_span = cont.original.span();
         let cont_callsite = callsite(&cont_span);
         quote! { _nexustack::__private::Option::Some(_nexustack::openapi::SchemaId::new(#name, *__callsite.get_or_init(|| #cont_callsite))) }
@@ -471,6 +530,10 @@ fn describe_struct_as_struct(
 
         #tag_field
         #(#describe_fields)*
+        #deny_unknown_fields
+        #translations
+        #example_names
+        #modifier
 
         _nexustack::openapi::StructSchemaBuilder::end(__builder)
     }
@@ -481,6 +544,7 @@ fn describe_struct_as_map(
     cont: &Container,
     example_cont: &ExampleContainerIdentifier,
     name: Option<&Name>,
+    with_custom_examples: bool,
 ) -> Fragment {
     let cattrs = &cont.attrs;
     let describe_fields =
@@ -490,15 +554,51 @@ fn describe_struct_as_map(
     let tag_field = describe_struct_tag_field(cattrs, &StructTrait::Map);
     let tag_field_exists = !tag_field.is_empty();
 
+    let deny_unknown_fields = if cattrs.deny_unknown_fields() {
+        quote! {
+            _nexustack::openapi::MapSchemaBuilder::deny_additional_elements(&mut __builder)?;
+        }
+    } else {
+        quote! {}
+    };
+
+    let translations = describe_translations(
+        cattrs,
+        &quote!(_nexustack::openapi::MapSchemaBuilder),
+        &format_ident!("__builder"),
+    );
+
+    let example_names = describe_example_names(
+        cattrs,
+        &quote!(_nexustack::openapi::MapSchemaBuilder),
+        &format_ident!("__builder"),
+        with_custom_examples,
+    );
+
+    let modifier = describe_modifier(
+        cattrs,
+        &quote!(_nexustack::openapi::MapSchemaBuilder),
+        &format_ident!("__builder"),
+    );
+
     let mut serialized_fields = fields.iter().peekable();
 
-    let let_mut = mut_if(serialized_fields.peek().is_some() || tag_field_exists);
+    let let_mut = mut_if(
+        serialized_fields.peek().is_some()
+            || tag_field_exists
+            || cattrs.deny_unknown_fields()
+            || !translations.is_empty()
+            || !example_names.is_empty()
+            || !modifier.is_empty(),
+    );
 
     let description = cattrs.description();
-    let examples = examples(fields, example_cont);
+    let examples = custom_examples(cont)
+        .filter(|_| with_custom_examples)
+        .unwrap_or_else(|| examples(fields, example_cont));
     let deprecated = cattrs.deprecated();
 
-    let id = if let Some(name) = name {
+    let id = if let Some(name) = name.filter(|_| !cattrs.inline()) {
         let cont_span = cont.original.span();
         let cont_callsite = callsite(&cont_span);
         quote! { _nexustack::__private::Option::Some(_nexustack::openapi::SchemaId::new(#name, *__callsite.get_or_init(|| #cont_callsite))) }
@@ -518,6 +618,10 @@ fn describe_struct_as_map(
 
         #tag_field
         #(#describe_fields)*
+        #deny_unknown_fields
+        #translations
+        #example_names
+        #modifier
 
         _nexustack::openapi::MapSchemaBuilder::end(__builder)
     }
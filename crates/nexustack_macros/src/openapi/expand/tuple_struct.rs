@@ -14,8 +14,8 @@ use crate::{
     internals::callsite,
     openapi::{
         expand::{
-            ExampleContainerIdentifier, Parameters, TupleTrait, describe_tuple_struct_visitor,
-            mut_if,
+            ExampleContainerIdentifier, Parameters, TupleTrait, custom_examples,
+            custom_examples_type, describe_tuple_struct_visitor, mut_if,
         },
         generics::{field_contains_generic_params, make_lifetimes_static},
         internals::ast::{Container, Field},
@@ -32,7 +32,8 @@ pub fn expand_tuple_struct(cont: &Container, fields: &[Field]) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = params.generics.split_for_impl();
     let (example_cont, example_cont_id) = example_container(cont, fields);
     let body = Stmts(describe(fields, cont, &example_cont_id));
-    let examples = examples_type(fields, &example_cont_id);
+    let examples =
+        custom_examples_type(cont).unwrap_or_else(|| examples_type(fields, &example_cont_id));
 
     quote! {
         static __callsite: _nexustack::__private::utils::AtomicOnceCell<_nexustack::Callsite> =
@@ -258,13 +259,14 @@ fn describe(
 
     let description = cattrs.description();
     let deprecated = cattrs.deprecated();
-    let examples = examples(fields, example_cont);
+    let examples = custom_examples(cont).unwrap_or_else(|| examples(fields, example_cont));
 
     // If any of the fields contains one of the containers generic parameters,
     // we cannot provide a unique type name.
-    let id = if fields
-        .iter()
-        .any(|field| field_contains_generic_params(field, cont))
+    let id = if cattrs.inline()
+        || fields
+            .iter()
+            .any(|field| field_contains_generic_params(field, cont))
     {
         quote! { _nexustack::__private::Option::None }
     } else {
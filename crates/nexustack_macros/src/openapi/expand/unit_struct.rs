@@ -13,7 +13,7 @@ use crate::{
     fragment::{Fragment, Stmts},
     internals::callsite,
     openapi::{
-        expand::{ExampleContainerIdentifier, Parameters},
+        expand::{ExampleContainerIdentifier, Parameters, custom_examples_type},
         internals::ast::Container,
         serde::build_example_struct_attribute,
     },
@@ -28,7 +28,7 @@ pub fn expand_unit_struct(cont: &Container) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = params.generics.split_for_impl();
     let (example_cont, example_cont_id) = example_container(cont);
     let body = Stmts(describe(cont, &example_cont_id));
-    let examples = examples_type(&example_cont_id);
+    let examples = custom_examples_type(cont).unwrap_or_else(|| examples_type(&example_cont_id));
 
     quote! {
         static __callsite: _nexustack::__private::utils::AtomicOnceCell<_nexustack::Callsite> =
@@ -79,15 +79,27 @@ fn describe(cont: &Container, example_cont: &ExampleContainerIdentifier) -> Frag
     let description = cattrs.description();
     let deprecated = cattrs.deprecated();
     let type_name = cattrs.name().serialize_name();
-    let cont_span = cont.original.span();
-    let cont_callsite = callsite(&cont_span);
+    let custom_examples = cattrs.examples();
+    let examples = if custom_examples.is_empty() {
+        quote!(_nexustack::__private::once(#example_cont_instantiation))
+    } else {
+        quote!(_nexustack::__private::IntoIterator::into_iter([#(#custom_examples()),*]))
+    };
+
+    let id = if cattrs.inline() {
+        quote! { _nexustack::__private::Option::None }
+    } else {
+        let cont_span = cont.original.span();
+        let cont_callsite = callsite(&cont_span);
+        quote! { _nexustack::__private::Option::Some(_nexustack::openapi::SchemaId::new(#type_name, *__callsite.get_or_init(|| #cont_callsite))) }
+    };
 
     quote_expr! {
         _nexustack::openapi::SchemaBuilder::describe_unit_struct(
             __schema_builder,
-            _nexustack::__private::Option::Some(_nexustack::openapi::SchemaId::new(#type_name, *__callsite.get_or_init(|| #cont_callsite))),
+            #id,
             _nexustack::__private::Option::Some(#description),
-            || _nexustack::__private::Result::Ok(_nexustack::__private::once(#example_cont_instantiation)),
+            || _nexustack::__private::Result::Ok(#examples),
             #deprecated,
         )
     }
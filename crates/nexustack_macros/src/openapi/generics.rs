@@ -32,7 +32,8 @@ pub fn field_contains_generic_params(field: &Field, cont: &Container) -> bool {
         .map(|type_param| &type_param.ident)
         .collect::<Vec<_>>();
 
-    if type_params.is_empty() {
+    // The examples of a custom schema do not depend on the field type.
+    if type_params.is_empty() || field.attrs.schema_with().is_some() {
         return false;
     }
 
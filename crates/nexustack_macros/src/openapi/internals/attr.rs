@@ -17,7 +17,8 @@ use proc_macro2::{Span, TokenStream};
 use quote::ToTokens;
 use std::{borrow::Cow, collections::BTreeSet};
 use syn::{
-    Ident, Token, meta::ParseNestedMeta, parse::Parser, parse_quote, spanned::Spanned, token,
+    Ident, Token, meta::ParseNestedMeta, parse::Parser, parse_quote, punctuated::Punctuated,
+    spanned::Spanned, token,
 };
 
 // This module handles parsing of attributes. The entrypoints
@@ -59,6 +60,7 @@ pub struct Container {
     name: MultiName,
     transparent: bool,
     deny_unknown_fields: bool,
+    inline: bool,
     default: Default,
     rename_all_rules: RenameAllRules,
     rename_all_fields_rules: RenameAllRules,
@@ -74,8 +76,15 @@ pub struct Container {
     /// Error message generated when type can't be deserialized
     expecting: Option<String>,
     non_exhaustive: bool,
+    read_only: bool,
+    write_only: bool,
     deprecated: bool,
     description: String,
+    /// Translations of the description as pairs of language tag and description
+    translations: Vec<(String, String)>,
+    examples: Vec<syn::ExprPath>,
+    /// Names of the examples, either empty or one for each example
+    example_names: Vec<String>,
     // TODO: rename
     derive: Derive,
 }
@@ -136,6 +145,7 @@ impl Container {
         let mut de_name = Attr::none(cx, RENAME);
         let mut transparent = BoolAttr::none(cx, TRANSPARENT);
         let mut deny_unknown_fields = BoolAttr::none(cx, DENY_UNKNOWN_FIELDS);
+        let mut inline = BoolAttr::none(cx, INLINE);
         let mut default = Attr::none(cx, DEFAULT);
         let mut rename_all_ser_rule = Attr::none(cx, RENAME_ALL);
         let mut rename_all_de_rule = Attr::none(cx, RENAME_ALL);
@@ -158,7 +168,12 @@ impl Container {
         let mut write = Attr::none(cx, WRITE);
         let mut description = Attr::none(cx, DESCRIPTION);
         let mut deprecated = Attr::none(cx, DESCRIPTION);
-        let mut non_exhaustive = Attr::none(cx, DESCRIPTION);
+        let mut non_exhaustive = Attr::none(cx, NON_EXHAUSTIVE);
+        let mut read_only = BoolAttr::none(cx, READ_ONLY);
+        let mut write_only = BoolAttr::none(cx, WRITE_ONLY);
+        let mut translations = VecAttr::none(cx, DESCRIPTION_I18N);
+        let mut examples = Vec::new();
+        let mut example_names = Vec::new();
 
         if !meta.is_empty() {
             let parser = syn::meta::parser(|meta| {
@@ -233,6 +248,9 @@ impl Container {
                 } else if meta.path == DENY_UNKNOWN_FIELDS {
                     // #[api_schema(deny_unknown_fields)]
                     deny_unknown_fields.set_true(meta.path);
+                } else if meta.path == INLINE {
+                    // #[api_schema(inline)]
+                    inline.set_true(meta.path);
                 } else if meta.path == DEFAULT {
                     if meta.input.peek(Token![=]) {
                         // #[api_schema(default = "...")]
@@ -389,11 +407,77 @@ impl Container {
                 } else if meta.path == WRITE {
                     // #[api_schema(write)]
                     write.set(&meta.path, true);
+                } else if meta.path == READ_ONLY {
+                    // #[api_schema(read_only)]
+                    read_only.set_true(&meta.path);
+                } else if meta.path == WRITE_ONLY {
+                    // #[api_schema(write_only)]
+                    write_only.set_true(&meta.path);
                 } else if meta.path == DESCRIPTION {
                     // #[api_schema(description = "...")]
                     if let Some(s) = get_lit_str(cx, DESCRIPTION, &meta)? {
                         description.set(&meta.path, s.value());
                     }
+                } else if meta.path == DESCRIPTION_I18N {
+                    // #[api_schema(description_i18n(en = "...", pt_br = "..."))]
+                    meta.parse_nested_meta(|nested| {
+                        let Some(language) = nested.path.get_ident() else {
+                            return Err(nested.error("expected a language tag"));
+                        };
+
+                        if let Some(s) = get_lit_str(cx, DESCRIPTION_I18N, &nested)? {
+                            // Language tags use hyphens, which are not valid in identifiers.
+                            let language = unraw(language).to_string().replace('_', "-");
+                            translations.insert(&nested.path, (language, s.value()));
+                        }
+
+                        Ok(())
+                    })?;
+                } else if meta.path == EXAMPLE {
+                    if meta.input.peek(syn::token::Paren) {
+                        // #[api_schema(example(name = "...", value = "..."))]
+                        let mut name = Attr::none(cx, NAME);
+                        let mut value = Attr::none(cx, VALUE);
+
+                        meta.parse_nested_meta(|nested| {
+                            if nested.path == NAME {
+                                if let Some(s) = get_lit_str(cx, NAME, &nested)? {
+                                    name.set(&nested.path, s.value());
+                                }
+                            } else if nested.path == VALUE {
+                                if let Some(path) = parse_lit_into_expr_path(cx, VALUE, &nested)? {
+                                    value.set(&nested.path, path);
+                                }
+                            } else {
+                                return Err(nested.error("expected `name` or `value`"));
+                            }
+
+                            Ok(())
+                        })?;
+
+                        match (name.get(), value.get()) {
+                            (Some(name), Some(value)) => {
+                                if example_names.contains(&name) {
+                                    return Err(
+                                        meta.error(format_args!("duplicate example name `{name}`"))
+                                    );
+                                }
+
+                                example_names.push(name);
+                                examples.push(value);
+                            }
+                            _ => {
+                                return Err(meta.error(
+                                    "expected #[api_schema(example(name = \"...\", value = \"...\"))]",
+                                ));
+                            }
+                        }
+                    } else {
+                        // #[api_schema(example = "...")]
+                        if let Some(path) = parse_lit_into_expr_path(cx, EXAMPLE, &meta)? {
+                            examples.push(path);
+                        }
+                    }
                 } else if meta.path == DEPRECATED {
                     if meta.input.peek(Token![=]) {
                         // #[api_schema(deprecated = "...")]
@@ -414,6 +498,16 @@ impl Container {
                         // #[api_schema(non_exhaustive)]
                         non_exhaustive.set(&meta.path, true)
                     }
+                } else if meta.path == EXHAUSTIVE {
+                    if meta.input.peek(Token![=]) {
+                        // #[api_schema(exhaustive = "...")]
+                        if let Some(value) = parse_lit_into_bool(cx, EXHAUSTIVE, &meta)? {
+                            non_exhaustive.set(&meta.path, !value)
+                        }
+                    } else {
+                        // #[api_schema(exhaustive)]
+                        non_exhaustive.set(&meta.path, false)
+                    }
                 } else {
                     let path = meta.path.to_token_stream().to_string().replace(' ', "");
                     return Err(meta.error(format_args!("unknown container attribute `{path}`")));
@@ -456,6 +550,7 @@ impl Container {
             name: MultiName::from_attrs(Name::from(&unraw(&item.ident)), ser_name, de_name, None),
             transparent: transparent.get(),
             deny_unknown_fields: deny_unknown_fields.get(),
+            inline: inline.get(),
             default: default.get().unwrap_or(Default::None),
             rename_all_rules: RenameAllRules {
                 serialize: rename_all_ser_rule.get().unwrap_or(RenameRule::None),
@@ -481,6 +576,8 @@ impl Container {
                 _ => Derive::ReadWrite,
             },
             non_exhaustive: non_exhaustive.get().unwrap_or(false),
+            read_only: read_only.get(),
+            write_only: write_only.get(),
             deprecated: deprecated.get().unwrap_or(false),
             description: match description.get() {
                 Some(description) => description,
@@ -489,6 +586,9 @@ impl Container {
                     String::new()
                 }
             },
+            translations: translations.get(),
+            examples,
+            example_names,
         }
     }
 
@@ -512,6 +612,10 @@ impl Container {
         self.deny_unknown_fields
     }
 
+    pub fn inline(&self) -> bool {
+        self.inline
+    }
+
     pub fn default(&self) -> &Default {
         &self.default
     }
@@ -567,6 +671,14 @@ impl Container {
         self.non_exhaustive
     }
 
+    pub fn read_only(&self) -> bool {
+        self.read_only
+    }
+
+    pub fn write_only(&self) -> bool {
+        self.write_only
+    }
+
     pub fn deprecated(&self) -> bool {
         self.deprecated
     }
@@ -575,6 +687,18 @@ impl Container {
         &self.description
     }
 
+    pub fn translations(&self) -> &[(String, String)] {
+        &self.translations
+    }
+
+    pub fn examples(&self) -> &[syn::ExprPath] {
+        &self.examples
+    }
+
+    pub fn example_names(&self) -> &[String] {
+        &self.example_names
+    }
+
     pub fn derive(&self) -> Derive {
         self.derive
     }
@@ -953,6 +1077,8 @@ impl Variant {
 pub struct Field {
     name: MultiName,
     skip: bool,
+    skip_serializing: bool,
+    skip_deserializing: bool,
     skip_serializing_if: Option<syn::ExprPath>,
     default: Default,
     ser_bound: Option<Vec<syn::WherePredicate>>,
@@ -961,6 +1087,8 @@ pub struct Field {
     transparent: bool,
     deprecated: bool,
     description: String,
+    examples: Vec<syn::ExprPath>,
+    schema_with: Option<syn::ExprPath>,
 }
 
 /// Represents the default to use for a field when deserializing.
@@ -1004,6 +1132,8 @@ impl Field {
         let mut de_name = Attr::none(cx, RENAME);
         let mut de_aliases = VecAttr::none(cx, RENAME);
         let mut skip = BoolAttr::none(cx, SKIP);
+        let mut skip_serializing = BoolAttr::none(cx, SKIP_SERIALIZING);
+        let mut skip_deserializing = BoolAttr::none(cx, SKIP_DESERIALIZING);
         let mut skip_serializing_if = Attr::none(cx, SKIP_SERIALIZING_IF);
         let mut default = Attr::none(cx, DEFAULT);
         let mut ser_bound = Attr::none(cx, BOUND);
@@ -1011,6 +1141,9 @@ impl Field {
         let mut flatten = BoolAttr::none(cx, FLATTEN);
         let mut description = Attr::none(cx, DESCRIPTION);
         let mut deprecated = Attr::none(cx, DESCRIPTION);
+        let mut examples = Vec::new();
+        let mut schema_with = Attr::none(cx, SCHEMA_WITH);
+        let mut custom_serde = Vec::new();
 
         let ident = match &field.ident {
             Some(ident) => Name::from(&unraw(ident)),
@@ -1072,16 +1205,10 @@ impl Field {
                     }
                 } else if meta.path == SKIP_SERIALIZING {
                     // #[api_property(skip_serializing)]
-                    cx.syn_error(syn::Error::new(
-                        meta.path.span(),
-                        "#[api_property(skip_serializing)] disallowed. Use #[api_property(skip)] instead.",
-                    ));
+                    skip_serializing.set_true(&meta.path);
                 } else if meta.path == SKIP_DESERIALIZING {
                     // #[api_property(skip_deserializing)]
-                    cx.syn_error(syn::Error::new(
-                        meta.path.span(),
-                        "#[api_property(skip_deserializing)] disallowed. Use #[api_property(skip)] instead.",
-                    ));
+                    skip_deserializing.set_true(&meta.path);
                 } else if meta.path == SKIP {
                     // #[api_property(skip)]
                     skip.set_true(&meta.path);
@@ -1091,23 +1218,31 @@ impl Field {
                         skip_serializing_if.set(&meta.path, path);
                     }
                 } else if meta.path == WITH {
-                    // #[api_variant(with = "...")]
-                    cx.syn_error(syn::Error::new(
+                    // #[api_property(with = "...")]
+                    meta.value()?.parse::<syn::LitStr>()?;
+                    custom_serde.push(syn::Error::new(
                         meta.path.span(),
-                        "Custom (de)serializers are disallowed. Use a dedicated type for custom (de)serialization.",
+                        "Custom (de)serializers are disallowed. Use a dedicated type for custom (de)serialization or describe the schema with #[api_property(schema_with = \"...\")].",
                     ));
                 } else if meta.path == SERIALIZE_WITH {
-                    // #[api_variant(serialize_with = "...")]
-                    cx.syn_error(syn::Error::new(
+                    // #[api_property(serialize_with = "...")]
+                    meta.value()?.parse::<syn::LitStr>()?;
+                    custom_serde.push(syn::Error::new(
                         meta.path.span(),
-                        "Custom serializers are disallowed. Use a dedicated type for custom de/serialization.",
+                        "Custom serializers are disallowed. Use a dedicated type for custom de/serialization or describe the schema with #[api_property(schema_with = \"...\")].",
                     ));
                 } else if meta.path == DESERIALIZE_WITH {
-                    // #[api_variant(deserialize_with = "...")]
-                    cx.syn_error(syn::Error::new(
+                    // #[api_property(deserialize_with = "...")]
+                    meta.value()?.parse::<syn::LitStr>()?;
+                    custom_serde.push(syn::Error::new(
                         meta.path.span(),
-                        "Custom deserializers are disallowed. Use a dedicated type for custom de/serialization.",
+                        "Custom deserializers are disallowed. Use a dedicated type for custom de/serialization or describe the schema with #[api_property(schema_with = \"...\")].",
                     ));
+                } else if meta.path == SCHEMA_WITH {
+                    // #[api_property(schema_with = "...")]
+                    if let Some(path) = parse_lit_into_expr_path(cx, SCHEMA_WITH, &meta)? {
+                        schema_with.set(&meta.path, path);
+                    }
                 } else if meta.path == BOUND {
                     // #[api_property(bound = "T: SomeBound")]
                     // #[api_property(bound(serialize = "...", deserialize = "..."))]
@@ -1138,7 +1273,12 @@ impl Field {
                         // #[api_schema(deprecated)]
                         deprecated.set(&meta.path, true)
                     }
-                } else if meta.path != BORROW{
+                } else if meta.path == EXAMPLE {
+                    // #[api_property(example = "...")]
+                    if let Some(path) = parse_lit_into_expr_path(cx, EXAMPLE, &meta)? {
+                        examples.push(path);
+                    }
+                } else if meta.path != BORROW {
                     let path = meta.path.to_token_stream().to_string().replace(' ', "");
                     return Err(meta.error(format_args!("unknown field attribute `{path}`")));
                 }
@@ -1147,6 +1287,30 @@ impl Field {
                 cx.syn_error(err);
             }
 
+            // Options that only affect the schema are unknown to serde and must not be forwarded.
+            if let syn::Meta::List(meta_list) = &mut field.attrs[i].meta
+                && let Ok(nested) =
+                    meta_list.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)
+            {
+                let serde_nested = nested
+                    .into_iter()
+                    .filter(|nested| {
+                        let path = nested.path();
+                        path != DESCRIPTION
+                            && path != DEPRECATED
+                            && path != EXAMPLE
+                            && path != SCHEMA_WITH
+                    })
+                    .collect::<Punctuated<syn::Meta, Token![,]>>();
+
+                if serde_nested.is_empty() {
+                    field.attrs.remove(i);
+                    continue;
+                }
+
+                meta_list.tokens = serde_nested.into_token_stream();
+            }
+
             match &mut field.attrs[i].meta {
                 syn::Meta::Path(_) => {}
                 syn::Meta::List(meta_list) => {
@@ -1164,6 +1328,16 @@ impl Field {
             }
         }
 
+        // Custom (de)serializers change the wire shape of the field, which is only sound if the schema is replaced
+        // as well.
+        let schema_with = schema_with.get();
+
+        if schema_with.is_none() {
+            for err in custom_serde {
+                cx.syn_error(err);
+            }
+        }
+
         // Is skip_deserializing, initialize the field to Default::default() unless a
         // different default is specified by `#[api_property(default = "...")]` or `#[api_schema(default = "...")]`` on
         // ourselves or our container (e.g. the struct we are in) respectively.
@@ -1189,6 +1363,8 @@ impl Field {
         Field {
             name: MultiName::from_attrs(ident, ser_name, de_name, Some(de_aliases)),
             skip: skip.get(),
+            skip_serializing: skip_serializing.get(),
+            skip_deserializing: skip_deserializing.get(),
             skip_serializing_if: skip_serializing_if.get(),
             default: default.get().unwrap_or(Default::None),
             ser_bound: ser_bound.get(),
@@ -1203,6 +1379,8 @@ impl Field {
                     String::new()
                 }
             },
+            examples,
+            schema_with,
         }
     }
 
@@ -1232,6 +1410,14 @@ impl Field {
         self.skip
     }
 
+    pub fn skip_serializing(&self) -> bool {
+        self.skip_serializing
+    }
+
+    pub fn skip_deserializing(&self) -> bool {
+        self.skip_deserializing
+    }
+
     pub fn skip_serializing_if(&self) -> Option<&syn::ExprPath> {
         self.skip_serializing_if.as_ref()
     }
@@ -1267,6 +1453,14 @@ impl Field {
     pub fn description(&self) -> &str {
         &self.description
     }
+
+    pub fn examples(&self) -> &[syn::ExprPath] {
+        &self.examples
+    }
+
+    pub fn schema_with(&self) -> Option<&syn::ExprPath> {
+        self.schema_with.as_ref()
+    }
 }
 
 type SerAndDe<T> = (Option<T>, Option<T>);
@@ -27,6 +27,12 @@ pub fn check(cx: &Ctxt, cont: &mut Container) {
     check_adjacent_tag_conflict(cx, cont);
     check_transparent(cx, cont, derive);
     check_from_and_try_from(cx, cont);
+    check_examples(cx, cont, derive);
+    check_description_i18n(cx, cont);
+    check_example_names(cx, cont);
+    check_read_only_write_only(cx, cont);
+    check_schema_with(cx, cont);
+    check_skip_serializing_deserializing(cx, cont);
 
     if cont.attrs.derive() == Derive::ReadWrite {
         if let Some(type_from) = cont.attrs.type_from() {
@@ -335,6 +341,162 @@ fn check_default_on_tuple(cx: &Ctxt, cont: &Container) {
     }
 }
 
+// Translated descriptions are only emitted for schemas that carry their own
+// description object, i.e. structs with named fields and enums.
+fn check_description_i18n(cx: &Ctxt, cont: &Container) {
+    if cont.attrs.translations().is_empty() {
+        return;
+    }
+
+    let supported = match &cont.data {
+        Data::Enum(_) => true,
+        Data::Struct(style, _) => matches!(style, Style::Struct),
+    } && !cont.attrs.transparent()
+        && cont.attrs.type_into().is_none();
+
+    if !supported {
+        cx.error_spanned_by(
+            &cont.original,
+            "#[api_schema(description_i18n(...))] is only supported on structs with named fields and enums",
+        );
+    }
+}
+
+// Named examples are emitted by the same schemas that carry translated descriptions,
+// i.e. structs with named fields and enums. Either all examples are named or none.
+fn check_example_names(cx: &Ctxt, cont: &Container) {
+    let example_names = cont.attrs.example_names();
+
+    if example_names.is_empty() {
+        return;
+    }
+
+    if example_names.len() != cont.attrs.examples().len() {
+        cx.error_spanned_by(
+            &cont.original,
+            "either all or none of the examples must be named",
+        );
+    }
+
+    let supported = match &cont.data {
+        Data::Enum(_) => true,
+        Data::Struct(style, _) => matches!(style, Style::Struct),
+    };
+
+    if !supported {
+        cx.error_spanned_by(
+            &cont.original,
+            "#[api_schema(example(name = \"...\", value = \"...\"))] is only supported on structs with named fields and enums",
+        );
+    }
+}
+
+// Like translated descriptions, the type-level access modifier is only emitted for
+// structs with named fields and enums.
+fn check_read_only_write_only(cx: &Ctxt, cont: &Container) {
+    if !cont.attrs.read_only() && !cont.attrs.write_only() {
+        return;
+    }
+
+    if cont.attrs.read_only() && cont.attrs.write_only() {
+        cx.error_spanned_by(
+            &cont.original,
+            "#[api_schema(read_only)] and #[api_schema(write_only)] cannot be used together",
+        );
+    }
+
+    let supported = match &cont.data {
+        Data::Enum(_) => true,
+        Data::Struct(style, _) => matches!(style, Style::Struct),
+    } && !cont.attrs.transparent()
+        && cont.attrs.type_into().is_none();
+
+    if !supported {
+        cx.error_spanned_by(
+            &cont.original,
+            "#[api_schema(read_only)] and #[api_schema(write_only)] are only supported on structs with named fields and enums",
+        );
+    }
+}
+
+// A custom schema replaces the schema of a single named field, so it cannot be used where the
+// field type itself makes up the schema.
+fn check_schema_with(cx: &Ctxt, cont: &Container) {
+    let fields = match &cont.data {
+        Data::Enum(variants) => variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter().map(|field| (variant.style, field)))
+            .collect::<Vec<_>>(),
+        Data::Struct(style, fields) => fields.iter().map(|field| (*style, field)).collect(),
+    };
+
+    for (style, field) in fields {
+        if field.attrs.schema_with().is_none() {
+            continue;
+        }
+
+        if !matches!(style, Style::Struct) {
+            cx.error_spanned_by(
+                field.original,
+                "#[api_property(schema_with = \"...\")] is only supported on named fields",
+            );
+        }
+
+        if field.attrs.flatten() {
+            cx.error_spanned_by(
+                field.original,
+                "#[api_property(schema_with = \"...\")] cannot be combined with #[api_property(flatten)]",
+            );
+        }
+
+        if cont.attrs.transparent() {
+            cx.error_spanned_by(
+                field.original,
+                "#[api_property(schema_with = \"...\")] cannot be used on fields of a transparent struct",
+            );
+        }
+    }
+}
+
+// Fields skipped in one direction only are described as read-only or write-only properties, which only
+// exist for named fields.
+fn check_skip_serializing_deserializing(cx: &Ctxt, cont: &Container) {
+    let fields = match &cont.data {
+        Data::Enum(variants) => variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter().map(|field| (variant.style, field)))
+            .collect::<Vec<_>>(),
+        Data::Struct(style, fields) => fields.iter().map(|field| (*style, field)).collect(),
+    };
+
+    for (style, field) in fields {
+        if !field.attrs.skip_serializing() && !field.attrs.skip_deserializing() {
+            continue;
+        }
+
+        if field.attrs.skip_serializing() && field.attrs.skip_deserializing() {
+            cx.error_spanned_by(
+                field.original,
+                "#[api_property(skip_serializing)] and #[api_property(skip_deserializing)] cannot be used together. Use #[api_property(skip)] instead.",
+            );
+        }
+
+        if !matches!(style, Style::Struct) {
+            cx.error_spanned_by(
+                field.original,
+                "#[api_property(skip_serializing)] and #[api_property(skip_deserializing)] are only supported on named fields. Use #[api_property(skip)] instead.",
+            );
+        }
+
+        if field.attrs.flatten() {
+            cx.error_spanned_by(
+                field.original,
+                "#[api_property(flatten)] cannot be combined with #[api_property(skip_serializing)] or #[api_property(skip_deserializing)]",
+            );
+        }
+    }
+}
+
 // Flattening has some restrictions we can test.
 fn check_flatten(cx: &Ctxt, cont: &Container) {
     match &cont.data {
@@ -665,3 +827,35 @@ fn check_from_and_try_from(cx: &Ctxt, cont: &mut Container) {
         );
     }
 }
+
+// Custom examples are values of the container type itself, so the type has to be
+// serializable and must describe its own schema.
+fn check_examples(cx: &Ctxt, cont: &Container, derive: Derive) {
+    if cont.attrs.examples().is_empty() {
+        return;
+    }
+
+    if !derive.write() {
+        cx.error_spanned_by(
+            &cont.original,
+            "#[api_schema(example = \"...\")] requires the type to be serializable",
+        );
+    }
+
+    if cont.attrs.transparent() {
+        cx.error_spanned_by(
+            &cont.original,
+            "#[api_schema(example = \"...\")] is not allowed with #[api_schema(transparent)]",
+        );
+    }
+
+    if cont.attrs.type_from().is_some()
+        || cont.attrs.type_try_from().is_some()
+        || cont.attrs.type_into().is_some()
+    {
+        cx.error_spanned_by(
+            &cont.original,
+            "#[api_schema(example = \"...\")] is not allowed with #[api_schema(from = \"...\")], #[api_schema(try_from = \"...\")] or #[api_schema(into = \"...\")]",
+        );
+    }
+}